@@ -4,6 +4,8 @@ use tauri::Manager;
 use tauri::path::BaseDirectory;
 use trash::delete;
 
+use crate::utils::thumbnail_cache::thumbnails_dir;
+
 #[tauri::command(rename_all = "snake_case")]
 pub async fn clear_cache(handle: tauri::AppHandle) -> Result<(), String> {
     let app_cache_dir = handle
@@ -11,6 +13,12 @@ pub async fn clear_cache(handle: tauri::AppHandle) -> Result<(), String> {
         .resolve("cache".to_string(), BaseDirectory::AppConfig)
         .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
 
+    let app_thumbnails_dir = thumbnails_dir(&app_cache_dir);
+    if app_thumbnails_dir.exists() && app_thumbnails_dir.is_dir() {
+        delete(&app_thumbnails_dir)
+            .map_err(|e| format!("Failed to delete thumbnails folder: {}", e))?;
+    }
+
     if app_cache_dir.exists() && app_cache_dir.is_dir() {
         let entries: Vec<_> = fs::read_dir(&app_cache_dir)
             .map_err(|e| format!("Failed to read cache directory: {}", e))?
@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::game::deployment_ledger::{ensure_game_closed, purge_ledger, save_ledger};
+use crate::utils::create_junction::create_junction;
+use crate::utils::hidden_command::hidden_command;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentStrategy {
+    #[default]
+    Link,
+    Hardlink,
+    Copy,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployedEntry {
+    pub module_id: String,
+    pub source: String,
+    pub target: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeploymentLedger {
+    pub app_id: u32,
+    pub strategy: DeploymentStrategy,
+    pub deployed_at: u128,
+    pub entries: Vec<DeployedEntry>,
+}
+
+const LEDGER_DIRECTORY: &str = "deployments";
+
+fn is_link(path: &Path) -> bool {
+    path.read_link().is_ok()
+}
+
+// Junctions read back in the verbatim \\?\C:\ form on Windows, so both sides
+// are canonicalized before they are compared.
+fn links_to(link: &Path, source: &Path) -> bool {
+    if !is_link(link) {
+        return false;
+    }
+    match (fs::canonicalize(link), fs::canonicalize(source)) {
+        (Ok(link_target), Ok(source)) => link_target == source,
+        _ => false,
+    }
+}
+
+fn mirror_tree(source: &Path, target: &Path, hardlink: bool) -> Result<(), String> {
+    fs::create_dir_all(target)
+        .map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+
+    for entry in fs::read_dir(source).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let source_path = entry.path();
+        let target_path = target.join(entry.file_name());
+
+        if source_path.is_dir() {
+            mirror_tree(&source_path, &target_path, hardlink)?;
+            continue;
+        }
+
+        // Hard links cannot cross volumes, copy those files instead.
+        if !hardlink || fs::hard_link(&source_path, &target_path).is_err() {
+            fs::copy(&source_path, &target_path)
+                .map_err(|e| format!("Failed to copy {}: {}", source_path.display(), e))?;
+        }
+    }
+
+    Ok(())
+}
+
+fn create_links_with_elevation(links: &[(PathBuf, PathBuf)]) -> Result<(), String> {
+    let mut script_content = String::from(
+        "@echo off\r\n\
+         NET SESSION >nul 2>&1\r\n\
+         IF %ERRORLEVEL% NEQ 0 (\r\n\
+             powershell -Command \"$proc = Start-Process -FilePath '%~dpnx0' -Verb RunAs -PassThru -Wait; exit $proc.ExitCode\"\r\n\
+             exit /b %ERRORLEVEL%\r\n\
+         )\r\n\
+         echo Running with elevation...\r\n\r\n",
+    );
+
+    for (target_path, link_path) in links {
+        script_content.push_str(&format!(
+            "mklink /D \"{}\" \"{}\"\r\n",
+            link_path.to_string_lossy().replace("/", "\\"),
+            target_path.to_string_lossy().replace("/", "\\")
+        ));
+    }
+
+    script_content.push_str("echo Done.\r\n");
+    script_content.push_str("exit /b %ERRORLEVEL%\r\n");
+    let script_path = std::env::temp_dir().join("bannerlord_symlinks.cmd");
+
+    fs::write(&script_path, script_content)
+        .map_err(|e| format!("Failed to create script file: {}", e))?;
+
+    let _ = hidden_command("cmd")
+        .args(["/C", script_path.to_str().unwrap()])
+        .output()
+        .map_err(|e| format!("Failed to execute script: {}", e))?;
+
+    let _ = fs::remove_file(&script_path);
+
+    Ok(())
+}
+
+fn remove_entry(entry: &DeployedEntry) -> Result<bool, String> {
+    let target = Path::new(&entry.target);
+    if !target.exists() && !is_link(target) {
+        return Ok(false);
+    }
+
+    match entry.kind.as_str() {
+        "junction" | "symlink" => {
+            if !is_link(target) {
+                return Err(format!(
+                    "{} is no longer a link, refusing to remove it",
+                    target.display()
+                ));
+            }
+            // Directory links are files on Unix, removing them never touches the mod.
+            #[cfg(windows)]
+            let removed = fs::remove_dir(target);
+            #[cfg(not(windows))]
+            let removed = fs::remove_file(target);
+            removed
+        }
+        _ => fs::remove_dir_all(target),
+    }
+    .map(|()| true)
+    .map_err(|e| format!("Failed to remove {}: {}", target.display(), e))
+}
+
+pub fn purge(handle: &tauri::AppHandle, app_id: u32) -> Result<usize, String> {
+    purge_ledger(
+        handle,
+        LEDGER_DIRECTORY,
+        app_id,
+        |ledger: &mut DeploymentLedger| &mut ledger.entries,
+        remove_entry,
+    )
+}
+
+fn deploy_module(
+    source: &Path,
+    target: &Path,
+    strategy: DeploymentStrategy,
+    elevated_links: &mut Vec<(PathBuf, PathBuf)>,
+) -> Result<&'static str, String> {
+    match strategy {
+        DeploymentStrategy::Link => match create_junction(source, target)? {
+            true => Ok("junction"),
+            false => {
+                elevated_links.push((source.to_path_buf(), target.to_path_buf()));
+                Ok("symlink")
+            }
+        },
+        DeploymentStrategy::Hardlink => mirror_tree(source, target, true).map(|()| "hardlink"),
+        DeploymentStrategy::Copy => mirror_tree(source, target, false).map(|()| "copy"),
+    }
+}
+
+pub fn deploy(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    game_modules_path: &Path,
+    mods: &[(String, PathBuf)],
+    strategy: DeploymentStrategy,
+) -> Result<Vec<String>, String> {
+    purge(handle, app_id)?;
+
+    let mut entries = Vec::new();
+    let mut elevated_links = Vec::new();
+    let mut failure = None;
+
+    for (module_id, source) in mods {
+        let target = game_modules_path.join(module_id);
+        if !source.is_dir() {
+            continue;
+        }
+
+        if target.exists() || is_link(&target) {
+            // Links left behind before the ledger existed are adopted when they
+            // already point at the right module, anything else is not ours.
+            if links_to(&target, source) {
+                entries.push(DeployedEntry {
+                    module_id: module_id.clone(),
+                    source: source.to_string_lossy().to_string(),
+                    target: target.to_string_lossy().to_string(),
+                    kind: "symlink".to_string(),
+                });
+                continue;
+            }
+            failure = Some(format!(
+                "{} already exists and is not managed by the mod manager",
+                target.display()
+            ));
+            break;
+        }
+
+        let kind = match deploy_module(source, &target, strategy, &mut elevated_links) {
+            Ok(kind) => kind,
+            Err(e) => {
+                // A half mirrored module is recorded as a copy so the purge removes it.
+                if target.exists() {
+                    entries.push(DeployedEntry {
+                        module_id: module_id.clone(),
+                        source: source.to_string_lossy().to_string(),
+                        target: target.to_string_lossy().to_string(),
+                        kind: "copy".to_string(),
+                    });
+                }
+                failure = Some(e);
+                break;
+            }
+        };
+
+        entries.push(DeployedEntry {
+            module_id: module_id.clone(),
+            source: source.to_string_lossy().to_string(),
+            target: target.to_string_lossy().to_string(),
+            kind: kind.to_string(),
+        });
+    }
+
+    if failure.is_none() && !elevated_links.is_empty() {
+        failure = create_links_with_elevation(&elevated_links).err();
+    }
+
+    let deployed_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Time error: {}", e))?
+        .as_millis();
+
+    // Record before verifying so a partial deployment can still be purged.
+    save_ledger(
+        handle,
+        LEDGER_DIRECTORY,
+        app_id,
+        &DeploymentLedger {
+            app_id,
+            strategy,
+            deployed_at,
+            entries: entries.clone(),
+        },
+    )?;
+
+    if let Some(e) = failure {
+        return Err(e);
+    }
+
+    for entry in &entries {
+        if !Path::new(&entry.target).exists() {
+            return Err(format!(
+                "Failed to verify deployment of: {}",
+                entry.module_id
+            ));
+        }
+    }
+
+    Ok(entries.into_iter().map(|entry| entry.module_id).collect())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn purge_deployment_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<usize, String> {
+    ensure_game_closed(app_id, "bannerlord")?;
+    purge(&handle, app_id)
+}
@@ -0,0 +1,28 @@
+use std::fs;
+use std::path::Path;
+use xml::reader::{EventReader, XmlEvent};
+
+pub fn game_version(game_installation_path: &Path) -> Option<String> {
+    let version_file = game_installation_path
+        .join("bin")
+        .join("Win64_Shipping_Client")
+        .join("Version.xml");
+    let content = fs::read_to_string(version_file).ok()?;
+
+    for event in EventReader::from_str(&content) {
+        match event {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) if name.local_name == "Singleplayer" => {
+                return attributes
+                    .iter()
+                    .find(|a| a.name.local_name.eq_ignore_ascii_case("value"))
+                    .map(|a| a.value.clone());
+            }
+            Err(_) => break,
+            _ => {}
+        }
+    }
+
+    None
+}
@@ -0,0 +1,254 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+use crate::r#mod::bannerlord::module_contents::{BannerlordModule, app_cache_dir, module_contents};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LauncherModuleEntry {
+    pub id: String,
+    pub is_selected: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LauncherProfileMod {
+    pub identifier: String,
+    pub module_id: String,
+    pub title: String,
+    pub mod_file_path: String,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LauncherProfile {
+    pub profile_name: String,
+    pub mods: Vec<LauncherProfileMod>,
+    pub unknown_modules: Vec<String>,
+}
+
+const DEFAULT_LAUNCHER_DATA: &str = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\r\n\
+<UserData xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" xmlns:xsd=\"http://www.w3.org/2001/XMLSchema\">\r\n\
+  <GameType>Singleplayer</GameType>\r\n\
+  <SingleplayerData>\r\n\
+    <ModDatas />\r\n\
+  </SingleplayerData>\r\n\
+  <MultiplayerData>\r\n\
+    <ModDatas />\r\n\
+  </MultiplayerData>\r\n\
+</UserData>\r\n";
+
+pub fn launcher_data_path(game: &Game) -> Result<PathBuf, String> {
+    let documents = game
+        .documents_folder()
+        .ok_or_else(|| "Documents folder could not be resolved.".to_string())?;
+    Ok(documents
+        .join(game.save_path_folder_name)
+        .join("Configs")
+        .join("LauncherData.xml"))
+}
+
+pub fn read_launcher_data(content: &str) -> Vec<LauncherModuleEntry> {
+    let mut entries = Vec::new();
+    let mut element_stack: Vec<String> = Vec::new();
+    let mut current: Option<LauncherModuleEntry> = None;
+
+    for event in EventReader::from_str(content) {
+        match event {
+            Ok(XmlEvent::StartElement { name, .. }) => {
+                if name.local_name == "UserModData"
+                    && element_stack.iter().any(|e| e == "SingleplayerData")
+                {
+                    current = Some(LauncherModuleEntry {
+                        id: String::new(),
+                        is_selected: false,
+                    });
+                }
+                element_stack.push(name.local_name);
+            }
+            Ok(XmlEvent::Characters(text)) => {
+                if let (Some(entry), Some(element)) = (current.as_mut(), element_stack.last()) {
+                    match element.as_str() {
+                        "Id" => entry.id = text.trim().to_string(),
+                        "IsSelected" => {
+                            entry.is_selected = text.trim().eq_ignore_ascii_case("true")
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Ok(XmlEvent::EndElement { name }) => {
+                element_stack.pop();
+                if name.local_name == "UserModData" {
+                    if let Some(entry) = current.take() {
+                        if !entry.id.is_empty() {
+                            entries.push(entry);
+                        }
+                    }
+                }
+            }
+            Err(_) => break,
+            _ => {}
+        }
+    }
+
+    entries
+}
+
+fn escape_xml(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn render_mod_datas(entries: &[LauncherModuleEntry]) -> String {
+    if entries.is_empty() {
+        return "<ModDatas />".to_string();
+    }
+
+    let mut mod_datas = String::from("<ModDatas>\r\n");
+    for entry in entries {
+        mod_datas.push_str(&format!(
+            "      <UserModData>\r\n        <Id>{}</Id>\r\n        <IsSelected>{}</IsSelected>\r\n      </UserModData>\r\n",
+            escape_xml(&entry.id),
+            entry.is_selected
+        ));
+    }
+    mod_datas.push_str("    </ModDatas>");
+    mod_datas
+}
+
+// Only the single player ModDatas block is replaced so multiplayer and DLL
+// check data written by the official launcher survive.
+pub fn write_launcher_data(content: &str, entries: &[LauncherModuleEntry]) -> Option<String> {
+    let section_start = content.find("<SingleplayerData>")?;
+    let section_end = section_start + content[section_start..].find("</SingleplayerData>")?;
+    let section = &content[section_start..section_end];
+
+    let mod_datas_start = section.find("<ModDatas")?;
+    let mod_datas_end = if let Some(end) = section.find("</ModDatas>") {
+        end + "</ModDatas>".len()
+    } else {
+        mod_datas_start + section[mod_datas_start..].find("/>")? + 2
+    };
+
+    Some(format!(
+        "{}{}{}",
+        &content[..section_start + mod_datas_start],
+        render_mod_datas(entries),
+        &content[section_start + mod_datas_end..]
+    ))
+}
+
+pub fn export_launcher_entries(
+    game: &Game,
+    entries: Vec<LauncherModuleEntry>,
+) -> Result<String, String> {
+    let launcher_data_path = launcher_data_path(game)?;
+    let existing = fs::read_to_string(&launcher_data_path).ok();
+
+    let mut merged = entries;
+    if let Some(ref existing) = existing {
+        for entry in read_launcher_data(existing) {
+            if !merged.iter().any(|e| e.id == entry.id) {
+                merged.push(LauncherModuleEntry {
+                    id: entry.id,
+                    is_selected: false,
+                });
+            }
+        }
+    }
+
+    let content = existing
+        .as_deref()
+        .and_then(|existing| write_launcher_data(existing, &merged))
+        .or_else(|| write_launcher_data(DEFAULT_LAUNCHER_DATA, &merged))
+        .ok_or_else(|| "Failed to build LauncherData.xml".to_string())?;
+
+    if let Some(parent) = launcher_data_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create Configs directory: {}", e))?;
+    }
+
+    // Only the user's own file is kept, later launches would otherwise replace
+    // it with one we wrote ourselves.
+    let backup_path = launcher_data_path.with_extension("xml.bak");
+    if launcher_data_path.exists() && !backup_path.exists() {
+        fs::copy(&launcher_data_path, &backup_path)
+            .map_err(|e| format!("Failed to back up LauncherData.xml: {}", e))?;
+    }
+
+    fs::write(&launcher_data_path, content)
+        .map_err(|e| format!("Failed to write LauncherData.xml: {}", e))?;
+
+    Ok(launcher_data_path.to_string_lossy().into_owned())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn import_launcher_data_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mods: Vec<BannerlordModule>,
+) -> Result<LauncherProfile, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let launcher_data_path = launcher_data_path(game)?;
+    let content = fs::read_to_string(&launcher_data_path)
+        .map_err(|e| format!("Failed to read LauncherData.xml: {}", e))?;
+
+    let app_cache_dir = app_cache_dir(&handle)?;
+    let (modules, _) = module_contents(&app_cache_dir, app_id, &mods);
+    let modules_by_id: HashMap<&str, _> = modules
+        .iter()
+        .map(|module| (module.contents.id.as_str(), module))
+        .collect();
+
+    let mut profile_mods = Vec::new();
+    let mut unknown_modules = Vec::new();
+    for entry in read_launcher_data(&content) {
+        match modules_by_id.get(entry.id.as_str()) {
+            Some(module) => profile_mods.push(LauncherProfileMod {
+                identifier: module.identifier.clone(),
+                module_id: entry.id,
+                title: module.contents.name.clone(),
+                mod_file_path: module.mod_path.to_string_lossy().to_string(),
+                is_active: entry.is_selected,
+            }),
+            None => unknown_modules.push(entry.id),
+        }
+    }
+
+    Ok(LauncherProfile {
+        profile_name: "LauncherData".to_string(),
+        mods: profile_mods,
+        unknown_modules,
+    })
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn export_launcher_data_bannerlord(
+    app_id: u32,
+    modules: Vec<LauncherModuleEntry>,
+) -> Result<String, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    export_launcher_entries(game, modules)
+}
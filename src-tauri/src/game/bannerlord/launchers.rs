@@ -0,0 +1,142 @@
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::game::{
+    find_installation_path::find_installation_path, launch_options::LaunchMode,
+    supported_games::SUPPORTED_GAMES,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleArgFormat {
+    Modules,
+    SingleplayerModules,
+    LauncherData,
+}
+
+pub struct BannerlordLauncher {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub exe_name: &'static str,
+    pub module_format: ModuleArgFormat,
+}
+
+pub const DEFAULT_LAUNCHER: &str = "native";
+
+pub const BANNERLORD_LAUNCHERS: &[BannerlordLauncher] = &[
+    BannerlordLauncher {
+        id: "native",
+        name: "Bannerlord",
+        exe_name: "Bannerlord.exe",
+        module_format: ModuleArgFormat::Modules,
+    },
+    BannerlordLauncher {
+        id: "blse_standalone",
+        name: "BLSE Standalone",
+        exe_name: "Bannerlord.BLSE.Standalone.exe",
+        module_format: ModuleArgFormat::SingleplayerModules,
+    },
+    BannerlordLauncher {
+        id: "blse_launcher",
+        name: "BLSE Launcher",
+        exe_name: "Bannerlord.BLSE.Launcher.exe",
+        module_format: ModuleArgFormat::LauncherData,
+    },
+    BannerlordLauncher {
+        id: "blse_launcher_ex",
+        name: "BLSE LauncherEx",
+        exe_name: "Bannerlord.BLSE.LauncherEx.exe",
+        module_format: ModuleArgFormat::LauncherData,
+    },
+    BannerlordLauncher {
+        id: "official_launcher",
+        name: "Official Launcher",
+        exe_name: "TaleWorlds.MountAndBlade.Launcher.exe",
+        module_format: ModuleArgFormat::LauncherData,
+    },
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DetectedLauncher {
+    pub id: String,
+    pub name: String,
+    pub exe_path: String,
+}
+
+impl BannerlordLauncher {
+    // Launchers that show their own module picker read LauncherData.xml instead.
+    pub fn module_args(&self, module_ids: &[String]) -> Vec<String> {
+        if module_ids.is_empty() {
+            return vec![];
+        }
+
+        let modules = format!("_MODULES_*{}*_MODULES_", module_ids.join("*"));
+        match self.module_format {
+            ModuleArgFormat::Modules => vec![modules],
+            ModuleArgFormat::SingleplayerModules => vec!["/singleplayer".to_string(), modules],
+            ModuleArgFormat::LauncherData => vec![],
+        }
+    }
+
+    // Steam always runs Bannerlord.exe, so another launcher would silently be
+    // skipped. Steam installs outside of Windows only start through Steam.
+    pub fn check_launch_mode(&self, launch_mode: LaunchMode) -> Result<(), String> {
+        if launch_mode == LaunchMode::Steam && self.id != DEFAULT_LAUNCHER {
+            return Err(format!(
+                "{} cannot be started through Steam, use the Bannerlord launcher instead",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+pub fn find_launcher(id: &str) -> Option<&'static BannerlordLauncher> {
+    BANNERLORD_LAUNCHERS
+        .iter()
+        .find(|launcher| launcher.id == id)
+}
+
+pub fn detect_launchers(exe_directory: &Path) -> Vec<DetectedLauncher> {
+    BANNERLORD_LAUNCHERS
+        .iter()
+        .filter_map(|launcher| {
+            let exe_path: PathBuf = exe_directory.join(launcher.exe_name);
+            if exe_path.is_file() {
+                Some(DetectedLauncher {
+                    id: launcher.id.to_string(),
+                    name: launcher.name.to_string(),
+                    exe_path: exe_path.to_string_lossy().into_owned(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn bannerlord_launchers(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<Vec<DetectedLauncher>, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let game_installation_path =
+        find_installation_path(&handle, game.clone()).ok_or_else(|| {
+            format!(
+                "Could not find installation path for game with app_id {}",
+                app_id
+            )
+        })?;
+
+    Ok(detect_launchers(
+        &game.exe_directory(Path::new(&game_installation_path)),
+    ))
+}
@@ -1 +1,6 @@
+pub mod deployment;
+pub mod game_version;
+pub mod launcher_data;
+pub mod launchers;
+pub mod preflight;
 pub mod start;
@@ -0,0 +1,280 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::game::find_installation_path::find_installation;
+use crate::game::launch_options::profile_launch_options;
+use crate::game::preflight::{
+    PlannedDeployment, PreflightIssue, PreflightReport, command_line, conflict_issues,
+};
+use crate::game::supported_games::SUPPORTED_GAMES;
+use crate::r#mod::bannerlord::game_compatibility::{
+    CompatibilityIssue, game_assemblies, installed_game_version, module_compatibility,
+};
+use crate::r#mod::bannerlord::module_contents::{
+    BannerlordModule, app_cache_dir, game_modules, module_contents,
+};
+use crate::r#mod::bannerlord::module_version::parse_module_version;
+use crate::r#mod::bannerlord::official_modules::OFFICIAL_MODULES;
+use crate::r#mod::bannerlord::sort_load_order::sort_modules;
+use crate::r#mod::bannerlord::validate_dependencies::validate_modules;
+use crate::steam::workshop_path_for_app::workshop_path_for_app;
+use crate::xml::submodule_contents::submodule_contents;
+
+use super::launchers::{DEFAULT_LAUNCHER, find_launcher};
+use super::start::{BannerlordMod, custom_mods, launch_command};
+
+// Official and workshop modules are passed by id, local ones by folder.
+// Workshop items are folders named after the item id, the module id is only
+// known from their SubModule.xml.
+fn module_path(
+    mod_info: &BannerlordMod,
+    game_modules_path: &Path,
+    workshop_path: Option<&Path>,
+    cache_dir: &Path,
+    app_id: u32,
+) -> Option<PathBuf> {
+    let mod_path = Path::new(&mod_info.mod_path);
+    if mod_path.is_dir() {
+        return Some(mod_path.to_path_buf());
+    }
+
+    let module_path = game_modules_path.join(&mod_info.bannerlord_id);
+    if module_path.is_dir() {
+        return Some(module_path);
+    }
+
+    let workshop_path = workshop_path?;
+    let item_path = workshop_path.join(&mod_info.identifier);
+    if item_path.join("SubModule.xml").is_file() {
+        return Some(item_path);
+    }
+
+    fs::read_dir(workshop_path)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|item_path| {
+            let identifier = item_path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            submodule_contents(item_path, cache_dir, app_id, identifier)
+                .is_some_and(|contents| contents.id == mod_info.bannerlord_id)
+        })
+}
+
+fn compatibility_message(issue: &CompatibilityIssue) -> String {
+    let expected = issue.expected.as_deref().unwrap_or("unknown");
+    let found = issue.found.as_deref().unwrap_or("unknown");
+    match issue.kind.as_str() {
+        "game_too_old" => format!(
+            "Needs {} {}, the game is {}",
+            issue.subject, expected, found
+        ),
+        "older_branch" => format!("Was made for {}, the game is {}", expected, found),
+        "missing_assembly" => format!("References {} which the game does not ship", issue.subject),
+        _ => format!(
+            "Was built against {} {}, the game has {}",
+            issue.subject, expected, found
+        ),
+    }
+}
+
+pub async fn build_preflight(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    mods: &[BannerlordMod],
+    launcher: Option<String>,
+    folder_paths: Vec<String>,
+) -> Result<PreflightReport, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let installation = find_installation(handle, game).ok_or_else(|| {
+        format!(
+            "Could not find installation path for game with app_id {}",
+            app_id
+        )
+    })?;
+    let game_installation_path = Path::new(&installation.path);
+    let game_modules_path = game_installation_path.join("Modules");
+
+    let mut issues = Vec::new();
+
+    let app_cache_dir = app_cache_dir(handle)?;
+    let workshop_path = workshop_path_for_app(app_id).map(PathBuf::from);
+
+    let mut modules = Vec::new();
+    for mod_info in mods {
+        match module_path(
+            mod_info,
+            &game_modules_path,
+            workshop_path.as_deref(),
+            &app_cache_dir,
+            app_id,
+        ) {
+            Some(mod_path) => modules.push(BannerlordModule {
+                identifier: mod_info.identifier.clone(),
+                mod_path: mod_path.to_string_lossy().to_string(),
+            }),
+            None => issues.push(PreflightIssue::blocking(
+                "missing_module",
+                &mod_info.identifier,
+                format!("{} could not be found", mod_info.bannerlord_id),
+            )),
+        }
+    }
+
+    let (enabled, unreadable) = module_contents(&app_cache_dir, app_id, &modules);
+    issues.extend(unreadable.iter().map(|identifier| {
+        PreflightIssue::blocking(
+            "unreadable_module",
+            identifier,
+            "SubModule.xml could not be read".to_string(),
+        )
+    }));
+
+    let mut installed = game_modules(&app_cache_dir, app_id, game_installation_path);
+    installed.extend(enabled.iter().cloned());
+    for issue in validate_modules(&enabled, &installed) {
+        let preflight_issue = match issue.kind.as_str() {
+            "missing" => PreflightIssue::blocking(
+                "missing_dependency",
+                &issue.module_id,
+                format!("Needs {} which is not installed", issue.dependency_id),
+            ),
+            "not_enabled" => PreflightIssue::blocking(
+                "missing_dependency",
+                &issue.module_id,
+                format!("Needs {} which is not enabled", issue.dependency_id),
+            ),
+            _ => PreflightIssue::warning(
+                "dependency_version",
+                &issue.module_id,
+                format!(
+                    "Needs {} {}, found {}",
+                    issue.dependency_id,
+                    issue.required_version.as_deref().unwrap_or("unknown"),
+                    issue.installed_version.as_deref().unwrap_or("unknown")
+                ),
+            ),
+        };
+        issues.push(preflight_issue);
+    }
+
+    let load_order = sort_modules(&enabled);
+    issues.extend(load_order.incompatible.iter().map(|incompatible| {
+        PreflightIssue::blocking(
+            "incompatible",
+            &incompatible.module_id,
+            format!("Is incompatible with {}", incompatible.incompatible_id),
+        )
+    }));
+    issues.extend(load_order.cycles.iter().map(|cycle| {
+        PreflightIssue::warning(
+            "load_order_cycle",
+            cycle.first().map(String::as_str).unwrap_or_default(),
+            format!("Modules depend on each other: {}", cycle.join(" -> ")),
+        )
+    }));
+
+    let raw_game_version = installed_game_version(&app_cache_dir, app_id, game_installation_path);
+    let parsed_game_version = raw_game_version.as_deref().and_then(parse_module_version);
+    let assemblies = game_assemblies(
+        &game_installation_path
+            .join("bin")
+            .join("Win64_Shipping_Client"),
+    );
+    for module in enabled
+        .iter()
+        .filter(|module| !OFFICIAL_MODULES.contains(&module.contents.id.as_str()))
+    {
+        let compatibility = module_compatibility(
+            module,
+            parsed_game_version.as_ref(),
+            raw_game_version.as_deref(),
+            &assemblies,
+        );
+        issues.extend(compatibility.issues.iter().map(|issue| {
+            PreflightIssue::warning(
+                "game_compatibility",
+                &compatibility.module_id,
+                compatibility_message(issue),
+            )
+        }));
+    }
+
+    let launch_options = profile_launch_options(handle, app_id, Some(profile_id))?;
+    let launcher_id = launcher
+        .or_else(|| launch_options.launcher.clone())
+        .unwrap_or_else(|| DEFAULT_LAUNCHER.to_string());
+    let mod_ids: Vec<String> = mods
+        .iter()
+        .map(|mod_info| mod_info.bannerlord_id.clone())
+        .collect();
+    let command = find_launcher(&launcher_id)
+        .ok_or_else(|| format!("Unknown launcher '{}'", launcher_id))
+        .and_then(|launcher| {
+            let launch_mode = launch_options.resolve_launch_mode(&installation)?;
+            launcher.check_launch_mode(launch_mode)?;
+            let exe_path = launch_options.executable_path(
+                &game.exe_directory(game_installation_path),
+                launcher.exe_name,
+            )?;
+            let mut args = launcher.module_args(&mod_ids);
+            args.extend(launch_options.args.iter().cloned());
+            launch_command(
+                app_id,
+                &installation,
+                launch_mode,
+                &exe_path,
+                &args,
+                &launch_options,
+            )
+        });
+    let command = match command {
+        Ok((command, _)) => command_line(&command),
+        Err(e) => {
+            issues.push(PreflightIssue::blocking("launch_options", game.name, e));
+            vec![]
+        }
+    };
+
+    let deployments = custom_mods(mods, &game_modules_path)
+        .into_iter()
+        .map(|(module_id, mod_path)| PlannedDeployment {
+            source: mod_path.to_string_lossy().to_string(),
+            target: game_modules_path
+                .join(module_id)
+                .to_string_lossy()
+                .to_string(),
+        })
+        .collect();
+
+    let active_paths: Vec<String> = modules
+        .iter()
+        .map(|module| module.mod_path.clone())
+        .collect();
+    issues.extend(conflict_issues(handle, app_id, profile_id, folder_paths, &active_paths).await?);
+
+    PreflightReport::new(app_id, profile_id, command, None, deployments, issues)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn preflight_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    mods: Vec<BannerlordMod>,
+    launcher: Option<String>,
+    folder_paths: Vec<String>,
+) -> Result<PreflightReport, String> {
+    build_preflight(&handle, app_id, profile_id, &mods, launcher, folder_paths).await
+}
@@ -1,124 +1,152 @@
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::os::windows::process::CommandExt;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use crate::AppState;
-use crate::game::find_installation_path::find_installation_path;
+use crate::game::config_snapshots::{apply_profile_configs, capture_after_exit};
+use crate::game::deployment_ledger::{purge_after_exit, roll_back_launch};
+use crate::game::find_installation_path::find_installation;
+use crate::game::game_update::ensure_update_acknowledged;
+use crate::game::launch_options::{
+    LaunchMode, LaunchOptions, exe_process_name, profile_launch_options,
+};
+use crate::game::storefronts::installation::Installation;
+#[cfg(not(windows))]
+use crate::game::storefronts::installation::wine_command;
 use crate::game::supported_games::SUPPORTED_GAMES;
-use crate::utils::create_junction::create_junction;
+use crate::steam::applaunch::applaunch_command;
+#[cfg(windows)]
+use crate::utils::hidden_command::hidden_command;
+
+use super::deployment::{DeploymentStrategy, deploy, purge};
+use super::launcher_data::{LauncherModuleEntry, export_launcher_entries};
+use super::launchers::{DEFAULT_LAUNCHER, ModuleArgFormat, find_launcher};
+use super::preflight::build_preflight;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BannerlordLaunch {
+    pub command: String,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BannerlordMod {
-    identifier: String,
-    bannerlord_id: String,
-    mod_path: String,
+    pub identifier: String,
+    pub bannerlord_id: String,
+    pub mod_path: String,
 }
 
-fn create_symlinks_with_elevation(
-    game_modules_path: &Path,
-    mods: &[(&str, &str, String)],
-) -> Result<Vec<String>, String> {
-    let mut created_symlinks = Vec::new();
-    let mut script_content = String::from(
-        "@echo off\r\n\
-         NET SESSION >nul 2>&1\r\n\
-         IF %ERRORLEVEL% NEQ 0 (\r\n\
-             powershell -Command \"$proc = Start-Process -FilePath '%~dpnx0' -Verb RunAs -PassThru -Wait; exit $proc.ExitCode\"\r\n\
-             exit /b %ERRORLEVEL%\r\n\
-         )\r\n\
-         echo Running with elevation...\r\n\r\n",
-    );
-    let mut needs_elevation = false;
-
-    if let Ok(entries) = fs::read_dir(game_modules_path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() && path.read_link().is_ok() {
-                if let Err(e) = fs::remove_dir(&path) {
-                    return Err(format!("Failed to remove existing symlink: {}", e));
-                }
-            }
-        }
-    }
-
-    for (_, mod_path, bannerlord_id) in mods {
-        let target_path = Path::new(mod_path);
-        let link_path = game_modules_path.join(bannerlord_id);
-
-        if !target_path.exists() {
-            continue;
-        }
-
-        match create_junction(target_path, &link_path) {
-            Ok(true) => {
-                created_symlinks.push(bannerlord_id.clone());
-                continue;
-            }
-            Ok(false) => {
-                let link_path_str = link_path.to_string_lossy().replace("/", "\\");
-                let target_path_str = target_path.to_string_lossy().replace("/", "\\");
-
-                script_content.push_str(&format!(
-                    "mklink /D \"{}\" \"{}\"\r\n",
-                    link_path_str, target_path_str
-                ));
-
-                needs_elevation = true;
-                created_symlinks.push(bannerlord_id.clone());
-            }
-            Err(e) => return Err(format!("Failed to create junction: {}", e)),
-        }
+#[cfg(windows)]
+fn direct_command(
+    _installation: &Installation,
+    exe_path: &Path,
+    args: &[String],
+) -> (Command, String) {
+    let mut command = hidden_command(exe_path);
+    if let Some(exe_directory) = exe_path.parent() {
+        command.current_dir(exe_directory);
     }
+    command.args(args);
+    (command, exe_path.to_string_lossy().into_owned())
+}
 
-    if needs_elevation {
-        script_content.push_str("echo Done.\r\n");
-        script_content.push_str("exit /b %ERRORLEVEL%\r\n");
-        let script_path = std::env::temp_dir().join("bannerlord_symlinks.cmd");
-
-        if let Err(e) = fs::write(&script_path, script_content) {
-            return Err(format!("Failed to create script file: {}", e));
-        }
+#[cfg(not(windows))]
+fn direct_command(
+    installation: &Installation,
+    exe_path: &Path,
+    args: &[String],
+) -> (Command, String) {
+    (
+        wine_command(installation, exe_path, args),
+        format!("wine {}", exe_path.to_string_lossy()),
+    )
+}
 
-        let _ = Command::new("cmd")
-            .args(["/C", script_path.to_str().unwrap()])
-            .creation_flags(0x08000000)
-            .output()
-            .map_err(|e| format!("Failed to execute script: {}", e))?;
+// Official modules already live in the game's Modules folder.
+pub fn custom_mods(mods: &[BannerlordMod], game_modules_path: &Path) -> Vec<(String, PathBuf)> {
+    mods.iter()
+        .filter(|mod_info| !mod_info.mod_path.is_empty() && Path::new(&mod_info.mod_path).is_dir())
+        .map(|mod_info| {
+            (
+                mod_info.bannerlord_id.clone(),
+                PathBuf::from(&mod_info.mod_path),
+            )
+        })
+        .filter(|(_, mod_path)| !mod_path.starts_with(game_modules_path))
+        .collect()
+}
 
-        let _ = fs::remove_file(&script_path);
-    }
+// Steam decides which executable runs, so only the native launcher gets
+// there, see BannerlordLauncher::check_launch_mode. Direct launches run the executable
+// itself, under wine outside of Windows.
+pub fn launch_command(
+    app_id: u32,
+    installation: &Installation,
+    launch_mode: LaunchMode,
+    exe_path: &Path,
+    args: &[String],
+    launch_options: &LaunchOptions,
+) -> Result<(Command, String), String> {
+    let (mut command, mut command_str) = if launch_mode == LaunchMode::Steam {
+        (
+            applaunch_command(app_id, args)?,
+            format!("steam -applaunch {}", app_id),
+        )
+    } else {
+        direct_command(installation, exe_path, args)
+    };
+    command.envs(&launch_options.env);
 
-    for bannerlord_id in &created_symlinks {
-        let link_path = game_modules_path.join(bannerlord_id);
-        if !link_path.exists() {
-            return Err(format!(
-                "Failed to verify symlink creation for: {}",
-                bannerlord_id
-            ));
-        }
+    for arg in args {
+        command_str.push_str(" ");
+        command_str.push_str(arg);
     }
 
-    Ok(created_symlinks)
+    Ok((command, command_str))
 }
 
 #[tauri::command(rename_all = "snake_case")]
 pub async fn start_game_bannerlord(
+    handle: tauri::AppHandle,
     app_state: tauri::State<'_, AppState>,
     app_id: u32,
     mods: Vec<BannerlordMod>,
-) -> Result<String, String> {
-    let steam_state = &app_state.steam_state;
-    steam_state.drop_all_clients();
+    deployment_strategy: Option<DeploymentStrategy>,
+    cleanup_after_exit: Option<bool>,
+    profile_id: Option<u64>,
+    launcher: Option<String>,
+    strict: Option<bool>,
+    folder_paths: Option<Vec<String>>,
+) -> Result<BannerlordLaunch, String> {
+    // Strict launches refuse whatever the preflight report would block.
+    if strict.unwrap_or(false) {
+        let profile_id =
+            profile_id.ok_or_else(|| "A strict launch needs a profile to check".to_string())?;
+        let report = build_preflight(
+            &handle,
+            app_id,
+            profile_id,
+            &mods,
+            launcher.clone(),
+            folder_paths.unwrap_or_default(),
+        )
+        .await?;
+        if report.blocking {
+            return Err(report.blocking_message());
+        }
+    }
 
     let game = SUPPORTED_GAMES
         .iter()
         .find(|game| game.steam_id == app_id)
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
 
-    let game_installation_path = match find_installation_path(game.clone()) {
-        Some(path) => path,
+    ensure_update_acknowledged(&handle, app_id)?;
+
+    let steam_state = &app_state.steam_state;
+    steam_state.drop_all_clients();
+
+    let installation = match find_installation(&handle, game) {
+        Some(installation) => installation,
         None => {
             return Err(format!(
                 "Could not find installation path for game with app_id {}",
@@ -127,82 +155,114 @@ pub async fn start_game_bannerlord(
         }
     };
 
-    let exe_directory = if game.exe_folder.is_empty() {
-        game_installation_path.clone()
-    } else {
-        Path::new(&game_installation_path.replace("\\", "/"))
-            .join(game.exe_folder.replace("\\", "/"))
-            .to_string_lossy()
-            .into_owned()
-    };
+    let game_installation_path = installation.path.clone();
+
+    let exe_directory = game
+        .exe_directory(Path::new(&game_installation_path))
+        .to_string_lossy()
+        .into_owned();
 
-    let mut modules_arg = String::new();
+    let launch_options = profile_launch_options(&handle, app_id, profile_id)?;
+    let launcher_id = launcher
+        .or_else(|| launch_options.launcher.clone())
+        .unwrap_or_else(|| DEFAULT_LAUNCHER.to_string());
+    let launcher =
+        find_launcher(&launcher_id).ok_or_else(|| format!("Unknown launcher '{}'", launcher_id))?;
+
+    let launch_mode = launch_options.resolve_launch_mode(&installation)?;
+    launcher.check_launch_mode(launch_mode)?;
+    // An alternate executable, e.g. Bannerlord.Native.exe, keeps the
+    // launcher's way of passing modules.
+    let exe_path = launch_options.executable_path(Path::new(&exe_directory), launcher.exe_name)?;
+
+    let mod_ids: Vec<String> = mods
+        .iter()
+        .map(|mod_info| mod_info.bannerlord_id.clone())
+        .collect();
     let mut symlinked_mods = Vec::new();
 
+    let roll_back = |backup_id: Option<&str>, e: String| {
+        roll_back_launch(&handle, game, app_id, backup_id, purge, e)
+    };
+
     if !mods.is_empty() {
-        let mod_ids: Vec<String> = mods
-            .iter()
-            .map(|mod_info| mod_info.bannerlord_id.clone())
-            .collect();
-
-        modules_arg = format!("_MODULES_*{}*_MODULES_", mod_ids.join("*"));
-
-        let custom_mods: Vec<(&str, &str, String)> = mods
-            .iter()
-            .filter(|mod_info| {
-                !mod_info.mod_path.is_empty() && Path::new(&mod_info.mod_path).exists()
-            })
-            .filter_map(|mod_info| {
-                let mod_path = Path::new(&mod_info.mod_path);
-                if mod_path.is_dir() {
-                    let bannerlord_id = mod_info.bannerlord_id.to_string().clone();
-                    Some((
-                        mod_info.identifier.as_str(),
-                        mod_path.to_str().unwrap(),
-                        bannerlord_id,
-                    ))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        if !custom_mods.is_empty() {
-            let game_modules_path = Path::new(&game_installation_path).join("Modules");
-            match create_symlinks_with_elevation(&game_modules_path, &custom_mods) {
-                Ok(created_mods) => {
-                    symlinked_mods = created_mods;
-                }
-                Err(e) => {
-                    return Err(format!("Failed to create symlinks: {}", e));
-                }
+        let game_modules_path = Path::new(&game_installation_path).join("Modules");
+        let custom_mods = custom_mods(&mods, &game_modules_path);
+
+        match deploy(
+            &handle,
+            app_id,
+            &game_modules_path,
+            &custom_mods,
+            deployment_strategy.unwrap_or_default(),
+        ) {
+            Ok(deployed_mods) => {
+                symlinked_mods = deployed_mods;
+            }
+            Err(e) => {
+                return Err(roll_back(None, format!("Failed to deploy mods: {}", e)));
             }
         }
 
-        if !symlinked_mods.is_empty() {
-            let symlinked_mods_str = symlinked_mods.join("*");
-            modules_arg.push_str(&format!("*{}*", symlinked_mods_str));
-        }
+        // Keep the official launcher in sync with the order we are starting
+        // with, only once the modules it lists are actually in place.
+        export_launcher_entries(
+            game,
+            mod_ids
+                .iter()
+                .map(|id| LauncherModuleEntry {
+                    id: id.clone(),
+                    is_selected: true,
+                })
+                .collect(),
+        )
+        .map_err(|e| roll_back(None, e))?;
     }
 
-    let exe_path = Path::new(&exe_directory).join("Bannerlord.exe");
-    let mut command = Command::new(&exe_path);
-    command.current_dir(&exe_directory);
-    command.creation_flags(0x08000000);
+    let backup_id = match profile_id {
+        Some(profile_id) => apply_profile_configs(&handle, game, app_id, profile_id)
+            .map_err(|e| roll_back(None, e))?,
+        None => None,
+    };
 
-    if !modules_arg.is_empty() {
-        command.arg(&modules_arg);
-    }
+    let mut args = launcher.module_args(&mod_ids);
+    args.extend(launch_options.args.iter().cloned());
 
-    let mut command_str = exe_path.to_string_lossy().into_owned();
-    if !modules_arg.is_empty() {
-        command_str.push_str(" ");
-        command_str.push_str(&modules_arg);
+    let command_str = launch_command(
+        app_id,
+        &installation,
+        launch_mode,
+        &exe_path,
+        &args,
+        &launch_options,
+    )
+    .and_then(|(mut command, command_str)| {
+        command
+            .spawn()
+            .map(|_| command_str)
+            .map_err(|e| format!("Failed to start game: {}", e))
+    })
+    .map_err(|e| roll_back(backup_id.as_deref(), e))?;
+
+    // Launchers with their own picker hand off to the game executable, so
+    // both are watched until neither is left.
+    let process_names = match launcher.module_format {
+        _ if launch_mode == LaunchMode::Steam => vec![game.exe_name.to_string()],
+        ModuleArgFormat::LauncherData => {
+            vec![exe_process_name(&exe_path), game.exe_name.to_string()]
+        }
+        _ => vec![exe_process_name(&exe_path)],
+    };
+
+    if let Some(profile_id) = profile_id {
+        capture_after_exit(handle.clone(), app_id, profile_id, process_names.clone());
     }
 
-    command
-        .spawn()
-        .map_err(|e| format!("Failed to start game: {}", e))?;
+    if !symlinked_mods.is_empty() && cleanup_after_exit.unwrap_or(true) {
+        purge_after_exit(handle, app_id, process_names, purge);
+    }
 
-    Ok(command_str)
+    Ok(BannerlordLaunch {
+        command: command_str,
+    })
 }
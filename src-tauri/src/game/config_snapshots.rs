@@ -0,0 +1,509 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+use crate::game::exit_watch::after_exit;
+use crate::game::find_installation_path::find_installation_path;
+use crate::game::is_running::is_process_running;
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+
+const MAX_BACKUPS: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub captured_at: u128,
+    pub entries: Vec<String>,
+    // Backups remember whose configs were live, profile snapshots leave it empty.
+    #[serde(default)]
+    pub active_profile_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ActiveProfile {
+    profile_id: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigBackup {
+    pub backup_id: String,
+    pub created_at: u128,
+    pub entries: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigEntry {
+    pub name: String,
+    pub live_path: String,
+    pub live_exists: bool,
+    pub captured: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigSnapshot {
+    pub profile_id: u64,
+    pub captured_at: Option<u128>,
+    pub entries: Vec<ConfigEntry>,
+}
+
+// Every tracked entry is a single file or folder swapped as a whole, LauncherData.xml
+// is left out since launches already write it from the profile's mod list.
+pub fn tracked_configs(handle: &tauri::AppHandle, game: &Game) -> Vec<(String, PathBuf)> {
+    let mut configs = Vec::new();
+
+    match game.r#type {
+        "totalwar" => {
+            if let Some(roaming_folder) = game.roaming_folder() {
+                let game_folder = roaming_folder
+                    .join("The Creative Assembly")
+                    .join(game.save_path_folder_name);
+                configs.push((
+                    "preferences.script.txt".to_string(),
+                    game_folder.join("scripts").join("preferences.script.txt"),
+                ));
+                configs.push(("mct".to_string(), game_folder.join("mct")));
+            }
+            if let Some(game_installation_path) = find_installation_path(handle, game.clone()) {
+                configs.push((
+                    "mct_settings.lua".to_string(),
+                    Path::new(&game_installation_path).join("mct_settings.lua"),
+                ));
+            }
+        }
+        "bannerlord" => {
+            if let Some(documents) = game.documents_folder() {
+                let configs_folder = documents.join(game.save_path_folder_name).join("Configs");
+                configs.push((
+                    "ModSettings".to_string(),
+                    configs_folder.join("ModSettings"),
+                ));
+                configs.push((
+                    "engine_config.txt".to_string(),
+                    configs_folder.join("engine_config.txt"),
+                ));
+                configs.push((
+                    "BannerlordConfig.txt".to_string(),
+                    configs_folder.join("BannerlordConfig.txt"),
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    configs
+}
+
+fn snapshots_dir(handle: &tauri::AppHandle, app_id: u32) -> Result<PathBuf, String> {
+    let snapshots_dir = handle
+        .path()
+        .resolve("config_snapshots".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?
+        .join(app_id.to_string());
+
+    if !snapshots_dir.exists() {
+        fs::create_dir_all(&snapshots_dir)
+            .map_err(|e| format!("Failed to create config snapshots directory: {}", e))?;
+    }
+
+    Ok(snapshots_dir)
+}
+
+fn profile_dir(handle: &tauri::AppHandle, app_id: u32, profile_id: u64) -> Result<PathBuf, String> {
+    Ok(snapshots_dir(handle, app_id)?
+        .join("profiles")
+        .join(profile_id.to_string()))
+}
+
+// Backup ids are the millisecond timestamps backup_live names the folders after,
+// anything else could point outside the backups folder.
+fn backup_dir(handle: &tauri::AppHandle, app_id: u32, backup_id: &str) -> Result<PathBuf, String> {
+    if backup_id.is_empty() || !backup_id.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(format!("Invalid config backup id: {}", backup_id));
+    }
+
+    Ok(snapshots_dir(handle, app_id)?
+        .join("backups")
+        .join(backup_id))
+}
+
+fn now_millis() -> Result<u128, String> {
+    Ok(SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Time error: {}", e))?
+        .as_millis())
+}
+
+fn load_manifest(profile_dir: &Path) -> Option<SnapshotManifest> {
+    let content = fs::read_to_string(profile_dir.join("manifest.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn load_active_profile(handle: &tauri::AppHandle, app_id: u32) -> Option<u64> {
+    let content =
+        fs::read_to_string(snapshots_dir(handle, app_id).ok()?.join("active.json")).ok()?;
+    serde_json::from_str::<ActiveProfile>(&content)
+        .ok()
+        .map(|active| active.profile_id)
+}
+
+fn save_active_profile(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(&ActiveProfile { profile_id })
+        .map_err(|e| format!("Failed to serialize active profile: {}", e))?;
+    fs::write(snapshots_dir(handle, app_id)?.join("active.json"), json)
+        .map_err(|e| format!("Failed to write active profile: {}", e))
+}
+
+fn restore_active_profile(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: Option<u64>,
+) -> Result<(), String> {
+    match profile_id {
+        Some(profile_id) => save_active_profile(handle, app_id, profile_id),
+        None => remove_path(&snapshots_dir(handle, app_id)?.join("active.json")),
+    }
+}
+
+fn remove_path(path: &Path) -> Result<(), String> {
+    if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else if path.exists() {
+        fs::remove_file(path)
+    } else {
+        Ok(())
+    }
+    .map_err(|e| format!("Failed to remove {}: {}", path.display(), e))
+}
+
+fn copy_path(source: &Path, target: &Path) -> Result<(), String> {
+    if source.is_dir() {
+        fs::create_dir_all(target)
+            .map_err(|e| format!("Failed to create {}: {}", target.display(), e))?;
+        for entry in fs::read_dir(source).map_err(|e| e.to_string())? {
+            let entry = entry.map_err(|e| e.to_string())?;
+            copy_path(&entry.path(), &target.join(entry.file_name()))?;
+        }
+        Ok(())
+    } else {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        fs::copy(source, target)
+            .map(|_| ())
+            .map_err(|e| format!("Failed to copy {}: {}", source.display(), e))
+    }
+}
+
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.{}", file_name, suffix))
+}
+
+// The new copy is staged next to the target so the final rename never
+// crosses volumes, the target is either the old or the new entry at any time.
+fn replace_path(source: &Path, target: &Path) -> Result<(), String> {
+    let staging = sibling(target, "tmo_staging");
+    let previous = sibling(target, "tmo_previous");
+    remove_path(&staging)?;
+    remove_path(&previous)?;
+
+    copy_path(source, &staging)?;
+
+    if target.exists() {
+        fs::rename(target, &previous)
+            .map_err(|e| format!("Failed to move {} aside: {}", target.display(), e))?;
+    }
+
+    if let Err(e) = fs::rename(&staging, target) {
+        if previous.exists() {
+            let _ = fs::rename(&previous, target);
+        }
+        let _ = remove_path(&staging);
+        return Err(format!("Failed to replace {}: {}", target.display(), e));
+    }
+
+    remove_path(&previous)
+}
+
+fn prune_backups(backups_dir: &Path) {
+    let Ok(entries) = fs::read_dir(backups_dir) else {
+        return;
+    };
+
+    let mut backups: Vec<PathBuf> = entries.flatten().map(|entry| entry.path()).collect();
+    backups.sort();
+    while backups.len() > MAX_BACKUPS {
+        let _ = fs::remove_dir_all(backups.remove(0));
+    }
+}
+
+fn backup_live(handle: &tauri::AppHandle, game: &Game, app_id: u32) -> Result<String, String> {
+    let backups_dir = snapshots_dir(handle, app_id)?.join("backups");
+    let created_at = now_millis()?;
+    let backup_id = created_at.to_string();
+    let backup_dir = backups_dir.join(&backup_id);
+    fs::create_dir_all(&backup_dir)
+        .map_err(|e| format!("Failed to create backup directory: {}", e))?;
+
+    let mut entries = Vec::new();
+    for (name, live_path) in tracked_configs(handle, game) {
+        if live_path.exists() {
+            copy_path(&live_path, &backup_dir.join(&name))?;
+            entries.push(name);
+        }
+    }
+
+    let manifest = serde_json::to_string_pretty(&SnapshotManifest {
+        captured_at: created_at,
+        entries,
+        active_profile_id: load_active_profile(handle, app_id),
+    })
+    .map_err(|e| format!("Failed to serialize backup manifest: {}", e))?;
+    fs::write(backup_dir.join("manifest.json"), manifest)
+        .map_err(|e| format!("Failed to write backup manifest: {}", e))?;
+
+    prune_backups(&backups_dir);
+    Ok(backup_id)
+}
+
+fn restore_entries(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    source_dir: &Path,
+    entries: &[String],
+) -> Result<(), String> {
+    let configs = tracked_configs(handle, game);
+    for name in entries {
+        if let Some((_, live_path)) = configs.iter().find(|(tracked, _)| tracked == name) {
+            replace_path(&source_dir.join(name), live_path)?;
+        }
+    }
+    Ok(())
+}
+
+// Restoring a backup also hands the live configs back to the profile they
+// belonged to, otherwise the next launch would capture them into the wrong one.
+pub fn restore_backup(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    app_id: u32,
+    backup_id: &str,
+) -> Result<(), String> {
+    let backup_dir = backup_dir(handle, app_id, backup_id)?;
+    let manifest = load_manifest(&backup_dir)
+        .ok_or_else(|| format!("Config backup {} was not found", backup_id))?;
+
+    restore_entries(handle, game, &backup_dir, &manifest.entries)?;
+    restore_active_profile(handle, app_id, manifest.active_profile_id)
+}
+
+pub fn capture_profile_configs(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<SnapshotManifest, String> {
+    let profile_dir = profile_dir(handle, app_id, profile_id)?;
+    fs::create_dir_all(&profile_dir)
+        .map_err(|e| format!("Failed to create config snapshot directory: {}", e))?;
+
+    let mut entries = Vec::new();
+    for (name, live_path) in tracked_configs(handle, game) {
+        let snapshot_path = profile_dir.join(&name);
+        if live_path.exists() {
+            replace_path(&live_path, &snapshot_path)?;
+            entries.push(name);
+        } else {
+            remove_path(&snapshot_path)?;
+        }
+    }
+
+    let manifest = SnapshotManifest {
+        captured_at: now_millis()?,
+        entries,
+        active_profile_id: None,
+    };
+    let json = serde_json::to_string_pretty(&manifest)
+        .map_err(|e| format!("Failed to serialize snapshot manifest: {}", e))?;
+    fs::write(profile_dir.join("manifest.json"), json)
+        .map_err(|e| format!("Failed to write snapshot manifest: {}", e))?;
+
+    Ok(manifest)
+}
+
+// Entries the profile never captured are left alone so a fresh profile starts
+// from the current settings instead of wiping them.
+pub fn apply_profile_configs(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<Option<String>, String> {
+    if let Some(active_profile_id) = load_active_profile(handle, app_id) {
+        if active_profile_id != profile_id {
+            capture_profile_configs(handle, game, app_id, active_profile_id)?;
+        }
+    }
+
+    let profile_dir = profile_dir(handle, app_id, profile_id)?;
+    let Some(manifest) = load_manifest(&profile_dir) else {
+        save_active_profile(handle, app_id, profile_id)?;
+        return Ok(None);
+    };
+
+    let backup_id = backup_live(handle, game, app_id)?;
+    if let Err(e) = restore_entries(handle, game, &profile_dir, &manifest.entries) {
+        let _ = restore_backup(handle, game, app_id, &backup_id);
+        return Err(format!("Failed to apply config snapshot: {}", e));
+    }
+
+    save_active_profile(handle, app_id, profile_id)?;
+    Ok(Some(backup_id))
+}
+
+pub fn capture_after_exit(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    exe_names: Vec<String>,
+) {
+    after_exit(exe_names, move || {
+        let Some(game) = SUPPORTED_GAMES.iter().find(|game| game.steam_id == app_id) else {
+            return;
+        };
+        if let Err(e) = capture_profile_configs(&handle, game, app_id, profile_id) {
+            eprintln!(
+                "Failed to capture configs for profile {}: {}",
+                profile_id, e
+            );
+        }
+    });
+}
+
+fn find_game(app_id: u32) -> Result<&'static Game, String> {
+    SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))
+}
+
+fn snapshot_info(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<ConfigSnapshot, String> {
+    let manifest = load_manifest(&profile_dir(handle, app_id, profile_id)?);
+    let entries = tracked_configs(handle, game)
+        .into_iter()
+        .map(|(name, live_path)| ConfigEntry {
+            captured: manifest
+                .as_ref()
+                .is_some_and(|manifest| manifest.entries.contains(&name)),
+            live_exists: live_path.exists(),
+            live_path: live_path.to_string_lossy().to_string(),
+            name,
+        })
+        .collect();
+
+    Ok(ConfigSnapshot {
+        profile_id,
+        captured_at: manifest.map(|manifest| manifest.captured_at),
+        entries,
+    })
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn config_snapshot(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<ConfigSnapshot, String> {
+    let game = find_game(app_id)?;
+    snapshot_info(&handle, game, app_id, profile_id)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn capture_config_snapshot(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<ConfigSnapshot, String> {
+    let game = find_game(app_id)?;
+    capture_profile_configs(&handle, game, app_id, profile_id)?;
+    snapshot_info(&handle, game, app_id, profile_id)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn apply_config_snapshot(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<Option<String>, String> {
+    let game = find_game(app_id)?;
+    if is_process_running(game.exe_name)? {
+        return Err("Cannot swap config files while the game is running".to_string());
+    }
+
+    apply_profile_configs(&handle, game, app_id, profile_id)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn delete_config_snapshot(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<(), String> {
+    find_game(app_id)?;
+    remove_path(&profile_dir(&handle, app_id, profile_id)?)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn config_backups(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<Vec<ConfigBackup>, String> {
+    find_game(app_id)?;
+    let backups_dir = snapshots_dir(&handle, app_id)?.join("backups");
+    let Ok(entries) = fs::read_dir(&backups_dir) else {
+        return Ok(vec![]);
+    };
+
+    let mut backups: Vec<ConfigBackup> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let manifest = load_manifest(&entry.path())?;
+            Some(ConfigBackup {
+                backup_id: entry.file_name().to_string_lossy().to_string(),
+                created_at: manifest.captured_at,
+                entries: manifest.entries,
+            })
+        })
+        .collect();
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    Ok(backups)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn restore_config_backup(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    backup_id: String,
+) -> Result<(), String> {
+    let game = find_game(app_id)?;
+    if is_process_running(game.exe_name)? {
+        return Err("Cannot restore config files while the game is running".to_string());
+    }
+
+    restore_backup(&handle, game, app_id, &backup_id)
+}
@@ -0,0 +1,155 @@
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::fs;
+use std::path::PathBuf;
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+use crate::game::config_snapshots::restore_backup;
+use crate::game::exit_watch::after_exit;
+use crate::game::is_running::is_process_running;
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+
+// Each game keeps its ledgers in its own AppConfig directory, one per app id.
+fn ledger_path(handle: &tauri::AppHandle, directory: &str, app_id: u32) -> Result<PathBuf, String> {
+    let deployments_dir = handle
+        .path()
+        .resolve(directory.to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    if !deployments_dir.exists() {
+        fs::create_dir_all(&deployments_dir)
+            .map_err(|e| format!("Failed to create deployments directory: {}", e))?;
+    }
+
+    Ok(deployments_dir.join(format!("{}.json", app_id)))
+}
+
+fn load_ledger<T: DeserializeOwned>(
+    handle: &tauri::AppHandle,
+    directory: &str,
+    app_id: u32,
+) -> Result<Option<T>, String> {
+    let path = ledger_path(handle, directory, app_id)?;
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read deployment ledger: {}", e))?;
+    let ledger = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse deployment ledger: {}", e))?;
+
+    Ok(Some(ledger))
+}
+
+pub fn save_ledger<T: Serialize>(
+    handle: &tauri::AppHandle,
+    directory: &str,
+    app_id: u32,
+    ledger: &T,
+) -> Result<(), String> {
+    let path = ledger_path(handle, directory, app_id)?;
+    let content = serde_json::to_string_pretty(ledger)
+        .map_err(|e| format!("Failed to serialize deployment ledger: {}", e))?;
+
+    fs::write(&path, content).map_err(|e| format!("Failed to write deployment ledger: {}", e))
+}
+
+// Entries that could not be removed stay in the ledger so a later purge can
+// retry them, the ledger itself goes once everything is gone.
+pub fn purge_ledger<L, E>(
+    handle: &tauri::AppHandle,
+    directory: &str,
+    app_id: u32,
+    entries: impl Fn(&mut L) -> &mut Vec<E>,
+    remove: impl Fn(&E) -> Result<bool, String>,
+) -> Result<usize, String>
+where
+    L: Serialize + DeserializeOwned,
+{
+    let Some(mut ledger) = load_ledger::<L>(handle, directory, app_id)? else {
+        return Ok(0);
+    };
+
+    let mut removed = 0;
+    let mut errors = Vec::new();
+    entries(&mut ledger).retain(|entry| match remove(entry) {
+        Ok(was_removed) => {
+            removed += usize::from(was_removed);
+            false
+        }
+        Err(e) => {
+            errors.push(e);
+            true
+        }
+    });
+
+    if entries(&mut ledger).is_empty() {
+        let _ = fs::remove_file(ledger_path(handle, directory, app_id)?);
+    } else {
+        save_ledger(handle, directory, app_id, &ledger)?;
+    }
+
+    if errors.is_empty() {
+        Ok(removed)
+    } else {
+        Err(errors.join("; "))
+    }
+}
+
+pub fn purge_after_exit(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    exe_names: Vec<String>,
+    purge: fn(&tauri::AppHandle, u32) -> Result<usize, String>,
+) {
+    after_exit(exe_names, move || {
+        if let Err(e) = purge(&handle, app_id) {
+            eprintln!("Failed to clean up deployment for {}: {}", app_id, e);
+        }
+    });
+}
+
+// A launch that fails after deploying puts the configs and the game folder
+// back the way they were, anything that could not be undone is reported too.
+pub fn roll_back_launch(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    app_id: u32,
+    backup_id: Option<&str>,
+    purge: fn(&tauri::AppHandle, u32) -> Result<usize, String>,
+    error: String,
+) -> String {
+    let mut errors = vec![error];
+    if let Some(backup_id) = backup_id {
+        if let Err(e) = restore_backup(handle, game, app_id, backup_id) {
+            errors.push(format!(
+                "Failed to restore config backup {}: {}",
+                backup_id, e
+            ));
+        }
+    }
+    if let Err(e) = purge(handle, app_id) {
+        errors.push(format!("Failed to clean up deployment: {}", e));
+    }
+    errors.join("; ")
+}
+
+// Manual purges refuse to pull files out from under a running game.
+pub fn ensure_game_closed(app_id: u32, game_type: &str) -> Result<(), String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != game_type {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    if is_process_running(game.exe_name)? {
+        return Err("Cannot purge the deployment while the game is running".to_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::game::is_running::is_process_running;
+
+// A launcher can sit in its own picker for a while before the game starts.
+const APPEAR_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+fn any_running(exe_names: &[String]) -> bool {
+    exe_names
+        .iter()
+        .any(|exe_name| is_process_running(exe_name).unwrap_or(false))
+}
+
+// Waits for one of the processes to show up, then for all of them to be gone.
+// Returns false when none of them appeared before the timeout.
+pub fn wait_for_exit(exe_names: &[String]) -> bool {
+    let started = Instant::now();
+    while !any_running(exe_names) {
+        if started.elapsed() > APPEAR_TIMEOUT {
+            return false;
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+
+    while any_running(exe_names) {
+        thread::sleep(POLL_INTERVAL);
+    }
+    true
+}
+
+pub fn after_exit<F>(exe_names: Vec<String>, action: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    thread::spawn(move || {
+        if wait_for_exit(&exe_names) {
+            action();
+        }
+    });
+}
@@ -1,47 +1,193 @@
-use super::supported_games::Game;
-use crate::steam::steam_paths::steam_paths;
-use regex::Regex;
-use std::{
-    fs,
-    path::{Path, PathBuf},
-};
-
-pub fn find_installation_path(game: Game) -> Option<String> {
-    let steam_install_paths = steam_paths().ok()?;
-    let re = Regex::new(r#"path"\s*"(.*?)""#).ok()?;
-
-    for steam_install_path in steam_install_paths {
-        let library_meta_file = Path::new(&steam_install_path)
+use super::installation_settings::{load_installation_settings, save_installation_settings};
+use super::storefronts::installation::{Installation, Storefront};
+use super::storefronts::{epic, gog, manual};
+use super::supported_games::{Game, SUPPORTED_GAMES};
+use crate::steam::app_manifest::find_app_manifest_in;
+use crate::steam::library_folders::{SteamLibrary, steam_libraries};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InstallationCandidate {
+    pub installation: Installation,
+    pub selected: bool,
+    pub exe_found: bool,
+    pub writable: bool,
+}
+
+pub fn has_exe(game: &Game, game_installation_path: &Path) -> bool {
+    let mut exe_path = game.exe_directory(game_installation_path);
+    exe_path.push(format!("{}.exe", game.exe_name));
+    exe_path.is_file()
+}
+
+// Mods are deployed and used_mods.txt is written into the install, a read-only
+// folder such as one under Program Files without rights fails late otherwise.
+fn is_writable(game_installation_path: &Path) -> bool {
+    let probe_path = game_installation_path.join(".tmo_write_test");
+    let writable = fs::write(&probe_path, b"").is_ok();
+    let _ = fs::remove_file(&probe_path);
+    writable
+}
+
+fn normalize(path: &str) -> String {
+    path.replace('\\', "/").trim_end_matches('/').to_lowercase()
+}
+
+// Every library can hold a copy, e.g. a beta branch kept next to the main install.
+pub fn steam_installation_paths(libraries: &[SteamLibrary], game: &Game) -> Vec<PathBuf> {
+    let mut paths = Vec::new();
+
+    if let Some(manifest) = find_app_manifest_in(libraries, game.steam_id) {
+        paths.push(PathBuf::from(&manifest.install_path));
+    }
+
+    // Installs copied between libraries by hand have no manifest to go by.
+    paths.extend(libraries.iter().map(|library| {
+        Path::new(&library.path)
             .join("steamapps")
-            .join("libraryfolders.vdf");
+            .join("common")
+            .join(game.steam_folder_name)
+    }));
 
-        if !library_meta_file.exists() {
-            continue;
+    let mut seen = Vec::new();
+    paths.retain(|path| {
+        let normalized = normalize(&path.to_string_lossy());
+        if seen.contains(&normalized) || !has_exe(game, path) {
+            return false;
         }
+        seen.push(normalized);
+        true
+    });
 
-        let file_data = fs::read_to_string(&library_meta_file).ok()?;
-        let library_folder_paths: Vec<PathBuf> = re
-            .captures_iter(&file_data)
-            .filter_map(|cap| cap.get(1))
-            .map(|m| PathBuf::from(m.as_str().replace("\\", "\\")))
-            .collect();
-
-        for lib_path in library_folder_paths {
-            let game_installation_path = lib_path
-                .join("steamapps/common")
-                .join(&game.steam_folder_name);
-            let mut exe_path = game_installation_path.clone();
-
-            if !game.exe_folder.is_empty() {
-                exe_path.push(&game.exe_folder);
-            }
+    paths
+}
+
+// Steam comes first since it is the only storefront the workshop works with.
+pub fn find_installations(handle: &tauri::AppHandle, game: &Game) -> Vec<Installation> {
+    let mut installations: Vec<Installation> = steam_installation_paths(&steam_libraries(), game)
+        .into_iter()
+        .map(|path| {
+            Installation::new(
+                path.to_string_lossy().into_owned(),
+                Storefront::Steam,
+                Some(game.steam_id.to_string()),
+                None,
+            )
+        })
+        .collect();
+    installations.extend(epic::installations(game));
+    installations.extend(gog::installations(game));
+    installations.extend(manual::manual_installations(handle, game));
 
-            exe_path.push(format!("{}.exe", game.exe_name));
+    let mut seen = Vec::new();
+    installations.retain(|installation| {
+        let path = normalize(&installation.path);
+        if seen.contains(&path) {
+            return false;
+        }
+        seen.push(path);
+        true
+    });
+
+    installations
+}
 
-            if exe_path.exists() && exe_path.is_file() {
-                return Some(game_installation_path.to_string_lossy().into_owned());
+// The selected install wins while it still has the game in it, a drive that
+// went missing falls back to whatever is detected first.
+pub fn find_installation(handle: &tauri::AppHandle, game: &Game) -> Option<Installation> {
+    let installations = find_installations(handle, game);
+    let selected_path = load_installation_settings(handle, game.steam_id)
+        .ok()
+        .and_then(|settings| settings.selected_path);
+
+    if let Some(selected_path) = selected_path {
+        if let Some(installation) = installations
+            .iter()
+            .find(|installation| normalize(&installation.path) == normalize(&selected_path))
+        {
+            return Some(installation.clone());
+        }
+    }
+
+    installations.into_iter().next()
+}
+
+pub fn find_installation_path(handle: &tauri::AppHandle, game: Game) -> Option<String> {
+    find_installation(handle, &game).map(|installation| installation.path)
+}
+
+fn find_game(app_id: u32) -> Result<&'static Game, String> {
+    SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn game_installation(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<Option<Installation>, String> {
+    let game = find_game(app_id)?;
+
+    Ok(find_installation(&handle, game))
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn game_installations(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<Vec<InstallationCandidate>, String> {
+    let game = find_game(app_id)?;
+    let active_path = find_installation(&handle, game).map(|installation| installation.path);
+
+    Ok(find_installations(&handle, game)
+        .into_iter()
+        .map(|installation| {
+            let path = PathBuf::from(&installation.path);
+            InstallationCandidate {
+                selected: active_path.as_ref() == Some(&installation.path),
+                exe_found: has_exe(game, &path),
+                writable: is_writable(&path),
+                installation,
             }
+        })
+        .collect())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn select_installation(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    path: Option<String>,
+) -> Result<(), String> {
+    let game = find_game(app_id)?;
+    let mut settings = load_installation_settings(&handle, app_id)?;
+
+    if let Some(path) = path.as_ref() {
+        let game_installation_path = Path::new(path);
+        if !has_exe(game, game_installation_path) {
+            return Err(format!(
+                "{}.exe was not found in {}",
+                game.exe_name,
+                game.exe_directory(game_installation_path).to_string_lossy()
+            ));
+        }
+        if !is_writable(game_installation_path) {
+            return Err(format!("{} is not writable", path));
+        }
+
+        // Picking a folder nothing detects adds it as a manual install.
+        let known = find_installations(&handle, game)
+            .iter()
+            .any(|installation| normalize(&installation.path) == normalize(path));
+        if !known {
+            settings.manual_paths.push(path.clone());
         }
     }
-    None
+
+    settings.selected_path = path;
+    save_installation_settings(&handle, app_id, &settings)
 }
@@ -1,8 +1,9 @@
-use std::{
-    os::windows::process::CommandExt,
-    process::{Command, Stdio},
-};
+use std::process::Stdio;
 
+use crate::utils::hidden_command::hidden_command;
+
+#[cfg(not(windows))]
+use super::is_running::running_process_ids;
 use super::supported_games::SUPPORTED_GAMES;
 
 #[tauri::command(rename_all = "snake_case")]
@@ -12,13 +13,27 @@ pub async fn force_quit(app_id: u32) -> Result<(), String> {
         .find(|game| game.steam_id == app_id)
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
 
-    let output = Command::new("taskkill")
-        .creation_flags(0x08000000)
+    #[cfg(windows)]
+    let output = hidden_command("taskkill")
         .args(["/F", "/IM", &format!("{}.exe", game.exe_name)])
         .stdout(Stdio::piped())
         .output()
         .map_err(|e| format!("Failed to force quit the game: {}", e))?;
 
+    #[cfg(not(windows))]
+    let output = {
+        let process_ids = running_process_ids(game.exe_name);
+        if process_ids.is_empty() {
+            return Ok(());
+        }
+        hidden_command("kill")
+            .arg("-9")
+            .args(process_ids.iter().map(|pid| pid.to_string()))
+            .stdout(Stdio::piped())
+            .output()
+            .map_err(|e| format!("Failed to force quit the game: {}", e))?
+    };
+
     if output.status.success() {
         Ok(())
     } else {
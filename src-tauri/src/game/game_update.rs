@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+use crate::pack::outdated_tables::{load_schema, outdated_tables};
+use crate::steam::app_manifest::find_app_manifest;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchedBuild {
+    pub build_id: u64,
+    pub last_updated: Option<u64>,
+    pub launched_at: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateCheckMod {
+    pub identifier: String,
+    pub mod_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OutdatedMod {
+    pub identifier: String,
+    pub mod_path: String,
+    pub modified_at: Option<u64>,
+    pub older_than_patch: bool,
+    pub outdated_tables: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GameUpdate {
+    pub app_id: u32,
+    pub previous_build_id: u64,
+    pub current_build_id: u64,
+    pub patched_at: Option<u64>,
+    pub outdated_mods: Vec<OutdatedMod>,
+}
+
+fn launched_build_file(handle: &tauri::AppHandle, app_id: u32) -> Result<PathBuf, String> {
+    let game_builds_dir = handle
+        .path()
+        .resolve("game_builds".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    if !game_builds_dir.exists() {
+        fs::create_dir_all(&game_builds_dir)
+            .map_err(|e| format!("Failed to create game builds directory: {}", e))?;
+    }
+
+    Ok(game_builds_dir.join(format!("{}.json", app_id)))
+}
+
+fn load_launched_build(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+) -> Result<Option<LaunchedBuild>, String> {
+    let file_path = launched_build_file(handle, app_id)?;
+    if !file_path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read launched build: {}", e))?;
+    serde_json::from_str(&content)
+        .map(Some)
+        .map_err(|e| format!("Failed to parse launched build: {}", e))
+}
+
+fn unix_seconds(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH)
+        .ok()
+        .map(|duration| duration.as_secs())
+}
+
+fn file_modified_at(path: &Path) -> Option<u64> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(unix_seconds)
+}
+
+// Packs are single files, Bannerlord modules are folders where SubModule.xml
+// and the binaries are what a patch usually breaks.
+fn mod_modified_at(mod_path: &Path) -> Option<u64> {
+    if !mod_path.is_dir() {
+        return file_modified_at(mod_path);
+    }
+
+    let mut files = vec![mod_path.join("SubModule.xml")];
+    if let Ok(entries) = fs::read_dir(mod_path.join("bin").join("Win64_Shipping_Client")) {
+        files.extend(entries.flatten().map(|entry| entry.path()).filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("dll"))
+        }));
+    }
+
+    files.iter().filter_map(|path| file_modified_at(path)).max()
+}
+
+fn outdated_mods(
+    game: &Game,
+    patched_at: Option<u64>,
+    mods: &[UpdateCheckMod],
+) -> Vec<OutdatedMod> {
+    let schema = if game.r#type == "totalwar" {
+        load_schema(game)
+            .map_err(|e| eprintln!("Skipping table checks: {}", e))
+            .ok()
+    } else {
+        None
+    };
+
+    mods.iter()
+        .filter_map(|mod_info| {
+            let mod_path = Path::new(&mod_info.mod_path);
+            let modified_at = mod_modified_at(mod_path);
+            let older_than_patch = matches!(
+                (modified_at, patched_at),
+                (Some(modified_at), Some(patched_at)) if modified_at < patched_at
+            );
+
+            let outdated_tables = match schema.as_ref() {
+                Some(schema) if mod_path.is_file() => {
+                    outdated_tables(schema, mod_path).unwrap_or_default()
+                }
+                _ => vec![],
+            };
+
+            if !older_than_patch && outdated_tables.is_empty() {
+                return None;
+            }
+
+            Some(OutdatedMod {
+                identifier: mod_info.identifier.clone(),
+                mod_path: mod_info.mod_path.clone(),
+                modified_at,
+                older_than_patch,
+                outdated_tables,
+            })
+        })
+        .collect()
+}
+
+struct BuildChange {
+    previous_build_id: u64,
+    current_build_id: u64,
+    patched_at: Option<u64>,
+}
+
+fn build_change(handle: &tauri::AppHandle, app_id: u32) -> Result<Option<BuildChange>, String> {
+    let Some(manifest) = find_app_manifest(app_id) else {
+        return Ok(None);
+    };
+    // A pending update has not changed any game files yet.
+    if manifest.update_required {
+        return Ok(None);
+    }
+
+    let (Some(current_build_id), Some(launched)) =
+        (manifest.build_id, load_launched_build(handle, app_id)?)
+    else {
+        return Ok(None);
+    };
+    if launched.build_id == current_build_id {
+        return Ok(None);
+    }
+
+    Ok(Some(BuildChange {
+        previous_build_id: launched.build_id,
+        current_build_id,
+        patched_at: manifest.last_updated,
+    }))
+}
+
+pub fn detect_game_update(
+    handle: &tauri::AppHandle,
+    game: &Game,
+    mods: &[UpdateCheckMod],
+) -> Result<Option<GameUpdate>, String> {
+    let Some(change) = build_change(handle, game.steam_id)? else {
+        return Ok(None);
+    };
+
+    Ok(Some(GameUpdate {
+        app_id: game.steam_id,
+        previous_build_id: change.previous_build_id,
+        current_build_id: change.current_build_id,
+        patched_at: change.patched_at,
+        outdated_mods: outdated_mods(game, change.patched_at, mods),
+    }))
+}
+
+// The play flow shows check_game_update before launching, a launch that still
+// finds a new build skipped that step and would hide the warning.
+pub fn ensure_update_acknowledged(handle: &tauri::AppHandle, app_id: u32) -> Result<(), String> {
+    match build_change(handle, app_id) {
+        Ok(Some(_)) => {
+            return Err(
+                "The game was updated since the last launch, acknowledge the update first"
+                    .to_string(),
+            );
+        }
+        Ok(None) => {}
+        Err(e) => eprintln!("Failed to check for game update: {}", e),
+    }
+
+    if let Err(e) = record_launched_build(handle, app_id) {
+        eprintln!("{}", e);
+    }
+    Ok(())
+}
+
+pub fn record_launched_build(handle: &tauri::AppHandle, app_id: u32) -> Result<(), String> {
+    let Some(manifest) = find_app_manifest(app_id) else {
+        return Ok(());
+    };
+    let Some(build_id) = manifest.build_id else {
+        return Ok(());
+    };
+
+    let launched = LaunchedBuild {
+        build_id,
+        last_updated: manifest.last_updated,
+        launched_at: unix_seconds(SystemTime::now()).unwrap_or_default(),
+    };
+
+    let content = serde_json::to_string_pretty(&launched)
+        .map_err(|e| format!("Failed to serialize launched build: {}", e))?;
+    fs::write(launched_build_file(handle, app_id)?, content)
+        .map_err(|e| format!("Failed to write launched build: {}", e))
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn check_game_update(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mods: Vec<UpdateCheckMod>,
+) -> Result<Option<GameUpdate>, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?
+        .clone();
+
+    let update = tokio::task::spawn_blocking(move || detect_game_update(&handle, &game, &mods))
+        .await
+        .map_err(|e| format!("Failed to check for game update: {}", e))??;
+
+    Ok(update)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn acknowledge_game_update(handle: tauri::AppHandle, app_id: u32) -> Result<(), String> {
+    record_launched_build(&handle, app_id)
+}
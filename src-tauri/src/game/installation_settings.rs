@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InstallationSettings {
+    #[serde(default)]
+    pub manual_paths: Vec<String>,
+    #[serde(default)]
+    pub selected_path: Option<String>,
+}
+
+fn installation_settings_file(handle: &tauri::AppHandle, app_id: u32) -> Result<PathBuf, String> {
+    let installations_dir = handle
+        .path()
+        .resolve("installations".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    if !installations_dir.exists() {
+        fs::create_dir_all(&installations_dir)
+            .map_err(|e| format!("Failed to create installations directory: {}", e))?;
+    }
+
+    Ok(installations_dir.join(format!("{}.json", app_id)))
+}
+
+pub fn load_installation_settings(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+) -> Result<InstallationSettings, String> {
+    let file_path = installation_settings_file(handle, app_id)?;
+    if !file_path.exists() {
+        return Ok(InstallationSettings::default());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read installation settings: {}", e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse installation settings: {}", e))
+}
+
+pub fn save_installation_settings(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    settings: &InstallationSettings,
+) -> Result<(), String> {
+    let file_path = installation_settings_file(handle, app_id)?;
+    let content = serde_json::to_string_pretty(settings)
+        .map_err(|e| format!("Failed to serialize installation settings: {}", e))?;
+    fs::write(&file_path, content)
+        .map_err(|e| format!("Failed to write installation settings: {}", e))
+}
@@ -1,29 +1,66 @@
-use std::{
-    os::windows::process::CommandExt,
-    process::{Command, Stdio},
-};
+#[cfg(windows)]
+use std::process::Stdio;
 
-use super::supported_games::SUPPORTED_GAMES;
+#[cfg(windows)]
+use crate::utils::hidden_command::hidden_command;
 
-#[tauri::command(rename_all = "snake_case")]
-pub async fn is_game_running(app_id: u32) -> Result<bool, String> {
-    let game = SUPPORTED_GAMES
-        .iter()
-        .find(|game| game.steam_id == app_id)
-        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+use super::supported_games::SUPPORTED_GAMES;
 
-    let output = Command::new("tasklist")
-        .creation_flags(0x08000000)
+#[cfg(windows)]
+pub fn is_process_running(exe_name: &str) -> Result<bool, String> {
+    let output = hidden_command("tasklist")
         .arg("/FI")
-        .arg(format!("IMAGENAME eq {}.exe", game.exe_name))
+        .arg(format!("IMAGENAME eq {}.exe", exe_name))
         .stdout(Stdio::piped())
         .output()
         .map_err(|e| format!("Failed to check if the game is running: {}", e))?;
 
     if output.status.success() {
         let output_str = String::from_utf8_lossy(&output.stdout);
-        Ok(output_str.contains(game.exe_name))
+        Ok(output_str.contains(exe_name))
     } else {
         Err("Failed to check running processes".into())
     }
 }
+
+// Proton runs games through wine, so the Windows executable shows up as an
+// argument of the process rather than as its name.
+#[cfg(not(windows))]
+pub fn running_process_ids(exe_name: &str) -> Vec<u32> {
+    let target = format!("{}.exe", exe_name).to_lowercase();
+    let Ok(entries) = std::fs::read_dir("/proc") else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let pid = entry.file_name().to_str()?.parse::<u32>().ok()?;
+            let cmdline = std::fs::read(entry.path().join("cmdline")).ok()?;
+            cmdline
+                .split(|byte| *byte == 0)
+                .any(|arg| {
+                    let arg = String::from_utf8_lossy(arg)
+                        .to_lowercase()
+                        .replace('\\', "/");
+                    arg.rsplit('/').next() == Some(target.as_str())
+                })
+                .then_some(pid)
+        })
+        .collect()
+}
+
+#[cfg(not(windows))]
+pub fn is_process_running(exe_name: &str) -> Result<bool, String> {
+    Ok(!running_process_ids(exe_name).is_empty())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn is_game_running(app_id: u32) -> Result<bool, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    is_process_running(game.exe_name)
+}
@@ -0,0 +1,214 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+use super::find_installation_path::find_installation;
+use super::storefronts::installation::{Installation, Storefront};
+use super::supported_games::SUPPORTED_GAMES;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchMode {
+    #[default]
+    Direct,
+    Steam,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LaunchOptions {
+    #[serde(default)]
+    pub launcher: Option<String>,
+    #[serde(default)]
+    pub args: Vec<String>,
+    #[serde(default)]
+    pub executable: Option<String>,
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    #[serde(default)]
+    pub launch_mode: LaunchMode,
+}
+
+impl LaunchOptions {
+    // Steam picks the executable itself and the game does not inherit the
+    // environment of an already running client, so neither can be honored.
+    pub fn resolve_launch_mode(&self, installation: &Installation) -> Result<LaunchMode, String> {
+        let launch_mode = if cfg!(not(windows)) && installation.storefront == Storefront::Steam {
+            // Proton prefixes are only set up when Steam starts the game.
+            LaunchMode::Steam
+        } else {
+            self.launch_mode
+        };
+
+        if launch_mode == LaunchMode::Steam {
+            if installation.storefront != Storefront::Steam {
+                return Err(format!(
+                    "Installations from {} cannot be started through Steam",
+                    installation.storefront.label()
+                ));
+            }
+            if let Some(executable) = self.executable.as_ref() {
+                return Err(format!(
+                    "{} cannot be started through Steam, launch the game directly to use it",
+                    executable
+                ));
+            }
+            if !self.env.is_empty() {
+                return Err(
+                    "Environment variables are not passed on through Steam, add them to the game's launch options in Steam instead"
+                        .to_string(),
+                );
+            }
+        }
+
+        Ok(launch_mode)
+    }
+
+    pub fn executable_path(
+        &self,
+        exe_directory: &Path,
+        default_exe_name: &str,
+    ) -> Result<PathBuf, String> {
+        let exe_name = self.executable.as_deref().unwrap_or(default_exe_name);
+        if Path::new(exe_name).components().count() != 1 {
+            return Err(format!(
+                "{} must be the name of an executable next to the game's",
+                exe_name
+            ));
+        }
+
+        let exe_path = exe_directory.join(exe_name);
+        if !exe_path.is_file() {
+            return Err(format!(
+                "{} was not found in {}",
+                exe_name,
+                exe_directory.to_string_lossy()
+            ));
+        }
+        Ok(exe_path)
+    }
+}
+
+// The process list reports the executable without its extension.
+pub fn exe_process_name(exe_path: &Path) -> String {
+    exe_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+fn launch_options_file(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<PathBuf, String> {
+    let launch_options_dir = handle
+        .path()
+        .resolve("launch_options".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?
+        .join(app_id.to_string());
+
+    if !launch_options_dir.exists() {
+        fs::create_dir_all(&launch_options_dir)
+            .map_err(|e| format!("Failed to create launch options directory: {}", e))?;
+    }
+
+    Ok(launch_options_dir.join(format!("{}.json", profile_id)))
+}
+
+pub fn load_launch_options(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<LaunchOptions, String> {
+    let file_path = launch_options_file(handle, app_id, profile_id)?;
+    if !file_path.exists() {
+        return Ok(LaunchOptions::default());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read launch options: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse launch options: {}", e))
+}
+
+pub fn profile_launch_options(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: Option<u64>,
+) -> Result<LaunchOptions, String> {
+    match profile_id {
+        Some(profile_id) => load_launch_options(handle, app_id, profile_id),
+        None => Ok(LaunchOptions::default()),
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn launch_options(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<LaunchOptions, String> {
+    load_launch_options(&handle, app_id, profile_id)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn set_launch_options(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    options: LaunchOptions,
+) -> Result<(), String> {
+    if options
+        .env
+        .keys()
+        .any(|key| key.is_empty() || key.contains('='))
+    {
+        return Err("Environment variable names cannot be empty or contain '='".to_string());
+    }
+
+    let file_path = launch_options_file(&handle, app_id, profile_id)?;
+    let json = serde_json::to_string_pretty(&options)
+        .map_err(|e| format!("Failed to serialize launch options: {}", e))?;
+    fs::write(&file_path, json).map_err(|e| format!("Failed to write launch options: {}", e))
+}
+
+// Lists the executables a profile can pick from, e.g. a DX11 and a DX12 build.
+#[tauri::command(rename_all = "snake_case")]
+pub async fn game_executables(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<Vec<String>, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    let installation = find_installation(&handle, game).ok_or_else(|| {
+        format!(
+            "Could not find installation path for game with app_id {}",
+            app_id
+        )
+    })?;
+    let exe_directory = game.exe_directory(Path::new(&installation.path));
+
+    let mut executables: Vec<String> = fs::read_dir(&exe_directory)
+        .map_err(|e| format!("Failed to read game directory: {}", e))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|extension| extension.eq_ignore_ascii_case("exe"))
+        })
+        .filter_map(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .collect();
+    executables.sort();
+
+    Ok(executables)
+}
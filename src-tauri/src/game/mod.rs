@@ -1,13 +1,23 @@
 pub mod bannerlord;
+pub mod config_snapshots;
 pub mod delete_save_file;
+pub mod deployment_ledger;
+pub mod exit_watch;
 pub mod fetch_save_file_meta;
 pub mod find_installation_path;
 pub mod force_quit;
+pub mod game_update;
+pub mod installation_settings;
 pub mod is_running;
+pub mod launch_options;
 pub mod migrate_legacy_meta_files;
+pub mod preflight;
+#[cfg(not(windows))]
+pub mod proton_prefix;
 pub mod save_files;
 pub mod save_folder_watch;
 pub mod set_watch_save_folder;
+pub mod storefronts;
 pub mod supported_games;
 pub mod totalwar;
 pub mod upsert_save_file_meta;
@@ -0,0 +1,152 @@
+use serde::Serialize;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::r#mod::conflict_acknowledgements::normalize_path;
+use crate::r#mod::conflict_report::{ConflictSeverity, build_conflict_report};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreflightSeverity {
+    Warning,
+    Blocking,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PreflightIssue {
+    pub kind: String,
+    pub severity: PreflightSeverity,
+    pub subject: String,
+    pub message: String,
+}
+
+impl PreflightIssue {
+    pub fn blocking(kind: &str, subject: &str, message: String) -> Self {
+        PreflightIssue {
+            kind: kind.to_string(),
+            severity: PreflightSeverity::Blocking,
+            subject: subject.to_string(),
+            message,
+        }
+    }
+
+    pub fn warning(kind: &str, subject: &str, message: String) -> Self {
+        PreflightIssue {
+            kind: kind.to_string(),
+            severity: PreflightSeverity::Warning,
+            subject: subject.to_string(),
+            message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlannedDeployment {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PreflightReport {
+    pub app_id: u32,
+    pub profile_id: u64,
+    pub generated_at: u128,
+    pub command: Vec<String>,
+    pub used_mods_txt: Option<String>,
+    pub deployments: Vec<PlannedDeployment>,
+    pub issues: Vec<PreflightIssue>,
+    pub blocking: bool,
+}
+
+impl PreflightReport {
+    pub fn new(
+        app_id: u32,
+        profile_id: u64,
+        command: Vec<String>,
+        used_mods_txt: Option<String>,
+        deployments: Vec<PlannedDeployment>,
+        issues: Vec<PreflightIssue>,
+    ) -> Result<Self, String> {
+        let generated_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("Time error: {}", e))?
+            .as_millis();
+
+        Ok(PreflightReport {
+            app_id,
+            profile_id,
+            generated_at,
+            command,
+            used_mods_txt,
+            deployments,
+            blocking: issues
+                .iter()
+                .any(|issue| issue.severity == PreflightSeverity::Blocking),
+            issues,
+        })
+    }
+
+    pub fn blocking_message(&self) -> String {
+        let messages: Vec<String> = self
+            .issues
+            .iter()
+            .filter(|issue| issue.severity == PreflightSeverity::Blocking)
+            .map(|issue| format!("{}: {}", issue.subject, issue.message))
+            .collect();
+        format!("Launch blocked:\n{}", messages.join("\n"))
+    }
+}
+
+// Reads back what a launch would run without spawning anything.
+pub fn command_line(command: &Command) -> Vec<String> {
+    std::iter::once(command.get_program())
+        .chain(command.get_args())
+        .map(|part| part.to_string_lossy().into_owned())
+        .collect()
+}
+
+// Only pairs of mods that are both launched matter, acknowledged pairs were
+// already looked at by the user.
+pub async fn conflict_issues(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    folder_paths: Vec<String>,
+    active_paths: &[String],
+) -> Result<Vec<PreflightIssue>, String> {
+    if folder_paths.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let active_paths: Vec<String> = active_paths
+        .iter()
+        .map(|path| normalize_path(path))
+        .collect();
+    let report = build_conflict_report(handle, app_id, profile_id, folder_paths).await?;
+
+    Ok(report
+        .entries
+        .iter()
+        .filter(|entry| {
+            active_paths.contains(&normalize_path(&entry.mod_file_path))
+                && active_paths.contains(&normalize_path(&entry.other_mod_file_path))
+        })
+        .filter_map(|entry| {
+            let paths = entry
+                .paths
+                .iter()
+                .filter(|path| path.severity == ConflictSeverity::High && !path.acknowledged)
+                .count();
+            (paths > 0).then(|| {
+                PreflightIssue::blocking(
+                    "conflict",
+                    &entry.mod_file,
+                    format!(
+                        "Overrides {} high severity files of {}",
+                        paths, entry.other_mod_file
+                    ),
+                )
+            })
+        })
+        .collect())
+}
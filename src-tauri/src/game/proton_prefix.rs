@@ -0,0 +1,62 @@
+use std::path::{Path, PathBuf};
+
+use super::find_installation_path::steam_installation_paths;
+use super::storefronts::{epic, gog};
+use super::supported_games::Game;
+use crate::steam::library_folders::steam_libraries;
+use crate::steam::steam_paths::steam_paths;
+
+fn user_folder(steamapps_path: &Path, app_id: u32) -> PathBuf {
+    steamapps_path
+        .join("compatdata")
+        .join(app_id.to_string())
+        .join("pfx")
+        .join("drive_c")
+        .join("users")
+        .join("steamuser")
+}
+
+// Steam creates the prefix in the library the game is installed in, older
+// setups keep every prefix in the main Steam library instead.
+pub fn proton_user_folder(game: &Game) -> Option<PathBuf> {
+    if let Some(game_installation_path) = steam_installation_paths(&steam_libraries(), game)
+        .into_iter()
+        .next()
+    {
+        if let Some(steamapps_path) = game_installation_path
+            .parent()
+            .and_then(|common| common.parent())
+        {
+            let user_folder = user_folder(steamapps_path, game.steam_id);
+            if user_folder.is_dir() {
+                return Some(user_folder);
+            }
+        }
+    }
+
+    let steam_user_folder = steam_paths()
+        .ok()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|steam_path| user_folder(&Path::new(&steam_path).join("steamapps"), game.steam_id))
+        .find(|user_folder| user_folder.is_dir());
+    if steam_user_folder.is_some() {
+        return steam_user_folder;
+    }
+
+    // Epic and GOG installs run in the wine prefix Heroic made for them, whose
+    // user folder is named after the host user unless Proton is the runner.
+    epic::installations(game)
+        .into_iter()
+        .chain(gog::installations(game))
+        .filter_map(|installation| installation.wine_prefix)
+        .flat_map(|wine_prefix| {
+            let users = Path::new(&wine_prefix).join("drive_c").join("users");
+            let mut user_folders = vec![users.join("steamuser")];
+            if let Ok(user) = std::env::var("USER") {
+                user_folders.insert(0, users.join(user));
+            }
+            user_folders
+        })
+        .find(|user_folder| user_folder.is_dir())
+}
@@ -0,0 +1,106 @@
+#[cfg(windows)]
+use serde::Deserialize;
+#[cfg(windows)]
+use std::fs;
+use std::path::PathBuf;
+
+use crate::game::find_installation_path::has_exe;
+use crate::game::supported_games::Game;
+
+#[cfg(not(windows))]
+use super::heroic::{heroic_config_dirs, read_json, wine_prefix};
+use super::installation::{Installation, Storefront};
+
+#[cfg(windows)]
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct EpicManifest {
+    install_location: String,
+    app_name: String,
+    #[serde(default, rename = "bIsIncompleteInstall")]
+    is_incomplete_install: bool,
+}
+
+// The launcher keeps one .item manifest per installed game.
+#[cfg(windows)]
+fn installed_games() -> Vec<(String, PathBuf)> {
+    let program_data =
+        std::env::var("PROGRAMDATA").unwrap_or_else(|_| "C:\\ProgramData".to_string());
+    let manifests_path = PathBuf::from(program_data)
+        .join("Epic")
+        .join("EpicGamesLauncher")
+        .join("Data")
+        .join("Manifests");
+
+    let Ok(entries) = fs::read_dir(manifests_path) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "item"))
+        .filter_map(|path| {
+            let content = fs::read_to_string(path).ok()?;
+            let manifest: EpicManifest = serde_json::from_str(&content).ok()?;
+            (!manifest.is_incomplete_install)
+                .then(|| (manifest.app_name, PathBuf::from(manifest.install_location)))
+        })
+        .collect()
+}
+
+// Heroic installs Epic games through legendary and shares its installed.json.
+#[cfg(not(windows))]
+fn installed_games() -> Vec<(String, PathBuf)> {
+    let mut installed_files: Vec<PathBuf> = heroic_config_dirs()
+        .into_iter()
+        .map(|config_dir| {
+            config_dir
+                .join("legendaryConfig")
+                .join("legendary")
+                .join("installed.json")
+        })
+        .collect();
+    if let Some(config_dir) = dirs::config_dir() {
+        installed_files.push(config_dir.join("legendary").join("installed.json"));
+    }
+
+    installed_files
+        .iter()
+        .filter_map(|installed_file| read_json(installed_file))
+        .flat_map(|installed| {
+            installed
+                .as_object()
+                .map(|games| {
+                    games
+                        .iter()
+                        .filter_map(|(app_name, game)| {
+                            let install_path = game.get("install_path")?.as_str()?;
+                            Some((app_name.clone(), PathBuf::from(install_path)))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+// Epic ids are opaque, the game executable is what identifies the install.
+pub fn installations(game: &Game) -> Vec<Installation> {
+    installed_games()
+        .into_iter()
+        .filter(|(_, install_path)| has_exe(game, install_path))
+        .map(|(app_name, install_path)| {
+            #[cfg(windows)]
+            let prefix = None;
+            #[cfg(not(windows))]
+            let prefix = wine_prefix(&app_name);
+            Installation::new(
+                install_path.to_string_lossy().into_owned(),
+                Storefront::Epic,
+                Some(app_name),
+                prefix,
+            )
+        })
+        .collect()
+}
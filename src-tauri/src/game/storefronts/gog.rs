@@ -0,0 +1,74 @@
+use std::path::PathBuf;
+#[cfg(windows)]
+use winreg::RegKey;
+#[cfg(windows)]
+use winreg::enums::HKEY_LOCAL_MACHINE;
+
+use crate::game::find_installation_path::has_exe;
+use crate::game::supported_games::Game;
+
+#[cfg(not(windows))]
+use super::heroic::{heroic_config_dirs, read_json, wine_prefix};
+use super::installation::{Installation, Storefront};
+
+// GOG Galaxy and the offline installers both register games here.
+#[cfg(windows)]
+fn installed_games() -> Vec<(String, PathBuf)> {
+    let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+    let Ok(games_key) = hklm.open_subkey("SOFTWARE\\WOW6432Node\\GOG.com\\Games") else {
+        return vec![];
+    };
+
+    games_key
+        .enum_keys()
+        .flatten()
+        .filter_map(|game_id| {
+            let game_key = games_key.open_subkey(&game_id).ok()?;
+            let path = game_key.get_value::<String, _>("path").ok()?;
+            Some((game_id, PathBuf::from(path)))
+        })
+        .collect()
+}
+
+#[cfg(not(windows))]
+fn installed_games() -> Vec<(String, PathBuf)> {
+    heroic_config_dirs()
+        .into_iter()
+        .filter_map(|config_dir| read_json(&config_dir.join("gog_store").join("installed.json")))
+        .flat_map(|installed| {
+            installed
+                .get("installed")
+                .and_then(|games| games.as_array())
+                .map(|games| {
+                    games
+                        .iter()
+                        .filter_map(|game| {
+                            let app_name = game.get("appName")?.as_str()?;
+                            let install_path = game.get("install_path")?.as_str()?;
+                            Some((app_name.to_string(), PathBuf::from(install_path)))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+pub fn installations(game: &Game) -> Vec<Installation> {
+    installed_games()
+        .into_iter()
+        .filter(|(_, install_path)| has_exe(game, install_path))
+        .map(|(game_id, install_path)| {
+            #[cfg(windows)]
+            let prefix = None;
+            #[cfg(not(windows))]
+            let prefix = wine_prefix(&game_id);
+            Installation::new(
+                install_path.to_string_lossy().into_owned(),
+                Storefront::Gog,
+                Some(game_id),
+                prefix,
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,44 @@
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const FLATPAK_HEROIC_ID: &str = "com.heroicgameslauncher.hgl";
+
+// Heroic manages Epic (through legendary) and GOG games on Linux.
+pub fn heroic_config_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(config_dir) = dirs::config_dir() {
+        dirs.push(config_dir.join("heroic"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(
+            home.join(".var")
+                .join("app")
+                .join(FLATPAK_HEROIC_ID)
+                .join("config")
+                .join("heroic"),
+        );
+    }
+    dirs.into_iter().filter(|dir| dir.is_dir()).collect()
+}
+
+pub fn read_json(path: &Path) -> Option<Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub fn wine_prefix(app_name: &str) -> Option<String> {
+    heroic_config_dirs().into_iter().find_map(|config_dir| {
+        let games_config = read_json(
+            &config_dir
+                .join("GamesConfig")
+                .join(format!("{}.json", app_name)),
+        )?;
+        games_config
+            .get(app_name)?
+            .get("winePrefix")?
+            .as_str()
+            .filter(|prefix| !prefix.is_empty())
+            .map(|prefix| prefix.to_string())
+    })
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+#[cfg(not(windows))]
+use std::path::Path;
+#[cfg(not(windows))]
+use std::process::Command;
+
+#[cfg(not(windows))]
+use crate::utils::hidden_command::hidden_command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Storefront {
+    Steam,
+    Epic,
+    Gog,
+    Manual,
+}
+
+impl Storefront {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Storefront::Steam => "Steam",
+            Storefront::Epic => "Epic Games Store",
+            Storefront::Gog => "GOG",
+            Storefront::Manual => "manually added",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Installation {
+    pub path: String,
+    pub storefront: Storefront,
+    pub store_id: Option<String>,
+    pub wine_prefix: Option<String>,
+    pub workshop_supported: bool,
+}
+
+impl Installation {
+    pub fn new(
+        path: String,
+        storefront: Storefront,
+        store_id: Option<String>,
+        wine_prefix: Option<String>,
+    ) -> Self {
+        Installation {
+            path,
+            storefront,
+            store_id,
+            wine_prefix,
+            workshop_supported: storefront == Storefront::Steam,
+        }
+    }
+}
+
+// Installs outside of Steam cannot go through -applaunch, they are started
+// with wine in the prefix their launcher set up for them.
+#[cfg(not(windows))]
+pub fn wine_command(installation: &Installation, exe_path: &Path, args: &[String]) -> Command {
+    let mut command = hidden_command("wine");
+    command.arg(exe_path).args(args);
+    if let Some(exe_directory) = exe_path.parent() {
+        command.current_dir(exe_directory);
+    }
+    if let Some(wine_prefix) = installation.wine_prefix.as_ref() {
+        command.env("WINEPREFIX", wine_prefix);
+    }
+    command
+}
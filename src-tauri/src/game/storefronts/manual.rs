@@ -0,0 +1,64 @@
+use std::path::Path;
+
+use crate::game::find_installation_path::has_exe;
+use crate::game::installation_settings::{load_installation_settings, save_installation_settings};
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+
+use super::installation::{Installation, Storefront};
+
+pub fn manual_installations(handle: &tauri::AppHandle, game: &Game) -> Vec<Installation> {
+    let Ok(settings) = load_installation_settings(handle, game.steam_id) else {
+        return vec![];
+    };
+
+    settings
+        .manual_paths
+        .into_iter()
+        .filter(|path| has_exe(game, Path::new(path)))
+        .map(|path| Installation::new(path, Storefront::Manual, None, None))
+        .collect()
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn add_manual_installation(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    path: String,
+) -> Result<Installation, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if !has_exe(game, Path::new(&path)) {
+        return Err(format!(
+            "{}.exe was not found in {}",
+            game.exe_name,
+            game.exe_directory(Path::new(&path)).to_string_lossy()
+        ));
+    }
+
+    let mut settings = load_installation_settings(&handle, app_id)?;
+    if !settings.manual_paths.contains(&path) {
+        settings.manual_paths.push(path.clone());
+        save_installation_settings(&handle, app_id, &settings)?;
+    }
+
+    Ok(Installation::new(path, Storefront::Manual, None, None))
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn remove_manual_installation(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    path: String,
+) -> Result<(), String> {
+    let mut settings = load_installation_settings(&handle, app_id)?;
+    settings
+        .manual_paths
+        .retain(|manual_path| manual_path != &path);
+    if settings.selected_path.as_ref() == Some(&path) {
+        settings.selected_path = None;
+    }
+    save_installation_settings(&handle, app_id, &settings)
+}
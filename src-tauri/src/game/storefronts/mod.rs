@@ -0,0 +1,6 @@
+pub mod epic;
+pub mod gog;
+#[cfg(not(windows))]
+pub mod heroic;
+pub mod installation;
+pub mod manual;
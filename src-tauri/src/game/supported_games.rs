@@ -1,9 +1,16 @@
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex, OnceLock};
+use tauri::Manager;
+use tauri::path::BaseDirectory;
 
+#[cfg(not(windows))]
+use super::proton_prefix::proton_user_folder;
+#[cfg(windows)]
 use crate::utils::roaming_folder::roaming_folder;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Clone)]
 pub struct Game {
     pub name: &'static str,
     pub slug: &'static str,
@@ -18,76 +25,290 @@ pub struct Game {
     pub nexus_id: u128,
     pub r#type: &'static str,
     pub schema_file: &'static str,
+    pub pack_versions: &'static [&'static str],
+    pub working_directories: bool,
 }
 
-pub const SUPPORTED_GAMES: &[Game] = &[
-    Game {
-        name: "Total War: WARHAMMER 2",
-        slug: "tww2",
-        slug_opt: "wh2",
-        save_path_folder_name: "Warhammer2",
-        save_file_extension: "save",
-        exe_name: "Warhammer2",
-        exe_folder: "",
-        steam_id: 594570,
-        steam_folder_name: "Total War WARHAMMER II",
-        nexus_slug: "totalwarwarhammer2",
-        nexus_id: 2436,
-        r#type: "totalwar",
-        schema_file: "schema_wh2.ron",
-    },
-    Game {
-        name: "Total War: WARHAMMER 3",
-        slug: "tww3",
-        slug_opt: "wh3",
-        save_path_folder_name: "Warhammer3",
-        save_file_extension: "save",
-        exe_name: "Warhammer3",
-        exe_folder: "",
-        steam_id: 1142710,
-        steam_folder_name: "Total War WARHAMMER III",
-        nexus_slug: "totalwarwarhammer3",
-        nexus_id: 4717,
-        r#type: "totalwar",
-        schema_file: "schema_wh3.ron",
-    },
-    Game {
-        name: "Total War: Three Kingdoms",
-        slug: "tw3k",
-        slug_opt: "threeKingdoms",
-        save_path_folder_name: "ThreeKingdoms",
-        save_file_extension: "save",
-        exe_name: "Three_Kingdoms",
-        exe_folder: "",
-        steam_id: 779340,
-        steam_folder_name: "Total War THREE KINGDOMS",
-        nexus_slug: "totalwarthreekingdoms",
-        nexus_id: 2847,
-        r#type: "totalwar",
-        schema_file: "schema_3k.ron",
-    },
-    Game {
-        name: "Mount & Blade: Bannerlord",
-        slug: "mbbl",
-        slug_opt: "mbbl",
-        save_path_folder_name: "Mount and Blade II Bannerlord",
-        save_file_extension: "sav",
-        exe_name: "Bannerlord",
-        exe_folder: "bin\\Win64_Shipping_Client",
-        steam_id: 261550,
-        steam_folder_name: "Mount & Blade II Bannerlord",
-        nexus_slug: "mountandblade2bannerlord",
-        nexus_id: 3174,
-        r#type: "bannerlord",
-        schema_file: "",
-    },
+const GAME_TYPES: &[&str] = &["totalwar", "bannerlord"];
+const PACK_VERSIONS: &[&str] = &["PFH0", "PFH2", "PFH3", "PFH4", "PFH5", "PFH6"];
+
+const BUNDLED_DEFINITIONS: &[(&str, &str)] = &[
+    ("tww2.json", include_str!("../../games/tww2.json")),
+    ("tww3.json", include_str!("../../games/tww3.json")),
+    ("tw3k.json", include_str!("../../games/tw3k.json")),
+    ("mbbl.json", include_str!("../../games/mbbl.json")),
+    ("tww1.json", include_str!("../../games/tww1.json")),
+    ("rome2.json", include_str!("../../games/rome2.json")),
+    ("attila.json", include_str!("../../games/attila.json")),
+    ("troy.json", include_str!("../../games/troy.json")),
+    ("pharaoh.json", include_str!("../../games/pharaoh.json")),
 ];
 
+static USER_DEFINITIONS_DIR: OnceLock<PathBuf> = OnceLock::new();
+static DEFINITION_ERRORS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+pub static SUPPORTED_GAMES: LazyLock<Vec<Game>> = LazyLock::new(load_game_definitions);
+
+#[derive(Debug, Clone, Deserialize)]
+struct GameDefinition {
+    name: String,
+    slug: String,
+    #[serde(default)]
+    slug_opt: String,
+    save_path_folder_name: String,
+    save_file_extension: String,
+    exe_name: String,
+    #[serde(default)]
+    exe_folder: String,
+    steam_id: u32,
+    #[serde(default)]
+    steam_folder_name: String,
+    #[serde(default)]
+    nexus_slug: String,
+    #[serde(default)]
+    nexus_id: u128,
+    r#type: String,
+    #[serde(default)]
+    schema_file: String,
+    // Pack header versions the game loads, empty accepts any.
+    #[serde(default)]
+    pack_versions: Vec<String>,
+    // Older titles only load packs from data and ignore add_working_directory.
+    #[serde(default = "default_working_directories")]
+    working_directories: bool,
+}
+
+fn default_working_directories() -> bool {
+    true
+}
+
+// Definitions are read once and live for the whole run, leaking their strings
+// keeps the &'static fields every caller already borrows from.
+fn leak(value: String) -> &'static str {
+    Box::leak(value.into_boxed_str())
+}
+
+impl GameDefinition {
+    fn validate(&self) -> Result<(), String> {
+        let required = [
+            ("name", &self.name),
+            ("slug", &self.slug),
+            ("save_path_folder_name", &self.save_path_folder_name),
+            ("save_file_extension", &self.save_file_extension),
+            ("exe_name", &self.exe_name),
+        ];
+        for (field, value) in required {
+            if value.trim().is_empty() {
+                return Err(format!("{} must not be empty", field));
+            }
+        }
+
+        if self.steam_id == 0 {
+            return Err("steam_id must not be 0".to_string());
+        }
+        if !GAME_TYPES.contains(&self.r#type.as_str()) {
+            return Err(format!("Unknown game type '{}'", self.r#type));
+        }
+        if self.r#type == "totalwar" && self.schema_file.is_empty() {
+            return Err("Total War games need a schema_file".to_string());
+        }
+        if let Some(pack_version) = self
+            .pack_versions
+            .iter()
+            .find(|pack_version| !PACK_VERSIONS.contains(&pack_version.as_str()))
+        {
+            return Err(format!("Unknown pack version '{}'", pack_version));
+        }
+        if self.exe_name.to_lowercase().ends_with(".exe") {
+            return Err("exe_name must not include the .exe extension".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn into_game(self) -> Game {
+        Game {
+            slug_opt: leak(if self.slug_opt.is_empty() {
+                self.slug.clone()
+            } else {
+                self.slug_opt
+            }),
+            steam_folder_name: leak(if self.steam_folder_name.is_empty() {
+                self.name.clone()
+            } else {
+                self.steam_folder_name
+            }),
+            name: leak(self.name),
+            slug: leak(self.slug),
+            save_path_folder_name: leak(self.save_path_folder_name),
+            save_file_extension: leak(self.save_file_extension),
+            exe_name: leak(self.exe_name),
+            exe_folder: leak(self.exe_folder),
+            steam_id: self.steam_id,
+            nexus_slug: leak(self.nexus_slug),
+            nexus_id: self.nexus_id,
+            r#type: leak(self.r#type),
+            schema_file: leak(self.schema_file),
+            pack_versions: Box::leak(
+                self.pack_versions
+                    .into_iter()
+                    .map(leak)
+                    .collect::<Vec<_>>()
+                    .into_boxed_slice(),
+            ),
+            working_directories: self.working_directories,
+        }
+    }
+}
+
+// A file holds either a single definition or a list of them.
+fn parse_definitions(source: &str, content: &str) -> Result<Vec<GameDefinition>, String> {
+    let value: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| format!("Failed to parse game definitions in {}: {}", source, e))?;
+    let values = match value {
+        serde_json::Value::Array(values) => values,
+        value => vec![value],
+    };
+
+    values
+        .into_iter()
+        .map(|value| {
+            serde_json::from_value(value)
+                .map_err(|e| format!("Invalid game definition in {}: {}", source, e))
+        })
+        .collect()
+}
+
+fn user_definition_files() -> Vec<PathBuf> {
+    let Some(Ok(entries)) = USER_DEFINITIONS_DIR.get().map(fs::read_dir) else {
+        return vec![];
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    files
+}
+
+// User definitions replace the bundled one with the same steam_id, which is
+// how community forks of a supported title are set up.
+fn load_game_definitions() -> Vec<Game> {
+    let mut errors = Vec::new();
+    let mut definitions: Vec<GameDefinition> = Vec::new();
+
+    let bundled = BUNDLED_DEFINITIONS
+        .iter()
+        .map(|(source, content)| (source.to_string(), Ok(content.to_string())));
+    let user = user_definition_files().into_iter().map(|path| {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e));
+        (path.to_string_lossy().to_string(), content)
+    });
+
+    for (source, content) in bundled.chain(user) {
+        let parsed = content.and_then(|content| parse_definitions(&source, &content));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+
+        for definition in parsed {
+            if let Err(e) = definition.validate() {
+                errors.push(format!("{} in {}: {}", definition.slug, source, e));
+                continue;
+            }
+
+            if definitions.iter().any(|existing| {
+                existing.slug == definition.slug && existing.steam_id != definition.steam_id
+            }) {
+                errors.push(format!(
+                    "{} in {}: slug is already used by another game",
+                    definition.slug, source
+                ));
+                continue;
+            }
+
+            match definitions
+                .iter_mut()
+                .find(|existing| existing.steam_id == definition.steam_id)
+            {
+                Some(existing) => *existing = definition,
+                None => definitions.push(definition),
+            }
+        }
+    }
+
+    for error in errors.iter() {
+        eprintln!("{}", error);
+    }
+    if let Ok(mut definition_errors) = DEFINITION_ERRORS.lock() {
+        *definition_errors = errors;
+    }
+
+    definitions
+        .into_iter()
+        .map(|definition| definition.into_game())
+        .collect()
+}
+
+// Runs before any command so user definitions are part of the first load.
+pub fn init_game_definitions(handle: &tauri::AppHandle) {
+    match handle
+        .path()
+        .resolve("game_definitions".to_string(), BaseDirectory::AppConfig)
+    {
+        Ok(user_definitions_dir) => {
+            let _ = fs::create_dir_all(&user_definitions_dir);
+            let _ = USER_DEFINITIONS_DIR.set(user_definitions_dir);
+        }
+        Err(e) => eprintln!("Failed to resolve App Config directory: {}", e),
+    }
+
+    LazyLock::force(&SUPPORTED_GAMES);
+}
+
 impl Game {
+    pub fn exe_directory(&self, game_installation_path: &Path) -> PathBuf {
+        self.exe_folder
+            .split('\\')
+            .filter(|folder| !folder.is_empty())
+            .fold(game_installation_path.to_path_buf(), |path, folder| {
+                path.join(folder)
+            })
+    }
+
+    #[cfg(windows)]
+    pub fn roaming_folder(&self) -> Option<PathBuf> {
+        roaming_folder()
+    }
+
+    // On Linux the game writes into its Proton prefix, not the host's folders.
+    #[cfg(not(windows))]
+    pub fn roaming_folder(&self) -> Option<PathBuf> {
+        proton_user_folder(self).map(|user_folder| user_folder.join("AppData").join("Roaming"))
+    }
+
+    #[cfg(windows)]
+    pub fn documents_folder(&self) -> Option<PathBuf> {
+        dirs::document_dir()
+    }
+
+    #[cfg(not(windows))]
+    pub fn documents_folder(&self) -> Option<PathBuf> {
+        proton_user_folder(self).map(|user_folder| user_folder.join("Documents"))
+    }
+
     pub fn save_path_folder(&self) -> Result<String, String> {
         match self.r#type {
             "totalwar" => {
-                let roaming_folder = roaming_folder()
+                let roaming_folder = self
+                    .roaming_folder()
                     .ok_or_else(|| "Roaming folder could not be resolved.".to_string())?;
                 let save_folder_path = Path::new(&roaming_folder)
                     .join("The Creative Assembly")
@@ -96,7 +317,8 @@ impl Game {
                 Ok(save_folder_path.to_string_lossy().to_string())
             }
             "bannerlord" => {
-                let documents = dirs::document_dir()
+                let documents = self
+                    .documents_folder()
                     .ok_or_else(|| "Documents folder could not be resolved.".to_string())?;
                 let save_folder_path = documents
                     .join(self.save_path_folder_name)
@@ -123,6 +345,8 @@ pub struct GameWithPath<'a> {
     pub nexus_id: u128,
     pub r#type: &'a str,
     pub schema_file: &'a str,
+    pub pack_versions: &'a [&'a str],
+    pub working_directories: bool,
 }
 
 #[tauri::command(rename_all = "snake_case")]
@@ -145,8 +369,18 @@ pub fn supported_games() -> Result<Vec<GameWithPath<'static>>, String> {
             nexus_id: game.nexus_id,
             r#type: game.r#type,
             schema_file: game.schema_file,
+            pack_versions: game.pack_versions,
+            working_directories: game.working_directories,
         });
     }
 
     Ok(games_with_paths)
 }
+
+#[tauri::command(rename_all = "snake_case")]
+pub fn game_definition_errors() -> Result<Vec<String>, String> {
+    DEFINITION_ERRORS
+        .lock()
+        .map(|errors| errors.clone())
+        .map_err(|e| format!("Failed to read game definition errors: {}", e))
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::game::deployment_ledger::{ensure_game_closed, purge_ledger, save_ledger};
+
+use super::used_mods::UsedPack;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployedPack {
+    pub source: String,
+    pub target: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackLedger {
+    pub app_id: u32,
+    pub deployed_at: u128,
+    pub packs: Vec<DeployedPack>,
+}
+
+const LEDGER_DIRECTORY: &str = "pack_deployments";
+
+fn remove_pack(pack: &DeployedPack) -> Result<bool, String> {
+    let target = Path::new(&pack.target);
+    if !target.exists() {
+        return Ok(false);
+    }
+    fs::remove_file(target)
+        .map(|()| true)
+        .map_err(|e| format!("Failed to remove {}: {}", target.display(), e))
+}
+
+pub fn purge(handle: &tauri::AppHandle, app_id: u32) -> Result<usize, String> {
+    purge_ledger(
+        handle,
+        LEDGER_DIRECTORY,
+        app_id,
+        |ledger: &mut PackLedger| &mut ledger.packs,
+        remove_pack,
+    )
+}
+
+// Titles without add_working_directory only see packs inside data, so the
+// profile's packs are hard linked there, or copied across volumes, under the
+// name the game loads them by.
+pub fn deploy_packs(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    data_path: &Path,
+    packs: &[UsedPack],
+) -> Result<(), String> {
+    purge(handle, app_id)?;
+
+    let mut deployed = Vec::new();
+    let mut failure = None;
+    for used_pack in packs {
+        let source = &used_pack.pack_path;
+        let target = data_path.join(&used_pack.pack_name);
+        if *source == target {
+            continue;
+        }
+        if deployed
+            .iter()
+            .any(|pack: &DeployedPack| Path::new(&pack.target) == target)
+        {
+            continue;
+        }
+        if target.exists() {
+            failure = Some(format!(
+                "{} already exists and is not managed by the mod manager",
+                target.display()
+            ));
+            break;
+        }
+
+        let kind = if fs::hard_link(source, &target).is_ok() {
+            "hardlink"
+        } else if let Err(e) = fs::copy(source, &target) {
+            let _ = fs::remove_file(&target);
+            failure = Some(format!("Failed to copy {}: {}", source.display(), e));
+            break;
+        } else {
+            "copy"
+        };
+
+        deployed.push(DeployedPack {
+            source: source.to_string_lossy().to_string(),
+            target: target.to_string_lossy().to_string(),
+            kind: kind.to_string(),
+        });
+    }
+
+    // Packs placed before a failure are still recorded so they can be purged.
+    if !deployed.is_empty() {
+        let deployed_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_err(|e| format!("Time error: {}", e))?
+            .as_millis();
+
+        save_ledger(
+            handle,
+            LEDGER_DIRECTORY,
+            app_id,
+            &PackLedger {
+                app_id,
+                deployed_at,
+                packs: deployed,
+            },
+        )?;
+    }
+
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(()),
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn purge_deployment_totalwar(
+    handle: tauri::AppHandle,
+    app_id: u32,
+) -> Result<usize, String> {
+    ensure_game_closed(app_id, "totalwar")?;
+    purge(&handle, app_id)
+}
@@ -1 +1,4 @@
+pub mod deployment;
+pub mod preflight;
 pub mod start;
+pub mod used_mods;
@@ -0,0 +1,156 @@
+use std::path::Path;
+
+use crate::game::find_installation_path::find_installation;
+use crate::game::launch_options::profile_launch_options;
+use crate::game::preflight::{
+    PlannedDeployment, PreflightIssue, PreflightReport, command_line, conflict_issues,
+};
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+use crate::pack::outdated_tables::{load_schema, outdated_tables};
+
+use super::start::launch_command;
+use super::used_mods::{TotalwarMod, UsedPack, resolve_used_mods};
+
+// Outdated tables usually still load, so they only warn.
+fn outdated_pack_issues(game: &Game, packs: &[UsedPack]) -> Vec<PreflightIssue> {
+    let Ok(schema) = load_schema(game) else {
+        return vec![];
+    };
+
+    packs
+        .iter()
+        .filter_map(|used_pack| {
+            let tables = outdated_tables(&schema, &used_pack.pack_path).ok()?;
+            (!tables.is_empty()).then(|| {
+                PreflightIssue::warning(
+                    "outdated_pack",
+                    &used_pack.identifier,
+                    format!(
+                        "{} has tables older than the game: {}",
+                        used_pack.pack_name,
+                        tables.join(", ")
+                    ),
+                )
+            })
+        })
+        .collect()
+}
+
+pub async fn build_preflight(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    mods: &[TotalwarMod],
+    save_game: Option<&str>,
+    folder_paths: Vec<String>,
+) -> Result<PreflightReport, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "totalwar" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let installation = find_installation(handle, game).ok_or_else(|| {
+        format!(
+            "Could not find installation path for game with app_id {}",
+            app_id
+        )
+    })?;
+    let data_path = Path::new(&installation.path).join("data");
+
+    let mut issues = Vec::new();
+
+    let used_mods = resolve_used_mods(game, mods);
+    issues.extend(used_mods.problems.iter().map(|problem| {
+        PreflightIssue::blocking(problem.kind(), problem.identifier(), problem.message())
+    }));
+
+    let launch_options = profile_launch_options(handle, app_id, Some(profile_id))?;
+    let command = launch_options
+        .resolve_launch_mode(&installation)
+        .and_then(|launch_mode| {
+            let exe_path = launch_options.executable_path(
+                &game.exe_directory(Path::new(&installation.path)),
+                &format!("{}.exe", game.exe_name),
+            )?;
+            launch_command(
+                game,
+                &installation,
+                launch_mode,
+                &exe_path,
+                &launch_options,
+                save_game,
+            )
+        });
+    let command = match command {
+        Ok(command) => command_line(&command),
+        Err(e) => {
+            issues.push(PreflightIssue::blocking("launch_options", game.name, e));
+            vec![]
+        }
+    };
+
+    let deployments = if game.working_directories {
+        vec![]
+    } else {
+        used_mods
+            .packs
+            .iter()
+            .filter(|used_pack| used_pack.pack_path != data_path.join(&used_pack.pack_name))
+            .map(|used_pack| PlannedDeployment {
+                source: used_pack.pack_path.to_string_lossy().to_string(),
+                target: data_path
+                    .join(&used_pack.pack_name)
+                    .to_string_lossy()
+                    .to_string(),
+            })
+            .collect()
+    };
+
+    let schema_game = game.clone();
+    let schema_packs = used_mods.packs.clone();
+    issues.extend(
+        tokio::task::spawn_blocking(move || outdated_pack_issues(&schema_game, &schema_packs))
+            .await
+            .map_err(|e| format!("Failed to check for outdated packs: {}", e))?,
+    );
+
+    let active_paths: Vec<String> = used_mods
+        .packs
+        .iter()
+        .map(|used_pack| used_pack.pack_path.to_string_lossy().to_string())
+        .collect();
+    issues.extend(conflict_issues(handle, app_id, profile_id, folder_paths, &active_paths).await?);
+
+    PreflightReport::new(
+        app_id,
+        profile_id,
+        command,
+        Some(used_mods.render(&data_path, game.working_directories)),
+        deployments,
+        issues,
+    )
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn preflight_totalwar(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    mods: Vec<TotalwarMod>,
+    save_game: Option<String>,
+    folder_paths: Vec<String>,
+) -> Result<PreflightReport, String> {
+    build_preflight(
+        &handle,
+        app_id,
+        profile_id,
+        &mods,
+        save_game.as_deref(),
+        folder_paths,
+    )
+    .await
+}
@@ -1,30 +1,117 @@
-use std::os::windows::process::CommandExt;
-use std::{fs, path::Path, process::Command};
-use tokio::spawn;
-use tokio::time::{Duration, sleep};
+use serde::Serialize;
+use std::process::Command;
+use std::{fs, path::Path};
 
 use crate::AppState;
-use crate::game::find_installation_path::find_installation_path;
-use crate::game::supported_games::SUPPORTED_GAMES;
+use crate::game::config_snapshots::{apply_profile_configs, capture_after_exit};
+use crate::game::deployment_ledger::{purge_after_exit, roll_back_launch};
+use crate::game::find_installation_path::find_installation;
+use crate::game::game_update::ensure_update_acknowledged;
+use crate::game::launch_options::{
+    LaunchMode, LaunchOptions, exe_process_name, profile_launch_options,
+};
+use crate::game::storefronts::installation::Installation;
+#[cfg(not(windows))]
+use crate::game::storefronts::installation::wine_command;
+use crate::game::supported_games::{Game, SUPPORTED_GAMES};
+use crate::steam::applaunch::applaunch_command;
+#[cfg(windows)]
+use crate::utils::hidden_command::hidden_command;
+
+use super::deployment::{deploy_packs, purge};
+use super::preflight::build_preflight;
+use super::used_mods::{TotalwarMod, USED_MODS_FILE_NAME, UsedModsProblem, resolve_used_mods};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TotalwarLaunch {
+    pub launched: bool,
+    pub problems: Vec<UsedModsProblem>,
+}
+
+fn launch_args(launch_options: &LaunchOptions, save_game: Option<&str>) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(save) = save_game.filter(|save| !save.is_empty()) {
+        args.push("game_startup_mode".to_string());
+        args.push("campaign_load".to_string());
+        args.push(save.to_string());
+        args.push(";".to_string());
+    }
+    args.push(format!("{};", USED_MODS_FILE_NAME));
+    args.extend(launch_options.args.iter().cloned());
+    args
+}
+
+// The game looks up the used mods file relative to the install folder.
+#[cfg(windows)]
+fn direct_command(installation: &Installation, exe_path: &Path, args: &[String]) -> Command {
+    let mut command = hidden_command(exe_path);
+    command.current_dir(&installation.path).args(args);
+    command
+}
+
+// Installs outside of Steam have no Proton prefix and run with wine.
+#[cfg(not(windows))]
+fn direct_command(installation: &Installation, exe_path: &Path, args: &[String]) -> Command {
+    wine_command(installation, exe_path, args)
+}
+
+pub fn launch_command(
+    game: &Game,
+    installation: &Installation,
+    launch_mode: LaunchMode,
+    exe_path: &Path,
+    launch_options: &LaunchOptions,
+    save_game: Option<&str>,
+) -> Result<Command, String> {
+    let args = launch_args(launch_options, save_game);
+
+    // Steam starts the game inside its Proton prefix outside of Windows,
+    // arguments are passed on as is.
+    if launch_mode == LaunchMode::Steam {
+        return applaunch_command(game.steam_id, &args);
+    }
+
+    let mut command = direct_command(installation, exe_path, &args);
+    command.envs(&launch_options.env);
+    Ok(command)
+}
 
 #[tauri::command(rename_all = "snake_case")]
 pub async fn start_game_totalwar(
+    handle: tauri::AppHandle,
     app_state: tauri::State<'_, AppState>,
     app_id: u32,
-    add_directory_txt: String,
-    used_mods_txt: String,
+    mods: Vec<TotalwarMod>,
     save_game: Option<String>,
-) -> Result<(), String> {
-    let steam_state = &app_state.steam_state;
-    steam_state.drop_all_clients();
-
+    profile_id: Option<u64>,
+    strict: Option<bool>,
+    folder_paths: Option<Vec<String>>,
+) -> Result<TotalwarLaunch, String> {
     let game = SUPPORTED_GAMES
         .iter()
         .find(|game| game.steam_id == app_id)
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
 
-    let game_installation_path = match find_installation_path(game.clone()) {
-        Some(path) => path,
+    // Strict launches refuse whatever the preflight report would block.
+    if strict.unwrap_or(false) {
+        let profile_id =
+            profile_id.ok_or_else(|| "A strict launch needs a profile to check".to_string())?;
+        let report = build_preflight(
+            &handle,
+            app_id,
+            profile_id,
+            &mods,
+            save_game.as_deref(),
+            folder_paths.unwrap_or_default(),
+        )
+        .await?;
+        if report.blocking {
+            return Err(report.blocking_message());
+        }
+    }
+
+    let installation = match find_installation(&handle, game) {
+        Some(installation) => installation,
         None => {
             return Err(format!(
                 "Could not find installation path for game with app_id {}",
@@ -33,62 +120,88 @@ pub async fn start_game_totalwar(
         }
     };
 
-    let exe_directory = if game.exe_folder.is_empty() {
-        game_installation_path.clone()
+    let game_installation_path = installation.path.clone();
+    let data_path = Path::new(&game_installation_path).join("data");
+
+    let launch_options = profile_launch_options(&handle, app_id, profile_id)?;
+    let launch_mode = launch_options.resolve_launch_mode(&installation)?;
+    let exe_path = launch_options.executable_path(
+        &game.exe_directory(Path::new(&game_installation_path)),
+        &format!("{}.exe", game.exe_name),
+    )?;
+    // Steam starts the default executable whatever the profile picked.
+    let process_names = if launch_mode == LaunchMode::Steam {
+        vec![game.exe_name.to_string()]
     } else {
-        Path::new(&game_installation_path)
-            .join(game.exe_folder)
-            .to_string_lossy()
-            .to_string()
-            .replace("\\\\", "\\")
-            .replace("/", "\\")
+        vec![exe_process_name(&exe_path)]
+    };
+
+    let used_mods = resolve_used_mods(game, &mods);
+    if !used_mods.problems.is_empty() {
+        return Ok(TotalwarLaunch {
+            launched: false,
+            problems: used_mods.problems,
+        });
+    }
+
+    ensure_update_acknowledged(&handle, app_id)?;
+
+    let steam_state = &app_state.steam_state;
+    steam_state.drop_all_clients();
+
+    let roll_back = |backup_id: Option<&str>, e: String| {
+        roll_back_launch(&handle, game, app_id, backup_id, purge, e)
     };
 
-    let normalized_exe_directory = game_installation_path
-        .replace("\\\\", "\\")
-        .replace("/", "\\")
-        .trim_end_matches('\\')
-        .to_string();
+    // Older titles ignore add_working_directory, their packs go into data instead.
+    if !game.working_directories {
+        deploy_packs(&handle, app_id, &data_path, &used_mods.packs)
+            .map_err(|e| roll_back(None, e))?;
+    }
 
-    let used_mods_file_path =
-        Path::new(&game_installation_path).join("tw_mod_organizer_used_mods.txt");
+    let used_mods_file_path = Path::new(&game_installation_path).join(USED_MODS_FILE_NAME);
     fs::write(
         &used_mods_file_path,
-        format!("{}{}", add_directory_txt, used_mods_txt),
+        used_mods.render(&data_path, game.working_directories),
+    )
+    .map_err(|e| {
+        roll_back(
+            None,
+            format!("Failed to write {}: {}", USED_MODS_FILE_NAME, e),
+        )
+    })?;
+
+    let backup_id = match profile_id {
+        Some(profile_id) => apply_profile_configs(&handle, game, app_id, profile_id)
+            .map_err(|e| roll_back(None, e))?,
+        None => None,
+    };
+
+    launch_command(
+        game,
+        &installation,
+        launch_mode,
+        &exe_path,
+        &launch_options,
+        save_game.as_deref(),
     )
-    .map_err(|e| format!("Failed to write tw_mod_organizer_used_mods.txt: {}", e))?;
-
-    let batch_content = format!(
-        "start /d \"{}\" {}.exe{}{} tw_mod_organizer_used_mods.txt;",
-        normalized_exe_directory,
-        game.exe_name,
-        if let Some(save) = &save_game {
-            if !save.is_empty() {
-                format!(" game_startup_mode campaign_load \"{}\" ;", save)
-            } else {
-                String::new()
-            }
-        } else {
-            String::new()
-        },
-        ""
-    );
-
-    let batch_path = Path::new(&exe_directory).join("launch_game.bat");
-    fs::write(&batch_path, batch_content)
-        .map_err(|e| format!("Failed to write batch file: {}", e))?;
-
-    Command::new("cmd")
-        .creation_flags(0x08000000)
-        .args(&["/C", batch_path.to_string_lossy().as_ref()])
-        .spawn()
-        .map_err(|e| format!("Failed to execute batch file: {}", e))?;
-
-    let batch_path_clone = batch_path.clone();
-    spawn(async move {
-        sleep(Duration::from_secs(10)).await;
-        let _ = fs::remove_file(batch_path_clone);
-    });
-
-    Ok(())
+    .and_then(|mut command| {
+        command
+            .spawn()
+            .map_err(|e| format!("Failed to start the game: {}", e))
+    })
+    .map_err(|e| roll_back(backup_id.as_deref(), e))?;
+
+    if !game.working_directories {
+        purge_after_exit(handle.clone(), app_id, process_names.clone(), purge);
+    }
+
+    if let Some(profile_id) = profile_id {
+        capture_after_exit(handle, app_id, profile_id, process_names);
+    }
+
+    Ok(TotalwarLaunch {
+        launched: true,
+        problems: vec![],
+    })
 }
@@ -0,0 +1,225 @@
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::game::supported_games::Game;
+use crate::pack::pack_version::ensure_pack_version;
+
+pub const USED_MODS_FILE_NAME: &str = "tw_mod_organizer_used_mods.txt";
+
+// The frontend still decides which mods are active and in what order, since
+// sorting modes, separators and always-active base mods only exist there. The
+// list it sends is final; everything from quoting to missing files is checked
+// here.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotalwarMod {
+    pub identifier: String,
+    pub mod_file_path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UsedModsProblem {
+    MissingFile {
+        identifier: String,
+        mod_file_path: String,
+    },
+    InvalidPack {
+        identifier: String,
+        mod_file_path: String,
+        message: String,
+    },
+    UnquotablePath {
+        identifier: String,
+        mod_file_path: String,
+    },
+    DuplicatePackName {
+        identifier: String,
+        pack_name: String,
+        shadowed_by: String,
+    },
+}
+
+impl UsedModsProblem {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            UsedModsProblem::MissingFile { .. } => "missing_file",
+            UsedModsProblem::InvalidPack { .. } => "invalid_pack",
+            UsedModsProblem::UnquotablePath { .. } => "unquotable_path",
+            UsedModsProblem::DuplicatePackName { .. } => "duplicate_pack_name",
+        }
+    }
+
+    pub fn identifier(&self) -> &str {
+        match self {
+            UsedModsProblem::MissingFile { identifier, .. }
+            | UsedModsProblem::InvalidPack { identifier, .. }
+            | UsedModsProblem::UnquotablePath { identifier, .. }
+            | UsedModsProblem::DuplicatePackName { identifier, .. } => identifier,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        match self {
+            UsedModsProblem::MissingFile { mod_file_path, .. } => {
+                format!("{} does not exist", mod_file_path)
+            }
+            UsedModsProblem::InvalidPack { message, .. } => message.clone(),
+            UsedModsProblem::UnquotablePath { mod_file_path, .. } => format!(
+                "{} contains a quote or line break the game cannot read",
+                mod_file_path
+            ),
+            UsedModsProblem::DuplicatePackName {
+                pack_name,
+                shadowed_by,
+                ..
+            } => format!("{} is already loaded from {}", pack_name, shadowed_by),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UsedPack {
+    pub identifier: String,
+    pub pack_name: String,
+    pub pack_path: PathBuf,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct UsedMods {
+    pub packs: Vec<UsedPack>,
+    pub problems: Vec<UsedModsProblem>,
+}
+
+// The script has no escaping, a quote or line break would end the entry early.
+fn is_quotable(value: &str) -> bool {
+    !value.contains(['"', '\n', '\r'])
+}
+
+// Workshop .bin files are plain packs, the game only loads them from data
+// under a .pack name.
+fn pack_name(pack_path: &Path) -> Option<String> {
+    match pack_path.extension().and_then(|ext| ext.to_str()) {
+        Some("bin") => pack_path
+            .file_stem()
+            .map(|stem| format!("{}.pack", stem.to_string_lossy())),
+        _ => pack_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned()),
+    }
+}
+
+// Mods come in profile order where the bottom one wins, the game lets the
+// first line win, so the file lists them bottom up.
+pub fn resolve_used_mods(game: &Game, mods: &[TotalwarMod]) -> UsedMods {
+    let mut used_mods = UsedMods::default();
+
+    for mod_info in mods.iter().rev() {
+        let pack_path = PathBuf::from(mod_info.mod_file_path.replace('\\', "/"));
+        let Some(pack_name) = pack_name(&pack_path) else {
+            used_mods.problems.push(UsedModsProblem::MissingFile {
+                identifier: mod_info.identifier.clone(),
+                mod_file_path: mod_info.mod_file_path.clone(),
+            });
+            continue;
+        };
+
+        if let Some(used_pack) = used_mods
+            .packs
+            .iter()
+            .find(|used_pack| used_pack.pack_name.eq_ignore_ascii_case(&pack_name))
+        {
+            // The same pack listed twice is harmless, a different pack with
+            // the same name would silently load the other one instead.
+            if used_pack.pack_path != pack_path {
+                used_mods.problems.push(UsedModsProblem::DuplicatePackName {
+                    identifier: mod_info.identifier.clone(),
+                    pack_name,
+                    shadowed_by: used_pack.identifier.clone(),
+                });
+            }
+            continue;
+        }
+
+        if !is_quotable(&mod_info.mod_file_path) {
+            used_mods.problems.push(UsedModsProblem::UnquotablePath {
+                identifier: mod_info.identifier.clone(),
+                mod_file_path: mod_info.mod_file_path.clone(),
+            });
+            continue;
+        }
+
+        if !pack_path.is_file() {
+            used_mods.problems.push(UsedModsProblem::MissingFile {
+                identifier: mod_info.identifier.clone(),
+                mod_file_path: mod_info.mod_file_path.clone(),
+            });
+            continue;
+        }
+
+        if let Err(message) = ensure_pack_version(game, &pack_path) {
+            used_mods.problems.push(UsedModsProblem::InvalidPack {
+                identifier: mod_info.identifier.clone(),
+                mod_file_path: mod_info.mod_file_path.clone(),
+                message,
+            });
+            continue;
+        }
+
+        used_mods.packs.push(UsedPack {
+            identifier: mod_info.identifier.clone(),
+            pack_name,
+            pack_path,
+        });
+    }
+
+    used_mods
+}
+
+// The game runs under wine and only understands drive paths, the host root is Z:.
+#[cfg(not(windows))]
+fn script_path(path: &Path) -> String {
+    let path = path.to_string_lossy();
+    if path.starts_with('/') {
+        format!("Z:{}", path.replace('/', "\\"))
+    } else {
+        path.into_owned()
+    }
+}
+
+#[cfg(windows)]
+fn script_path(path: &Path) -> String {
+    path.to_string_lossy().replace('/', "\\")
+}
+
+impl UsedMods {
+    // Packs already inside data are found without a working directory.
+    pub fn working_directories(&self, data_path: &Path) -> Vec<PathBuf> {
+        let mut directories: Vec<PathBuf> = Vec::new();
+        for used_pack in self.packs.iter() {
+            let Some(directory) = used_pack.pack_path.parent() else {
+                continue;
+            };
+            if directory == data_path || directories.iter().any(|known| known == directory) {
+                continue;
+            }
+            directories.push(directory.to_path_buf());
+        }
+        directories
+    }
+
+    pub fn render(&self, data_path: &Path, with_working_directories: bool) -> String {
+        let mut content = String::new();
+        if with_working_directories {
+            for directory in self.working_directories(data_path) {
+                content.push_str(&format!(
+                    "add_working_directory \"{}\";\n",
+                    script_path(&directory)
+                ));
+            }
+        }
+        for used_pack in self.packs.iter() {
+            content.push_str(&format!("mod \"{}\";\n", used_pack.pack_name));
+        }
+        content
+    }
+}
@@ -63,6 +63,10 @@ pub fn run() {
             save_watcher_running: Arc::new(AtomicBool::new(false)),
             save_folder_path: Arc::new(Mutex::new(PathBuf::from(""))),
         })
+        .setup(|app| {
+            game::supported_games::init_game_definitions(app.handle());
+            Ok(())
+        })
         .on_window_event(|window, event| match event {
             tauri::WindowEvent::Destroyed { .. } => {
                 let app_handle = window.app_handle();
@@ -78,8 +82,18 @@ pub fn run() {
             app_version_check::app_version_check,
             clear_cache::clear_cache,
             r#mod::conflicts::conflicts,
+            r#mod::conflict_report::conflict_report,
+            r#mod::conflict_report::export_conflict_report,
+            r#mod::conflict_acknowledgements::conflict_acknowledgements,
+            r#mod::conflict_acknowledgements::acknowledge_conflict,
+            r#mod::conflict_acknowledgements::unacknowledge_conflict,
             r#mod::base_mods::base_mods,
             r#mod::local_mods::local_mods,
+            r#mod::migrate_data_mod::migrate_data_mod,
+            r#mod::bannerlord::sort_load_order::sort_load_order_bannerlord,
+            r#mod::bannerlord::validate_dependencies::validate_dependencies_bannerlord,
+            r#mod::bannerlord::lint_modules::lint_modules_bannerlord,
+            r#mod::bannerlord::game_compatibility::game_compatibility_bannerlord,
             r#mod::install::install_mod,
             r#mod::delete::delete_mod,
             pack::pack_files::pack_files,
@@ -88,6 +102,7 @@ pub fn run() {
             pack::pack_loc_data::pack_loc_data,
             pack::pack_loc_data_raw::pack_loc_data_raw,
             pack::pack_fetch_data::pack_fetch_data,
+            pack::pack_texture_data::pack_texture_data,
             pack::open_pack_file::open_pack_file,
             game::save_files::save_files,
             game::delete_save_file::delete_save_file,
@@ -96,10 +111,34 @@ pub fn run() {
             game::upsert_save_file_meta::upsert_save_file_meta,
             game::fetch_save_file_meta::fetch_save_file_meta,
             game::totalwar::start::start_game_totalwar,
+            game::totalwar::deployment::purge_deployment_totalwar,
+            game::totalwar::preflight::preflight_totalwar,
             game::bannerlord::start::start_game_bannerlord,
+            game::bannerlord::launcher_data::import_launcher_data_bannerlord,
+            game::bannerlord::launcher_data::export_launcher_data_bannerlord,
+            game::bannerlord::deployment::purge_deployment_bannerlord,
+            game::bannerlord::preflight::preflight_bannerlord,
+            game::bannerlord::launchers::bannerlord_launchers,
+            game::launch_options::launch_options,
+            game::launch_options::set_launch_options,
+            game::launch_options::game_executables,
+            game::config_snapshots::config_snapshot,
+            game::config_snapshots::capture_config_snapshot,
+            game::config_snapshots::apply_config_snapshot,
+            game::config_snapshots::delete_config_snapshot,
+            game::config_snapshots::config_backups,
+            game::config_snapshots::restore_config_backup,
             game::is_running::is_game_running,
             game::force_quit::force_quit,
+            game::game_update::acknowledge_game_update,
+            game::game_update::check_game_update,
+            game::find_installation_path::game_installation,
+            game::find_installation_path::game_installations,
+            game::find_installation_path::select_installation,
+            game::storefronts::manual::add_manual_installation,
+            game::storefronts::manual::remove_manual_installation,
             game::supported_games::supported_games,
+            game::supported_games::game_definition_errors,
             sevenz::zip_contents::zip_contents,
             nexus::auth_init::nexus_auth_init,
             nexus::download_link::nexus_download_link,
@@ -111,6 +150,7 @@ pub fn run() {
             download::sync::sync_downloads,
             steam::steam_paths::steam_paths,
             steam::steam_library_paths::steam_library_paths,
+            steam::app_manifest::steam_app_manifest,
             steam::subscribe::subscribe,
             steam::subscribed_mods::subscribed_mods,
             steam::unsubscribe::unsubscribe,
@@ -315,5 +315,13 @@ pub fn get_migrations() -> Vec<Migration> {
 			"#,
             kind: MigrationKind::Up,
         },
+        Migration {
+            version: 23,
+            description: "add_strict_launch_to_settings",
+            sql: r#"
+			ALTER TABLE settings ADD COLUMN strict_launch INTEGER DEFAULT 0 CHECK(strict_launch IN (0, 1));
+			"#,
+            kind: MigrationKind::Up,
+        },
     ]
 }
@@ -24,7 +24,7 @@ pub async fn base_mods(handle: tauri::AppHandle, app_id: u32) -> Result<Vec<ModI
         .iter()
         .find(|game| game.steam_id == app_id)
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
-    let game_installation_path = match find_installation_path(game.clone()) {
+    let game_installation_path = match find_installation_path(&handle, game.clone()) {
         Some(path) => path,
         None => {
             return Err(format!(
@@ -72,28 +72,26 @@ pub async fn base_mods(handle: tauri::AppHandle, app_id: u32) -> Result<Vec<ModI
             continue;
         }
 
-        let module_type = submodule_info.module_type.unwrap_or("".to_owned());
+        let module_type = submodule_info.module_type.clone().unwrap_or("".to_owned());
         if module_type != "Official" && module_type != "OfficialOptional" {
             continue;
         }
 
         let required_items: Vec<String> = submodule_info
-            .depended_modules
-            .unwrap_or_default()
-            .iter()
-            .map(|module| module.id.clone())
+            .load_before_ids()
+            .into_iter()
+            .map(String::from)
             .collect();
 
         let child_mods: Vec<String> = submodule_info
-            .modules_to_load_after_this
-            .unwrap_or_default()
-            .iter()
-            .map(|module| module.id.clone())
+            .load_after_ids()
+            .into_iter()
+            .map(String::from)
             .collect();
 
         mods.push(ModItem {
             game_specific_id: submodule_info.id.clone(),
-            identifier: submodule_info.id,
+            identifier: submodule_info.id.clone(),
             title: submodule_info.name.clone(),
             description: Some(String::from("")),
             created_at,
@@ -103,16 +101,20 @@ pub async fn base_mods(handle: tauri::AppHandle, app_id: u32) -> Result<Vec<ModI
             download_url: None,
             preview_url: None,
             version: Some(ModVersion::Text(
-                submodule_info.version.unwrap_or_else(|| "".to_string()),
+                submodule_info
+                    .version
+                    .clone()
+                    .unwrap_or_else(|| "".to_string()),
             )),
             item_type: String::from("base_mod"),
-            mod_file: submodule_info.name,
+            mod_file: submodule_info.name.clone(),
             mod_file_path: mod_path.to_string_lossy().to_string(),
             preview_local: String::from(""),
             creator_id: None,
             creator_name: Some(String::from("")),
             required_items: required_items,
             child_mods: child_mods,
+            submodule_info: Some(submodule_info),
         });
     }
 
@@ -0,0 +1,21 @@
+use crate::r#mod::conflict_report::{ConflictCategory, ConflictSeverity};
+
+// Keys come from conflicts::module_index: bin/<dll>, GUI/Prefabs/<name> and
+// <Tag>/<id> for ModuleData objects.
+pub fn conflict_severity(path: &str) -> (ConflictCategory, ConflictSeverity) {
+    // Two copies of an assembly, only one of them gets loaded.
+    if path.starts_with("bin/") {
+        return (ConflictCategory::Script, ConflictSeverity::High);
+    }
+
+    if path.starts_with("GUI/Prefabs/") {
+        return (ConflictCategory::Ui, ConflictSeverity::Medium);
+    }
+
+    // The object loaded last replaces the other one entirely.
+    if path.contains('/') {
+        return (ConflictCategory::DbTable, ConflictSeverity::High);
+    }
+
+    (ConflictCategory::Asset, ConflictSeverity::Low)
+}
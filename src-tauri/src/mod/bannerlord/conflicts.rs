@@ -0,0 +1,322 @@
+use bincode::{Decode, Encode};
+use rayon::prelude::*;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::task;
+use xml::reader::{EventReader, XmlEvent};
+
+use crate::xml::submodule_contents::submodule_contents;
+
+use super::has_mod_file::has_mod_file;
+use super::module_contents::app_cache_dir;
+
+#[derive(Encode, Decode, PartialEq, Eq, Clone, Debug)]
+pub struct FileMetadata {
+    pub size: u64,
+    pub modified: u64,
+}
+
+#[derive(Encode, Decode, Clone, Debug)]
+pub struct CacheEntry {
+    pub file_paths: Vec<String>,
+    pub file_metadata: FxHashMap<String, FileMetadata>,
+    pub conflicts: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+}
+
+fn module_dirs(folder_paths: &[String]) -> Vec<PathBuf> {
+    folder_paths
+        .iter()
+        .flat_map(|folder_path| {
+            fs::read_dir(folder_path)
+                .into_iter()
+                .flatten()
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir() && has_mod_file(path))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn collect_files(dir: &Path, extension: &str, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(&path, extension, files);
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case(extension))
+        {
+            files.push(path);
+        }
+    }
+}
+
+fn module_files(module_path: &Path) -> Vec<PathBuf> {
+    let mut files = vec![module_path.join("SubModule.xml")];
+    collect_files(&module_path.join("ModuleData"), "xml", &mut files);
+    collect_files(&module_path.join("GUI").join("Prefabs"), "xml", &mut files);
+    collect_files(
+        &module_path.join("bin").join("Win64_Shipping_Client"),
+        "dll",
+        &mut files,
+    );
+    files
+}
+
+// Objects are the direct children of a ModuleData file's root element, keyed by
+// their tag so an Item and a Settlement sharing an id are not reported.
+fn object_ids(path: &Path) -> Vec<String> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return vec![];
+    };
+
+    let mut ids = Vec::new();
+    let mut depth = 0;
+    for event in EventReader::from_str(content.trim_start_matches('\u{feff}')) {
+        match event {
+            Ok(XmlEvent::StartElement {
+                name, attributes, ..
+            }) => {
+                depth += 1;
+                if depth == 2 {
+                    if let Some(id) = attributes.iter().find(|a| a.name.local_name == "id") {
+                        ids.push(format!("{}/{}", name.local_name, id.value));
+                    }
+                }
+            }
+            Ok(XmlEvent::EndElement { .. }) => depth -= 1,
+            Err(_) => break,
+            _ => {}
+        }
+    }
+
+    ids
+}
+
+struct ModuleIndex {
+    path: String,
+    id: String,
+    dependencies: Vec<String>,
+    keys: FxHashSet<String>,
+    dlls: FxHashMap<String, PathBuf>,
+}
+
+impl ModuleIndex {
+    fn depends_on(&self, other: &ModuleIndex) -> bool {
+        !other.id.is_empty() && self.dependencies.contains(&other.id)
+    }
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    let same_size = match (fs::metadata(a), fs::metadata(b)) {
+        (Ok(a), Ok(b)) => a.len() == b.len(),
+        _ => false,
+    };
+    same_size
+        && match (fs::read(a), fs::read(b)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+}
+
+// Shared libraries such as 0Harmony.dll are routinely bundled next to the
+// module that provides them. Only one copy gets loaded, which is harmless when
+// the copies match or one module declares the other as a dependency.
+fn is_expected_dll(key: &str, module: &ModuleIndex, other: &ModuleIndex) -> bool {
+    if module.depends_on(other) || other.depends_on(module) {
+        return true;
+    }
+
+    match (module.dlls.get(key), other.dlls.get(key)) {
+        (Some(dll), Some(other_dll)) => same_file(dll, other_dll),
+        _ => false,
+    }
+}
+
+fn module_index(module_path: &Path, cache_dir: &Path, app_id: u32) -> ModuleIndex {
+    let mut index = FxHashSet::default();
+
+    let mut data_files = Vec::new();
+    collect_files(&module_path.join("ModuleData"), "xml", &mut data_files);
+    for data_file in data_files {
+        index.extend(object_ids(&data_file));
+    }
+
+    // Prefabs are resolved by name, whichever folder they live in.
+    let mut prefabs = Vec::new();
+    collect_files(
+        &module_path.join("GUI").join("Prefabs"),
+        "xml",
+        &mut prefabs,
+    );
+    for prefab in prefabs {
+        if let Some(stem) = prefab.file_stem().and_then(|stem| stem.to_str()) {
+            index.insert(format!("GUI/Prefabs/{}", stem));
+        }
+    }
+
+    let mut dlls = Vec::new();
+    collect_files(
+        &module_path.join("bin").join("Win64_Shipping_Client"),
+        "dll",
+        &mut dlls,
+    );
+    let mut dll_paths = FxHashMap::default();
+    for dll in dlls {
+        if let Some(file_name) = dll.file_name().and_then(|name| name.to_str()) {
+            let key = format!("bin/{}", file_name.to_lowercase());
+            index.insert(key.clone());
+            dll_paths.insert(key, dll);
+        }
+    }
+
+    let identifier = module_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let contents = submodule_contents(module_path, cache_dir, app_id, identifier);
+
+    ModuleIndex {
+        path: module_path.to_string_lossy().to_string(),
+        id: contents
+            .as_ref()
+            .map(|contents| contents.id.clone())
+            .unwrap_or_default(),
+        dependencies: contents
+            .as_ref()
+            .map(|contents| {
+                contents
+                    .load_before_ids()
+                    .into_iter()
+                    .map(|id| id.to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        keys: index,
+        dlls: dll_paths,
+    }
+}
+
+pub async fn conflicts(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    folder_paths: Vec<String>,
+) -> Result<BTreeMap<String, BTreeMap<String, Vec<String>>>, String> {
+    let app_cache_dir = app_cache_dir(&handle)?;
+    // v2 stopped reporting bundled copies of dependency DLLs.
+    let cache_bin_filename = format!("mod_conflicts_{}_cache_v2.bin", app_id);
+    let cache_file = app_cache_dir.join(cache_bin_filename);
+
+    let modules = module_dirs(&folder_paths);
+    let files_vec: Vec<PathBuf> = modules
+        .par_iter()
+        .flat_map(|module_path| module_files(module_path))
+        .collect();
+
+    let file_paths: Vec<String> = files_vec
+        .iter()
+        .map(|p| p.to_string_lossy().to_string())
+        .collect();
+
+    let file_metadata: FxHashMap<String, FileMetadata> = files_vec
+        .par_iter()
+        .filter_map(|path| {
+            let metadata = fs::metadata(path).ok()?;
+            let modified = metadata
+                .modified()
+                .ok()?
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .ok()?
+                .as_secs();
+            Some((
+                path.to_string_lossy().to_string(),
+                FileMetadata {
+                    size: metadata.len(),
+                    modified,
+                },
+            ))
+        })
+        .collect();
+
+    if let Ok(cache_content) = fs::read(&cache_file) {
+        let config = bincode::config::standard();
+        if let Ok((cache_entry, _)) =
+            bincode::decode_from_slice::<CacheEntry, _>(&cache_content, config)
+        {
+            let file_paths_set: FxHashSet<_> = file_paths.iter().collect();
+            let cached_paths_set: FxHashSet<_> = cache_entry.file_paths.iter().collect();
+
+            if file_paths_set == cached_paths_set
+                && file_metadata.iter().all(|(path, metadata)| {
+                    cache_entry
+                        .file_metadata
+                        .get(path)
+                        .is_some_and(|cached| cached == metadata)
+                })
+            {
+                return Ok(cache_entry.conflicts);
+            }
+        }
+    }
+
+    let conflicts_result = task::spawn_blocking(move || {
+        let indexes: Vec<ModuleIndex> = modules
+            .par_iter()
+            .map(|module_path| module_index(module_path, &app_cache_dir, app_id))
+            .collect();
+
+        indexes
+            .par_iter()
+            .filter_map(|module| {
+                let module_conflicts: BTreeMap<String, Vec<String>> = indexes
+                    .iter()
+                    .filter(|other| other.path != module.path)
+                    .filter_map(|other| {
+                        let mut shared: Vec<String> = module
+                            .keys
+                            .intersection(&other.keys)
+                            .filter(|key| {
+                                !key.starts_with("bin/") || !is_expected_dll(key, module, other)
+                            })
+                            .cloned()
+                            .collect();
+                        if shared.is_empty() {
+                            return None;
+                        }
+                        shared.sort_by(|a, b| {
+                            let folder_a = a.split('/').next().unwrap_or("");
+                            let folder_b = b.split('/').next().unwrap_or("");
+                            folder_a.cmp(folder_b).then_with(|| a.cmp(b))
+                        });
+                        Some((other.path.clone(), shared))
+                    })
+                    .collect();
+                (!module_conflicts.is_empty()).then(|| (module.path.clone(), module_conflicts))
+            })
+            .collect::<BTreeMap<_, _>>()
+    })
+    .await
+    .map_err(|e| format!("Task failed: {:?}", e))?;
+
+    let cache_entry = CacheEntry {
+        file_paths,
+        file_metadata,
+        conflicts: conflicts_result.clone(),
+    };
+
+    let config = bincode::config::standard();
+    if let Ok(cache_bin) = bincode::encode_to_vec(&cache_entry, config) {
+        let _ = fs::write(&cache_file, cache_bin);
+    }
+
+    Ok(conflicts_result)
+}
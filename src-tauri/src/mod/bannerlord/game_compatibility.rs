@@ -0,0 +1,241 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use crate::game::bannerlord::game_version::game_version;
+use crate::game::{
+    find_installation_path::find_installation_path, supported_games::SUPPORTED_GAMES,
+};
+use crate::utils::dotnet_assembly::read_assembly_metadata;
+
+use super::module_contents::{
+    BannerlordModule, LoadedModule, app_cache_dir, game_modules, module_contents,
+};
+use super::module_version::{ModuleVersion, parse_module_version};
+use super::official_modules::OFFICIAL_MODULES;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompatibilityIssue {
+    pub kind: String,
+    pub subject: String,
+    pub expected: Option<String>,
+    pub found: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleCompatibility {
+    pub module_id: String,
+    pub identifier: String,
+    pub name: String,
+    pub compatible: bool,
+    pub issues: Vec<CompatibilityIssue>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GameCompatibility {
+    pub game_version: Option<String>,
+    pub modules: Vec<ModuleCompatibility>,
+    pub unreadable: Vec<String>,
+}
+
+pub fn game_assemblies(bin_path: &Path) -> HashMap<String, String> {
+    let mut assemblies = HashMap::new();
+    let Ok(entries) = fs::read_dir(bin_path) else {
+        return assemblies;
+    };
+
+    for entry in entries.flatten() {
+        let file_name = entry.file_name().to_string_lossy().to_string();
+        if !file_name.starts_with("TaleWorlds.") || !file_name.ends_with(".dll") {
+            continue;
+        }
+
+        if let Some(definition) =
+            read_assembly_metadata(&entry.path()).and_then(|metadata| metadata.definition)
+        {
+            assemblies.insert(definition.name.clone(), definition.version_string());
+        }
+    }
+
+    assemblies
+}
+
+// Only the release type, major and minor parts identify a game branch,
+// builds within the same branch stay compatible.
+fn branch_mismatch(game: &ModuleVersion, required: &ModuleVersion) -> Option<&'static str> {
+    let mut game_branch = vec![Some(game.release_type as u32)];
+    game_branch.extend(game.parts.iter().take(2).cloned());
+    let mut required_branch = vec![Some(required.release_type as u32)];
+    required_branch.extend(required.parts.iter().take(2).cloned());
+
+    for (game_part, required_part) in game_branch.iter().zip(required_branch.iter()) {
+        let (Some(game_part), Some(required_part)) = (game_part, required_part) else {
+            return None;
+        };
+        if game_part < required_part {
+            return Some("game_too_old");
+        }
+        if game_part > required_part {
+            return Some("older_branch");
+        }
+    }
+
+    None
+}
+
+// Older installs have no version file, Native carries the game's version too.
+pub fn installed_game_version(
+    cache_dir: &Path,
+    app_id: u32,
+    game_installation_path: &Path,
+) -> Option<String> {
+    game_version(game_installation_path).or_else(|| {
+        game_modules(cache_dir, app_id, game_installation_path)
+            .into_iter()
+            .find(|module| module.contents.id == "Native")
+            .and_then(|module| module.contents.version)
+    })
+}
+
+pub fn module_compatibility(
+    module: &LoadedModule,
+    game_version: Option<&ModuleVersion>,
+    raw_game_version: Option<&str>,
+    assemblies: &HashMap<String, String>,
+) -> ModuleCompatibility {
+    let mut issues = Vec::new();
+
+    if let Some(game_version) = game_version {
+        let dependencies = module
+            .contents
+            .depended_modules
+            .iter()
+            .flatten()
+            .chain(module.contents.depended_module_metadatas.iter().flatten())
+            .filter(|dep| OFFICIAL_MODULES.contains(&dep.id.as_str()));
+
+        for dep in dependencies {
+            let Some(required) = dep
+                .dependent_version
+                .as_deref()
+                .and_then(parse_module_version)
+            else {
+                continue;
+            };
+
+            if let Some(kind) = branch_mismatch(game_version, &required) {
+                issues.push(CompatibilityIssue {
+                    kind: kind.to_string(),
+                    subject: dep.id.clone(),
+                    expected: dep.dependent_version.clone(),
+                    found: raw_game_version.map(String::from),
+                });
+            }
+        }
+    }
+
+    if !assemblies.is_empty() {
+        let bin_path = module.mod_path.join("bin").join("Win64_Shipping_Client");
+        let dll_names = module
+            .contents
+            .sub_modules
+            .iter()
+            .flatten()
+            .filter_map(|sub_module| sub_module.dll_name.clone());
+
+        for dll_name in dll_names {
+            let Some(metadata) = read_assembly_metadata(&bin_path.join(&dll_name)) else {
+                continue;
+            };
+
+            for reference in metadata
+                .references
+                .iter()
+                .filter(|reference| reference.name.starts_with("TaleWorlds."))
+            {
+                match assemblies.get(&reference.name) {
+                    None => issues.push(CompatibilityIssue {
+                        kind: "missing_assembly".to_string(),
+                        subject: format!("{} ({})", reference.name, dll_name),
+                        expected: Some(reference.version_string()),
+                        found: None,
+                    }),
+                    Some(version) if *version != reference.version_string() => {
+                        issues.push(CompatibilityIssue {
+                            kind: "assembly_mismatch".to_string(),
+                            subject: format!("{} ({})", reference.name, dll_name),
+                            expected: Some(reference.version_string()),
+                            found: Some(version.clone()),
+                        })
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    ModuleCompatibility {
+        module_id: module.contents.id.clone(),
+        identifier: module.identifier.clone(),
+        name: module.contents.name.clone(),
+        compatible: issues.is_empty(),
+        issues,
+    }
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn game_compatibility_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mods: Vec<BannerlordModule>,
+) -> Result<GameCompatibility, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let game_installation_path =
+        find_installation_path(&handle, game.clone()).ok_or_else(|| {
+            format!(
+                "Could not find installation path for game with app_id {}",
+                app_id
+            )
+        })?;
+    let game_installation_path = Path::new(&game_installation_path);
+
+    let app_cache_dir = app_cache_dir(&handle)?;
+    let (modules, unreadable) = module_contents(&app_cache_dir, app_id, &mods);
+
+    let raw_game_version = installed_game_version(&app_cache_dir, app_id, game_installation_path);
+    let parsed_game_version = raw_game_version.as_deref().and_then(parse_module_version);
+
+    let assemblies = game_assemblies(
+        &game_installation_path
+            .join("bin")
+            .join("Win64_Shipping_Client"),
+    );
+
+    let modules = modules
+        .iter()
+        .filter(|module| !OFFICIAL_MODULES.contains(&module.contents.id.as_str()))
+        .map(|module| {
+            module_compatibility(
+                module,
+                parsed_game_version.as_ref(),
+                raw_game_version.as_deref(),
+                &assemblies,
+            )
+        })
+        .collect();
+
+    Ok(GameCompatibility {
+        game_version: raw_game_version,
+        modules,
+        unreadable,
+    })
+}
@@ -0,0 +1,371 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use xml::reader::EventReader;
+
+use crate::game::{
+    find_installation_path::find_installation_path, supported_games::SUPPORTED_GAMES,
+};
+use crate::xml::submodule_contents::{SubModuleContents, submodule_contents};
+
+use super::module_contents::{BannerlordModule, app_cache_dir, game_modules};
+use super::module_version::parse_module_version;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintIssue {
+    pub severity: String,
+    pub kind: String,
+    pub message: String,
+    pub path: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleLint {
+    pub identifier: String,
+    pub module_id: Option<String>,
+    pub name: Option<String>,
+    pub mod_path: String,
+    pub issues: Vec<LintIssue>,
+}
+
+fn issue(severity: &str, kind: &str, message: String, path: Option<&Path>) -> LintIssue {
+    LintIssue {
+        severity: severity.to_string(),
+        kind: kind.to_string(),
+        message,
+        path: path.map(|path| path.to_string_lossy().to_string()),
+    }
+}
+
+// Walks the whole document, submodule_contents stops quietly at the first
+// error so it cannot tell a truncated file from a short one.
+fn xml_error(path: &Path) -> Option<String> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => return Some(format!("Failed to read file: {}", e)),
+    };
+
+    for event in EventReader::from_str(content.trim_start_matches('\u{feff}')) {
+        if let Err(e) = event {
+            return Some(e.to_string());
+        }
+    }
+
+    None
+}
+
+fn collect_xml_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_xml_files(&path, files);
+        } else if path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("xml"))
+        {
+            files.push(path);
+        }
+    }
+}
+
+// Deployed links resolve to the mod they point at so they are not reported
+// as a second copy of it.
+fn canonical_path(path: &str) -> String {
+    fs::canonicalize(path)
+        .map(|path| path.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string())
+}
+
+fn lint_contents(mod_path: &Path, contents: &SubModuleContents, issues: &mut Vec<LintIssue>) {
+    let submodule_path = mod_path.join("SubModule.xml");
+
+    if contents.id.is_empty() {
+        issues.push(issue(
+            "error",
+            "missing_id",
+            "SubModule.xml does not declare a module Id".to_string(),
+            Some(&submodule_path),
+        ));
+    }
+    if contents.name.is_empty() {
+        issues.push(issue(
+            "warning",
+            "missing_name",
+            "SubModule.xml does not declare a module Name".to_string(),
+            Some(&submodule_path),
+        ));
+    }
+    match contents.version.as_deref() {
+        None => issues.push(issue(
+            "warning",
+            "missing_version",
+            "SubModule.xml does not declare a module Version".to_string(),
+            Some(&submodule_path),
+        )),
+        Some(version) if parse_module_version(version).is_none() => issues.push(issue(
+            "warning",
+            "invalid_version",
+            format!("Version '{}' could not be parsed", version),
+            Some(&submodule_path),
+        )),
+        _ => {}
+    }
+
+    let dependencies = contents
+        .depended_modules
+        .iter()
+        .flatten()
+        .chain(contents.depended_module_metadatas.iter().flatten())
+        .chain(contents.incompatible_modules.iter().flatten());
+    for dependency in dependencies {
+        if dependency.id.is_empty() {
+            issues.push(issue(
+                "error",
+                "malformed_dependency",
+                "A dependency entry is missing its module id".to_string(),
+                Some(&submodule_path),
+            ));
+        }
+        if let Some(version) = dependency.dependent_version.as_deref() {
+            if parse_module_version(version).is_none() {
+                issues.push(issue(
+                    "warning",
+                    "invalid_version",
+                    format!(
+                        "Dependency {} requires unparseable version '{}'",
+                        dependency.id, version
+                    ),
+                    Some(&submodule_path),
+                ));
+            }
+        }
+    }
+
+    let bin_path = mod_path.join("bin").join("Win64_Shipping_Client");
+    for sub_module in contents.sub_modules.iter().flatten() {
+        let label = if sub_module.name.is_empty() {
+            "<unnamed>"
+        } else {
+            sub_module.name.as_str()
+        };
+
+        if sub_module.name.is_empty() || sub_module.sub_module_class_type.is_none() {
+            issues.push(issue(
+                "error",
+                "malformed_sub_module",
+                format!(
+                    "SubModule {} is missing its Name or SubModuleClassType",
+                    label
+                ),
+                Some(&submodule_path),
+            ));
+        }
+
+        match sub_module.dll_name.as_deref() {
+            None | Some("") => issues.push(issue(
+                "error",
+                "malformed_sub_module",
+                format!("SubModule {} does not declare a DLLName", label),
+                Some(&submodule_path),
+            )),
+            Some(dll_name) => {
+                let dll_path = bin_path.join(dll_name);
+                if !dll_path.is_file() {
+                    issues.push(issue(
+                        "error",
+                        "missing_dll",
+                        format!("SubModule {} references missing {}", label, dll_name),
+                        Some(&dll_path),
+                    ));
+                }
+            }
+        }
+
+        for assembly in sub_module.assemblies.iter() {
+            let assembly_path = bin_path.join(assembly);
+            if !assembly_path.is_file() {
+                issues.push(issue(
+                    "error",
+                    "missing_dll",
+                    format!("SubModule {} references missing {}", label, assembly),
+                    Some(&assembly_path),
+                ));
+            }
+        }
+    }
+
+    let module_data_path = mod_path.join("ModuleData");
+    for xml_node in contents.xml_nodes.iter().flatten() {
+        if xml_node.path.is_empty() {
+            issues.push(issue(
+                "error",
+                "malformed_xml_node",
+                format!("XmlNode {} does not declare a path", xml_node.id),
+                Some(&submodule_path),
+            ));
+            continue;
+        }
+
+        let file_name = if xml_node.path.to_lowercase().ends_with(".xml") {
+            xml_node.path.clone()
+        } else {
+            format!("{}.xml", xml_node.path)
+        };
+        let xml_path = module_data_path.join(file_name.replace("\\", "/"));
+        // The game accepts an xslt patch in place of the xml file.
+        let xslt_path = xml_path.with_extension("xslt");
+        if !xml_path.is_file() && !xslt_path.is_file() {
+            issues.push(issue(
+                "error",
+                "missing_xml",
+                format!(
+                    "XmlNode {} points at missing {}",
+                    xml_node.id, xml_node.path
+                ),
+                Some(&xml_path),
+            ));
+        }
+    }
+}
+
+pub fn lint_module(
+    cache_dir: &Path,
+    app_id: u32,
+    module: &BannerlordModule,
+) -> (ModuleLint, Option<SubModuleContents>) {
+    let mod_path = PathBuf::from(&module.mod_path);
+    let submodule_path = mod_path.join("SubModule.xml");
+    let mut issues = Vec::new();
+    let mut contents = None;
+
+    if !submodule_path.is_file() {
+        issues.push(issue(
+            "error",
+            "missing_submodule",
+            "SubModule.xml was not found".to_string(),
+            Some(&submodule_path),
+        ));
+    } else if let Some(error) = xml_error(&submodule_path) {
+        issues.push(issue(
+            "error",
+            "malformed_submodule",
+            format!("SubModule.xml could not be parsed: {}", error),
+            Some(&submodule_path),
+        ));
+    } else {
+        contents = submodule_contents(&mod_path, cache_dir, app_id, module.identifier.clone());
+        match contents.as_ref() {
+            Some(contents) => lint_contents(&mod_path, contents, &mut issues),
+            None => issues.push(issue(
+                "error",
+                "malformed_submodule",
+                "SubModule.xml could not be read".to_string(),
+                Some(&submodule_path),
+            )),
+        }
+    }
+
+    let mut xml_files = Vec::new();
+    collect_xml_files(&mod_path.join("ModuleData"), &mut xml_files);
+    collect_xml_files(&mod_path.join("GUI"), &mut xml_files);
+    for xml_file in xml_files {
+        if let Some(error) = xml_error(&xml_file) {
+            issues.push(issue(
+                "error",
+                "invalid_xml",
+                format!("Failed to parse XML: {}", error),
+                Some(&xml_file),
+            ));
+        }
+    }
+
+    (
+        ModuleLint {
+            identifier: module.identifier.clone(),
+            module_id: contents.as_ref().map(|contents| contents.id.clone()),
+            name: contents.as_ref().map(|contents| contents.name.clone()),
+            mod_path: module.mod_path.clone(),
+            issues,
+        },
+        contents,
+    )
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn lint_modules_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mods: Vec<BannerlordModule>,
+) -> Result<Vec<ModuleLint>, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let app_cache_dir = app_cache_dir(&handle)?;
+
+    let mut lints = Vec::new();
+    let mut paths_by_id: HashMap<String, Vec<String>> = HashMap::new();
+    for module in mods.iter() {
+        let (lint, contents) = lint_module(&app_cache_dir, app_id, module);
+        if let Some(contents) = contents.filter(|contents| !contents.id.is_empty()) {
+            paths_by_id
+                .entry(contents.id)
+                .or_default()
+                .push(canonical_path(&module.mod_path));
+        }
+        lints.push(lint);
+    }
+
+    // Modules shipped with the game clash with mods just as well.
+    if let Some(game_installation_path) = find_installation_path(&handle, game.clone()) {
+        for module in game_modules(&app_cache_dir, app_id, Path::new(&game_installation_path)) {
+            let mod_path = canonical_path(&module.mod_path.to_string_lossy());
+            let paths = paths_by_id.entry(module.contents.id).or_default();
+            if !paths.contains(&mod_path) {
+                paths.push(mod_path);
+            }
+        }
+    }
+
+    for lint in lints.iter_mut() {
+        let Some(paths) = lint
+            .module_id
+            .as_ref()
+            .and_then(|module_id| paths_by_id.get(module_id))
+        else {
+            continue;
+        };
+
+        let mod_path = canonical_path(&lint.mod_path);
+        let others: Vec<&String> = paths.iter().filter(|path| **path != mod_path).collect();
+        if !others.is_empty() {
+            lint.issues.push(LintIssue {
+                severity: "error".to_string(),
+                kind: "duplicate_id".to_string(),
+                message: format!(
+                    "Module id {} is also used by {}",
+                    lint.module_id.clone().unwrap_or_default(),
+                    others
+                        .iter()
+                        .map(|path| path.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                path: Some(lint.mod_path.clone()),
+            });
+        }
+    }
+
+    Ok(lints)
+}
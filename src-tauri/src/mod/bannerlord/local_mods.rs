@@ -70,6 +70,7 @@ pub async fn local_mods(
             creator_name: meta.creator_name,
             required_items: vec![],
             child_mods: vec![],
+            submodule_info: None,
         });
     }
 
@@ -81,25 +82,23 @@ pub async fn local_mods(
         {
             if let Some((submodule_info, _)) = mod_contents_map.get(&mod_id) {
                 mod_item.game_specific_id = submodule_info.id.clone();
-                if let Some(ref depended_modules) = submodule_info.depended_modules {
-                    for dep in depended_modules {
-                        if let Some((_, identifier)) = mod_contents_map.get(&dep.id) {
-                            mod_item.required_items.push(identifier.clone());
-                        } else {
-                            mod_item.required_items.push(dep.id.clone());
-                        }
+                for dep_id in submodule_info.load_before_ids() {
+                    if let Some((_, identifier)) = mod_contents_map.get(dep_id) {
+                        mod_item.required_items.push(identifier.clone());
+                    } else {
+                        mod_item.required_items.push(dep_id.to_string());
                     }
                 }
 
-                if let Some(ref modules_to_load_after) = submodule_info.modules_to_load_after_this {
-                    for child in modules_to_load_after {
-                        if let Some((_, identifier)) = mod_contents_map.get(&child.id) {
-                            mod_item.child_mods.push(identifier.clone());
-                        } else {
-                            mod_item.child_mods.push(child.id.clone());
-                        }
+                for child_id in submodule_info.load_after_ids() {
+                    if let Some((_, identifier)) = mod_contents_map.get(child_id) {
+                        mod_item.child_mods.push(identifier.clone());
+                    } else {
+                        mod_item.child_mods.push(child_id.to_string());
                     }
                 }
+
+                mod_item.submodule_info = Some(submodule_info.clone());
             }
         }
     }
@@ -1,3 +1,12 @@
 pub mod base_mods;
+pub mod conflict_severity;
+pub mod conflicts;
+pub mod game_compatibility;
 pub mod has_mod_file;
+pub mod lint_modules;
 pub mod local_mods;
+pub mod module_contents;
+pub mod module_version;
+pub mod official_modules;
+pub mod sort_load_order;
+pub mod validate_dependencies;
@@ -0,0 +1,80 @@
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+use crate::xml::submodule_contents::{SubModuleContents, submodule_contents};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct BannerlordModule {
+    pub identifier: String,
+    pub mod_path: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoadedModule {
+    pub identifier: String,
+    pub mod_path: PathBuf,
+    pub contents: SubModuleContents,
+}
+
+pub fn app_cache_dir(handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let app_cache_dir = handle
+        .path()
+        .resolve("cache".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    if !app_cache_dir.exists() {
+        fs::create_dir_all(&app_cache_dir)
+            .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+    }
+
+    Ok(app_cache_dir)
+}
+
+pub fn module_contents(
+    cache_dir: &Path,
+    app_id: u32,
+    modules: &[BannerlordModule],
+) -> (Vec<LoadedModule>, Vec<String>) {
+    let mut loaded = Vec::with_capacity(modules.len());
+    let mut unreadable = Vec::new();
+
+    for module in modules {
+        let mod_path = PathBuf::from(&module.mod_path);
+        match submodule_contents(&mod_path, cache_dir, app_id, module.identifier.clone()) {
+            Some(contents) if !contents.id.is_empty() => loaded.push(LoadedModule {
+                identifier: module.identifier.clone(),
+                mod_path,
+                contents,
+            }),
+            _ => unreadable.push(module.identifier.clone()),
+        }
+    }
+
+    (loaded, unreadable)
+}
+
+pub fn game_modules(
+    cache_dir: &Path,
+    app_id: u32,
+    game_installation_path: &Path,
+) -> Vec<LoadedModule> {
+    let modules_path = game_installation_path.join("Modules");
+    let entries = match fs::read_dir(&modules_path) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let modules: Vec<BannerlordModule> = entries
+        .flatten()
+        .filter(|entry| entry.path().join("SubModule.xml").exists())
+        .map(|entry| BannerlordModule {
+            identifier: entry.file_name().to_string_lossy().to_string(),
+            mod_path: entry.path().to_string_lossy().to_string(),
+        })
+        .collect();
+
+    module_contents(cache_dir, app_id, &modules).0
+}
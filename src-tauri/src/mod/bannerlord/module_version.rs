@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleVersion {
+    pub release_type: u8,
+    pub parts: Vec<Option<u32>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionCheck {
+    Satisfied,
+    TooOld,
+    TooNew,
+}
+
+// Bannerlord prefixes versions with the release type: a(lpha), b(eta),
+// e(arly access), v (release) and d(evelopment).
+fn release_type_rank(prefix: char) -> Option<u8> {
+    match prefix.to_ascii_lowercase() {
+        'a' => Some(0),
+        'b' => Some(1),
+        'e' => Some(2),
+        'v' | 'd' => Some(3),
+        _ => None,
+    }
+}
+
+pub fn parse_module_version(value: &str) -> Option<ModuleVersion> {
+    let value = value.trim();
+    let first = value.chars().next()?;
+
+    let (release_type, rest) = match release_type_rank(first) {
+        Some(rank) => (rank, &value[first.len_utf8()..]),
+        None => (3, value),
+    };
+
+    let mut parts = Vec::new();
+    for part in rest.split('.') {
+        let part = part.trim();
+        if part == "*" || part.eq_ignore_ascii_case("x") {
+            parts.push(None);
+            continue;
+        }
+
+        let digits: String = part.chars().take_while(|c| c.is_ascii_digit()).collect();
+        if digits.is_empty() {
+            return None;
+        }
+        parts.push(Some(digits.parse().ok()?));
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(ModuleVersion {
+        release_type,
+        parts,
+    })
+}
+
+impl ModuleVersion {
+    fn has_wildcard(&self) -> bool {
+        self.parts.iter().any(|part| part.is_none())
+    }
+
+    fn part(&self, index: usize) -> u32 {
+        self.parts.get(index).copied().flatten().unwrap_or(0)
+    }
+}
+
+pub fn check_version(installed: &ModuleVersion, required: &ModuleVersion) -> VersionCheck {
+    if installed.release_type < required.release_type {
+        return VersionCheck::TooOld;
+    }
+
+    // A wildcard pins every component in front of it, e.g. e1.x.x accepts any 1.*.* build.
+    if required.has_wildcard() {
+        for (index, part) in required.parts.iter().enumerate() {
+            let Some(part) = part else {
+                break;
+            };
+            match installed.part(index).cmp(part) {
+                Ordering::Less => return VersionCheck::TooOld,
+                Ordering::Greater => return VersionCheck::TooNew,
+                Ordering::Equal => {}
+            }
+        }
+        return VersionCheck::Satisfied;
+    }
+
+    if installed.release_type > required.release_type {
+        return VersionCheck::Satisfied;
+    }
+
+    let length = installed.parts.len().max(required.parts.len());
+    for index in 0..length {
+        match installed.part(index).cmp(&required.part(index)) {
+            Ordering::Less => return VersionCheck::TooOld,
+            Ordering::Greater => return VersionCheck::Satisfied,
+            Ordering::Equal => {}
+        }
+    }
+
+    VersionCheck::Satisfied
+}
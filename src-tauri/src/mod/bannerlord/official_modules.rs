@@ -0,0 +1,22 @@
+pub const OFFICIAL_MODULES: &[&str] = &[
+    "Native",
+    "SandBoxCore",
+    "Sandbox",
+    "StoryMode",
+    "CustomBattle",
+];
+
+pub fn is_official(module_id: &str, module_type: Option<&str>) -> bool {
+    OFFICIAL_MODULES.contains(&module_id)
+        || matches!(module_type, Some("Official") | Some("OfficialOptional"))
+}
+
+// Canonical official modules keep their fixed order, DLCs follow them and
+// community modules come last.
+pub fn official_rank(module_id: &str, module_type: Option<&str>) -> usize {
+    match OFFICIAL_MODULES.iter().position(|id| *id == module_id) {
+        Some(position) => position,
+        None if is_official(module_id, module_type) => OFFICIAL_MODULES.len(),
+        None => OFFICIAL_MODULES.len() + 1,
+    }
+}
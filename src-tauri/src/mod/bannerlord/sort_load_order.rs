@@ -0,0 +1,316 @@
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::game::supported_games::SUPPORTED_GAMES;
+
+use super::module_contents::{BannerlordModule, LoadedModule, app_cache_dir, module_contents};
+use super::official_modules::{is_official, official_rank};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadOrderNode {
+    pub id: String,
+    pub identifier: String,
+    pub name: String,
+    pub official: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadOrderEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MissingModule {
+    pub module_id: String,
+    pub dependency_id: String,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct IncompatibleModule {
+    pub module_id: String,
+    pub incompatible_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LoadOrderResult {
+    pub order: Vec<LoadOrderNode>,
+    pub edges: Vec<LoadOrderEdge>,
+    pub missing: Vec<MissingModule>,
+    pub incompatible: Vec<IncompatibleModule>,
+    pub cycles: Vec<Vec<String>>,
+    pub unreadable: Vec<String>,
+}
+
+struct ModuleRelation {
+    other_id: String,
+    loads_before: Option<bool>,
+    optional: bool,
+    kind: &'static str,
+}
+
+fn module_relations(module: &LoadedModule) -> Vec<ModuleRelation> {
+    let mut relations = Vec::new();
+
+    for dep in module.contents.depended_modules.iter().flatten() {
+        relations.push(ModuleRelation {
+            other_id: dep.id.clone(),
+            loads_before: Some(true),
+            optional: dep.optional.unwrap_or(false),
+            kind: "dependency",
+        });
+    }
+
+    for dep in module.contents.depended_module_metadatas.iter().flatten() {
+        relations.push(ModuleRelation {
+            other_id: dep.id.clone(),
+            loads_before: match dep.load_order.as_deref() {
+                Some("LoadBeforeThis") => Some(true),
+                Some("LoadAfterThis") => Some(false),
+                _ => None,
+            },
+            optional: dep.optional.unwrap_or(false),
+            kind: "dependency",
+        });
+    }
+
+    for child in module.contents.modules_to_load_after_this.iter().flatten() {
+        relations.push(ModuleRelation {
+            other_id: child.id.clone(),
+            loads_before: Some(false),
+            optional: true,
+            kind: "load_after",
+        });
+    }
+
+    relations
+}
+
+pub fn sort_modules(modules: &[LoadedModule]) -> LoadOrderResult {
+    let mut index_by_id: HashMap<&str, usize> = HashMap::new();
+    let mut nodes: Vec<&LoadedModule> = Vec::new();
+    for module in modules {
+        if !index_by_id.contains_key(module.contents.id.as_str()) {
+            index_by_id.insert(module.contents.id.as_str(), nodes.len());
+            nodes.push(module);
+        }
+    }
+
+    let ranks: Vec<usize> = nodes
+        .iter()
+        .map(|module| official_rank(&module.contents.id, module.contents.module_type.as_deref()))
+        .collect();
+
+    let mut adjacency: Vec<Vec<usize>> = vec![Vec::new(); nodes.len()];
+    let mut in_degree = vec![0usize; nodes.len()];
+    let mut seen_edges = HashSet::new();
+    let mut edges = Vec::new();
+    let mut missing = Vec::new();
+    let mut incompatible = Vec::new();
+
+    for (index, module) in nodes.iter().enumerate() {
+        for other in module.contents.incompatible_modules.iter().flatten() {
+            if index_by_id.contains_key(other.id.as_str()) {
+                incompatible.push(IncompatibleModule {
+                    module_id: module.contents.id.clone(),
+                    incompatible_id: other.id.clone(),
+                });
+            }
+        }
+
+        for relation in module_relations(module) {
+            let other = match index_by_id.get(relation.other_id.as_str()) {
+                Some(other) => *other,
+                None => {
+                    if relation.kind == "dependency" {
+                        missing.push(MissingModule {
+                            module_id: module.contents.id.clone(),
+                            dependency_id: relation.other_id,
+                            optional: relation.optional,
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            if other == index {
+                continue;
+            }
+
+            let (from, to) = match relation.loads_before {
+                Some(true) => (other, index),
+                Some(false) => (index, other),
+                None => continue,
+            };
+
+            if seen_edges.insert((from, to)) {
+                adjacency[from].push(to);
+                in_degree[to] += 1;
+                edges.push(LoadOrderEdge {
+                    from: nodes[from].contents.id.clone(),
+                    to: nodes[to].contents.id.clone(),
+                    kind: relation.kind.to_string(),
+                });
+            }
+        }
+    }
+
+    let mut queue: BinaryHeap<Reverse<(usize, usize)>> = in_degree
+        .iter()
+        .enumerate()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(index, _)| Reverse((ranks[index], index)))
+        .collect();
+
+    let mut sorted = Vec::with_capacity(nodes.len());
+    let mut placed = vec![false; nodes.len()];
+    while let Some(Reverse((_, index))) = queue.pop() {
+        sorted.push(index);
+        placed[index] = true;
+        for &next in &adjacency[index] {
+            in_degree[next] -= 1;
+            if in_degree[next] == 0 {
+                queue.push(Reverse((ranks[next], next)));
+            }
+        }
+    }
+
+    let mut cycles = Vec::new();
+    if sorted.len() < nodes.len() {
+        for component in strongly_connected(&adjacency, &placed) {
+            if component.len() > 1 {
+                cycles.push(
+                    component
+                        .iter()
+                        .map(|index| nodes[*index].contents.id.clone())
+                        .collect(),
+                );
+            }
+        }
+
+        // Modules stuck in or behind a cycle keep their relative ranking at the end.
+        let mut remaining: Vec<usize> = (0..nodes.len()).filter(|index| !placed[*index]).collect();
+        remaining.sort_by_key(|index| (ranks[*index], *index));
+        sorted.extend(remaining);
+    }
+
+    let order = sorted
+        .into_iter()
+        .map(|index| {
+            let module = nodes[index];
+            LoadOrderNode {
+                id: module.contents.id.clone(),
+                identifier: module.identifier.clone(),
+                name: module.contents.name.clone(),
+                official: is_official(&module.contents.id, module.contents.module_type.as_deref()),
+            }
+        })
+        .collect();
+
+    LoadOrderResult {
+        order,
+        edges,
+        missing,
+        incompatible,
+        cycles,
+        unreadable: vec![],
+    }
+}
+
+fn strongly_connected(adjacency: &[Vec<usize>], placed: &[bool]) -> Vec<Vec<usize>> {
+    struct Tarjan<'a> {
+        adjacency: &'a [Vec<usize>],
+        placed: &'a [bool],
+        index: usize,
+        indices: Vec<Option<usize>>,
+        low_links: Vec<usize>,
+        on_stack: Vec<bool>,
+        stack: Vec<usize>,
+        components: Vec<Vec<usize>>,
+    }
+
+    impl Tarjan<'_> {
+        fn visit(&mut self, node: usize) {
+            self.indices[node] = Some(self.index);
+            self.low_links[node] = self.index;
+            self.index += 1;
+            self.stack.push(node);
+            self.on_stack[node] = true;
+
+            for &next in &self.adjacency[node] {
+                if self.placed[next] {
+                    continue;
+                }
+                match self.indices[next] {
+                    None => {
+                        self.visit(next);
+                        self.low_links[node] = self.low_links[node].min(self.low_links[next]);
+                    }
+                    Some(next_index) if self.on_stack[next] => {
+                        self.low_links[node] = self.low_links[node].min(next_index);
+                    }
+                    _ => {}
+                }
+            }
+
+            if Some(self.low_links[node]) == self.indices[node] {
+                let mut component = Vec::new();
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack[member] = false;
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                component.sort();
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut tarjan = Tarjan {
+        adjacency,
+        placed,
+        index: 0,
+        indices: vec![None; adjacency.len()],
+        low_links: vec![0; adjacency.len()],
+        on_stack: vec![false; adjacency.len()],
+        stack: Vec::new(),
+        components: Vec::new(),
+    };
+
+    for node in 0..adjacency.len() {
+        if !placed[node] && tarjan.indices[node].is_none() {
+            tarjan.visit(node);
+        }
+    }
+
+    tarjan.components
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn sort_load_order_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mods: Vec<BannerlordModule>,
+) -> Result<LoadOrderResult, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let app_cache_dir = app_cache_dir(&handle)?;
+    let (modules, unreadable) = module_contents(&app_cache_dir, app_id, &mods);
+
+    let mut result = sort_modules(&modules);
+    result.unreadable = unreadable;
+
+    Ok(result)
+}
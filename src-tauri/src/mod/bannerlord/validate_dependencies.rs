@@ -0,0 +1,143 @@
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::game::{
+    find_installation_path::find_installation_path, supported_games::SUPPORTED_GAMES,
+};
+
+use super::module_contents::{
+    BannerlordModule, LoadedModule, app_cache_dir, game_modules, module_contents,
+};
+use super::module_version::{VersionCheck, check_version, parse_module_version};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyIssue {
+    pub module_id: String,
+    pub module_name: String,
+    pub dependency_id: String,
+    pub required_version: Option<String>,
+    pub installed_version: Option<String>,
+    pub kind: String,
+    pub optional: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyValidation {
+    pub valid: bool,
+    pub issues: Vec<DependencyIssue>,
+    pub unreadable: Vec<String>,
+}
+
+pub fn validate_modules(
+    enabled: &[LoadedModule],
+    installed: &[LoadedModule],
+) -> Vec<DependencyIssue> {
+    let enabled_by_id: HashMap<&str, &LoadedModule> = enabled
+        .iter()
+        .map(|module| (module.contents.id.as_str(), module))
+        .collect();
+    let installed_by_id: HashMap<&str, &LoadedModule> = installed
+        .iter()
+        .map(|module| (module.contents.id.as_str(), module))
+        .collect();
+
+    let mut issues = Vec::new();
+    for module in enabled {
+        let dependencies = module
+            .contents
+            .depended_modules
+            .iter()
+            .flatten()
+            .chain(module.contents.depended_module_metadatas.iter().flatten());
+
+        for dep in dependencies {
+            let optional = dep.optional.unwrap_or(false);
+            let mut issue = |kind: &str, installed_version: Option<String>| {
+                issues.push(DependencyIssue {
+                    module_id: module.contents.id.clone(),
+                    module_name: module.contents.name.clone(),
+                    dependency_id: dep.id.clone(),
+                    required_version: dep.dependent_version.clone(),
+                    installed_version,
+                    kind: kind.to_string(),
+                    optional,
+                });
+            };
+
+            let dependency = match enabled_by_id.get(dep.id.as_str()) {
+                Some(dependency) => dependency,
+                None => {
+                    if !optional {
+                        match installed_by_id.get(dep.id.as_str()) {
+                            Some(installed) => {
+                                issue("not_enabled", installed.contents.version.clone())
+                            }
+                            None => issue("missing", None),
+                        }
+                    }
+                    continue;
+                }
+            };
+
+            let (Some(required), Some(installed)) = (
+                dep.dependent_version
+                    .as_deref()
+                    .and_then(parse_module_version),
+                dependency
+                    .contents
+                    .version
+                    .as_deref()
+                    .and_then(parse_module_version),
+            ) else {
+                continue;
+            };
+
+            match check_version(&installed, &required) {
+                VersionCheck::Satisfied => {}
+                VersionCheck::TooOld => issue("too_old", dependency.contents.version.clone()),
+                VersionCheck::TooNew => issue("too_new", dependency.contents.version.clone()),
+            }
+        }
+    }
+
+    issues
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn validate_dependencies_bannerlord(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mods: Vec<BannerlordModule>,
+) -> Result<DependencyValidation, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "bannerlord" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let game_installation_path =
+        find_installation_path(&handle, game.clone()).ok_or_else(|| {
+            format!(
+                "Could not find installation path for game with app_id {}",
+                app_id
+            )
+        })?;
+
+    let app_cache_dir = app_cache_dir(&handle)?;
+    let (enabled, unreadable) = module_contents(&app_cache_dir, app_id, &mods);
+
+    let mut installed = game_modules(&app_cache_dir, app_id, Path::new(&game_installation_path));
+    installed.extend(enabled.iter().cloned());
+
+    let issues = validate_modules(&enabled, &installed);
+
+    Ok(DependencyValidation {
+        valid: issues.iter().all(|issue| issue.optional),
+        issues,
+        unreadable,
+    })
+}
@@ -1,8 +1,10 @@
 use serde::Deserialize;
 
 use crate::game::supported_games::SUPPORTED_GAMES;
+use crate::xml::submodule_contents::SubModuleContents;
 
 use super::bannerlord;
+use super::totalwar;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
@@ -32,6 +34,7 @@ pub struct ModItem {
     pub creator_name: Option<String>,
     pub required_items: Vec<String>,
     pub child_mods: Vec<String>,
+    pub submodule_info: Option<SubModuleContents>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -58,7 +61,10 @@ pub async fn base_mods(handle: tauri::AppHandle, app_id: u32) -> Result<Vec<ModI
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
 
     match game.r#type.as_ref() {
-        "totalwar" => Ok(vec![]),
+        "totalwar" => {
+            let mods = totalwar::base_mods::base_mods(handle, app_id).await?;
+            Ok(mods)
+        }
         "bannerlord" => {
             let mods = bannerlord::base_mods::base_mods(handle, app_id).await?;
             Ok(mods)
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConflictAcknowledgement {
+    pub mod_file_path: String,
+    pub other_mod_file_path: String,
+    pub paths: Vec<String>,
+    pub acknowledged_at: u128,
+}
+
+pub fn normalize_path(path: &str) -> String {
+    path.replace('\\', "/").trim_end_matches('/').to_lowercase()
+}
+
+// Mods in different folders can share a file name, so pairs are keyed on
+// their full paths.
+pub fn acknowledgement_key(mod_file_path: &str, other_mod_file_path: &str) -> String {
+    let mod_file_path = normalize_path(mod_file_path);
+    let other_mod_file_path = normalize_path(other_mod_file_path);
+    if mod_file_path <= other_mod_file_path {
+        format!("{}|{}", mod_file_path, other_mod_file_path)
+    } else {
+        format!("{}|{}", other_mod_file_path, mod_file_path)
+    }
+}
+
+fn acknowledgements_file(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<PathBuf, String> {
+    let acknowledgements_dir = handle
+        .path()
+        .resolve(
+            "conflict_acknowledgements".to_string(),
+            BaseDirectory::AppConfig,
+        )
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?
+        .join(app_id.to_string());
+
+    if !acknowledgements_dir.exists() {
+        fs::create_dir_all(&acknowledgements_dir)
+            .map_err(|e| format!("Failed to create acknowledgements directory: {}", e))?;
+    }
+
+    Ok(acknowledgements_dir.join(format!("{}.json", profile_id)))
+}
+
+pub fn load_acknowledgements(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<BTreeMap<String, ConflictAcknowledgement>, String> {
+    let file_path = acknowledgements_file(handle, app_id, profile_id)?;
+    if !file_path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| format!("Failed to read acknowledgements: {}", e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to parse acknowledgements: {}", e))
+}
+
+fn save_acknowledgements(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    acknowledgements: &BTreeMap<String, ConflictAcknowledgement>,
+) -> Result<(), String> {
+    let file_path = acknowledgements_file(handle, app_id, profile_id)?;
+    let json = serde_json::to_string_pretty(acknowledgements)
+        .map_err(|e| format!("Failed to serialize acknowledgements: {}", e))?;
+    fs::write(&file_path, json).map_err(|e| format!("Failed to write acknowledgements: {}", e))
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn conflict_acknowledgements(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+) -> Result<Vec<ConflictAcknowledgement>, String> {
+    let acknowledgements = load_acknowledgements(&handle, app_id, profile_id)?;
+    Ok(acknowledgements.into_values().collect())
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn acknowledge_conflict(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    mod_file_path: String,
+    other_mod_file_path: String,
+    paths: Vec<String>,
+) -> Result<(), String> {
+    let mut acknowledgements = load_acknowledgements(&handle, app_id, profile_id)?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Time error: {}", e))?
+        .as_millis();
+
+    let key = acknowledgement_key(&mod_file_path, &other_mod_file_path);
+    let acknowledgement = acknowledgements
+        .entry(key)
+        .or_insert_with(|| ConflictAcknowledgement {
+            mod_file_path: mod_file_path.clone(),
+            other_mod_file_path: other_mod_file_path.clone(),
+            paths: vec![],
+            acknowledged_at: now,
+        });
+
+    for path in paths {
+        if !acknowledgement.paths.contains(&path) {
+            acknowledgement.paths.push(path);
+        }
+    }
+    acknowledgement.paths.sort();
+    acknowledgement.acknowledged_at = now;
+
+    save_acknowledgements(&handle, app_id, profile_id, &acknowledgements)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn unacknowledge_conflict(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    mod_file_path: String,
+    other_mod_file_path: String,
+) -> Result<(), String> {
+    let mut acknowledgements = load_acknowledgements(&handle, app_id, profile_id)?;
+    acknowledgements.remove(&acknowledgement_key(&mod_file_path, &other_mod_file_path));
+    save_acknowledgements(&handle, app_id, profile_id, &acknowledgements)
+}
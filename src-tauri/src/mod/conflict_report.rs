@@ -0,0 +1,345 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{Manager, path::BaseDirectory};
+
+use super::bannerlord;
+use super::conflict_acknowledgements::{acknowledgement_key, load_acknowledgements};
+use super::conflicts::conflicts;
+use super::totalwar;
+use crate::game::supported_games::SUPPORTED_GAMES;
+use crate::utils::create_app_default_paths::create_app_default_paths;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictSeverity {
+    Harmless,
+    Low,
+    Medium,
+    High,
+}
+
+impl ConflictSeverity {
+    pub fn weight(&self) -> u32 {
+        match self {
+            ConflictSeverity::Harmless => 0,
+            ConflictSeverity::Low => 1,
+            ConflictSeverity::Medium => 3,
+            ConflictSeverity::High => 10,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictSeverity::Harmless => "harmless",
+            ConflictSeverity::Low => "low",
+            ConflictSeverity::Medium => "medium",
+            ConflictSeverity::High => "high",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConflictCategory {
+    DbTable,
+    Script,
+    Ui,
+    Text,
+    Asset,
+}
+
+impl ConflictCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConflictCategory::DbTable => "db_table",
+            ConflictCategory::Script => "script",
+            ConflictCategory::Ui => "ui",
+            ConflictCategory::Text => "text",
+            ConflictCategory::Asset => "asset",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictPath {
+    pub path: String,
+    pub category: ConflictCategory,
+    pub severity: ConflictSeverity,
+    pub acknowledged: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictReportEntry {
+    pub mod_file: String,
+    pub mod_file_path: String,
+    pub other_mod_file: String,
+    pub other_mod_file_path: String,
+    pub severity: ConflictSeverity,
+    pub score: u32,
+    pub acknowledged: bool,
+    pub paths: Vec<ConflictPath>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictReportSummary {
+    pub total_pairs: usize,
+    pub total_paths: usize,
+    pub unacknowledged_pairs: usize,
+    pub by_severity: BTreeMap<String, usize>,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConflictReport {
+    pub app_id: u32,
+    pub profile_id: u64,
+    pub generated_at: u128,
+    pub summary: ConflictReportSummary,
+    pub entries: Vec<ConflictReportEntry>,
+}
+
+pub async fn build_conflict_report(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    folder_paths: Vec<String>,
+) -> Result<ConflictReport, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    let conflict_map = conflicts(handle.clone(), app_id, folder_paths).await?;
+    let acknowledgements = load_acknowledgements(handle, app_id, profile_id)?;
+
+    let mut entries = Vec::new();
+    for (mod_file_path, other_mods) in &conflict_map {
+        for (other_mod_file_path, shared_paths) in other_mods {
+            // The conflict map lists every pair twice, keep one side only.
+            if mod_file_path > other_mod_file_path {
+                continue;
+            }
+
+            let mod_file = file_name(mod_file_path);
+            let other_mod_file = file_name(other_mod_file_path);
+            let acknowledged_paths = acknowledgements
+                .get(&acknowledgement_key(mod_file_path, other_mod_file_path))
+                .map(|ack| ack.paths.clone())
+                .unwrap_or_default();
+
+            let paths: Vec<ConflictPath> = shared_paths
+                .iter()
+                .map(|path| {
+                    let (category, severity) = match game.r#type {
+                        "totalwar" => totalwar::conflict_severity::conflict_severity(path),
+                        "bannerlord" => bannerlord::conflict_severity::conflict_severity(path),
+                        _ => (ConflictCategory::Asset, ConflictSeverity::Low),
+                    };
+                    ConflictPath {
+                        path: path.clone(),
+                        category,
+                        severity,
+                        acknowledged: acknowledged_paths.contains(path),
+                    }
+                })
+                .collect();
+
+            let severity = paths
+                .iter()
+                .map(|p| p.severity)
+                .max()
+                .unwrap_or(ConflictSeverity::Harmless);
+            let score = paths.iter().map(|p| p.severity.weight()).sum();
+            let acknowledged = paths.iter().all(|p| p.acknowledged);
+
+            entries.push(ConflictReportEntry {
+                mod_file,
+                mod_file_path: mod_file_path.clone(),
+                other_mod_file,
+                other_mod_file_path: other_mod_file_path.clone(),
+                severity,
+                score,
+                acknowledged,
+                paths,
+            });
+        }
+    }
+
+    entries.sort_by(|a, b| {
+        b.severity
+            .cmp(&a.severity)
+            .then_with(|| b.score.cmp(&a.score))
+            .then_with(|| a.mod_file.cmp(&b.mod_file))
+    });
+
+    let mut by_severity = BTreeMap::new();
+    for entry in &entries {
+        *by_severity
+            .entry(entry.severity.as_str().to_string())
+            .or_insert(0) += 1;
+    }
+
+    let summary = ConflictReportSummary {
+        total_pairs: entries.len(),
+        total_paths: entries.iter().map(|e| e.paths.len()).sum(),
+        unacknowledged_pairs: entries.iter().filter(|e| !e.acknowledged).count(),
+        by_severity,
+        score: entries.iter().map(|e| e.score).sum(),
+    };
+
+    let generated_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|e| format!("Time error: {}", e))?
+        .as_millis();
+
+    Ok(ConflictReport {
+        app_id,
+        profile_id,
+        generated_at,
+        summary,
+        entries,
+    })
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn conflict_report(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    folder_paths: Vec<String>,
+) -> Result<ConflictReport, String> {
+    build_conflict_report(&handle, app_id, profile_id, folder_paths).await
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn export_conflict_report(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    profile_id: u64,
+    profile_name: String,
+    folder_paths: Vec<String>,
+    format: String,
+) -> Result<String, String> {
+    let report = build_conflict_report(&handle, app_id, profile_id, folder_paths).await?;
+
+    let _ = create_app_default_paths(handle.clone());
+    let default_exports_path = handle
+        .path()
+        .resolve("exports".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    let contents = match format.as_str() {
+        "json" => serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize conflict report: {}", e))?,
+        "html" => render_html(&report, &profile_name),
+        _ => return Err(format!("Unsupported report format: {}", format)),
+    };
+
+    let file_name = format!(
+        "conflict_report_{}_{}_{}.{}",
+        profile_name, app_id, profile_id, format
+    );
+    let file_path = default_exports_path.join(file_name);
+
+    let mut file = File::create(&file_path).map_err(|e| format!("Failed to create file: {}", e))?;
+    file.write_all(contents.as_bytes())
+        .map_err(|e| format!("Failed to write to file: {}", e))?;
+
+    Ok(file_path.to_string_lossy().into_owned())
+}
+
+fn file_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.to_string())
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn render_html(report: &ConflictReport, profile_name: &str) -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Conflict Report</title>\n\
+         <style>\n\
+         body { font-family: sans-serif; background: #111; color: #ddd; margin: 2rem; }\n\
+         table { border-collapse: collapse; width: 100%; margin-bottom: 2rem; }\n\
+         th, td { border: 1px solid #333; padding: 4px 8px; text-align: left; vertical-align: top; }\n\
+         .high { color: #f87171; } .medium { color: #fbbf24; } .low { color: #60a5fa; } .harmless { color: #9ca3af; }\n\
+         .acknowledged { opacity: 0.5; }\n\
+         </style>\n</head>\n<body>\n",
+    );
+
+    html.push_str(&format!(
+        "<h1>Conflict Report - {}</h1>\n<p>App ID: {} | Profile ID: {} | Generated at: {}</p>\n",
+        escape_html(profile_name),
+        report.app_id,
+        report.profile_id,
+        report.generated_at
+    ));
+
+    html.push_str("<h2>Summary</h2>\n<table>\n");
+    html.push_str(&format!(
+        "<tr><th>Conflicting pairs</th><td>{}</td></tr>\n\
+         <tr><th>Conflicting files</th><td>{}</td></tr>\n\
+         <tr><th>Unacknowledged pairs</th><td>{}</td></tr>\n\
+         <tr><th>Score</th><td>{}</td></tr>\n",
+        report.summary.total_pairs,
+        report.summary.total_paths,
+        report.summary.unacknowledged_pairs,
+        report.summary.score
+    ));
+    for (severity, count) in &report.summary.by_severity {
+        html.push_str(&format!(
+            "<tr><th class=\"{}\">{}</th><td>{}</td></tr>\n",
+            severity, severity, count
+        ));
+    }
+    html.push_str("</table>\n");
+
+    for entry in &report.entries {
+        html.push_str(&format!(
+            "<h3 class=\"{}{}\">{} &harr; {} ({}, score {})</h3>\n",
+            entry.severity.as_str(),
+            if entry.acknowledged {
+                " acknowledged"
+            } else {
+                ""
+            },
+            escape_html(&entry.mod_file),
+            escape_html(&entry.other_mod_file),
+            entry.severity.as_str(),
+            entry.score
+        ));
+        html.push_str("<table>\n<tr><th>Path</th><th>Category</th><th>Severity</th><th>Acknowledged</th></tr>\n");
+        for path in &entry.paths {
+            html.push_str(&format!(
+                "<tr class=\"{}{}\"><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                path.severity.as_str(),
+                if path.acknowledged {
+                    " acknowledged"
+                } else {
+                    ""
+                },
+                escape_html(&path.path),
+                path.category.as_str(),
+                path.severity.as_str(),
+                if path.acknowledged { "yes" } else { "no" }
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
@@ -2,7 +2,7 @@ use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
-use super::totalwar;
+use super::{bannerlord, totalwar};
 use crate::game::supported_games::SUPPORTED_GAMES;
 
 #[derive(Serialize, Deserialize)]
@@ -35,7 +35,11 @@ pub async fn conflicts(
                 totalwar::conflicts::conflicts(handle, app_id, folder_paths).await?;
             Ok(conflicts_result)
         }
-        "bannerlord" => Ok(BTreeMap::new()),
+        "bannerlord" => {
+            let conflicts_result =
+                bannerlord::conflicts::conflicts(handle, app_id, folder_paths).await?;
+            Ok(conflicts_result)
+        }
         _ => Err(format!("Game type '{}' is not supported", game.r#type)),
     }
 }
@@ -1,14 +1,13 @@
 use serde::{Deserialize, Serialize};
 use std::fs::{File, create_dir_all, read_dir, remove_dir_all, rename};
 use std::io::Write;
-use std::os::windows::process::CommandExt;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::time::{SystemTime, UNIX_EPOCH};
 use tauri::AppHandle;
 
 use crate::sevenz::find_7zip_path::find_7zip_path;
 use crate::utils::create_app_default_paths::create_app_default_paths;
+use crate::utils::hidden_command::hidden_command;
 
 use super::base_mods::ModVersion;
 use super::validate_mod_path::validate_mod_path;
@@ -104,8 +103,7 @@ pub async fn install_mod(
         if let Some(image_file_path) = &mod_details.image_file_path {
             if !image_file_path.trim().is_empty() {
                 let output_path = format!("-o{}", mod_folder.to_str().unwrap());
-                let extract_result = Command::new(&seven_zip_path)
-                    .creation_flags(0x08000000)
+                let extract_result = hidden_command(&seven_zip_path)
                     .args([
                         "e",
                         &mod_details.zip_file_path,
@@ -164,8 +162,7 @@ pub async fn install_mod(
     create_dir_all(&temp_extract_folder)
         .map_err(|e| format!("Failed to create temp directory: {}", e))?;
 
-    let output = Command::new(&seven_zip_path)
-        .creation_flags(0x08000000)
+    let output = hidden_command(&seven_zip_path)
         .args([
             "x",
             &mod_details.zip_file_path,
@@ -24,7 +24,7 @@ pub async fn local_mods(
 
     match game.r#type.as_ref() {
         "totalwar" => {
-            let mods = totalwar::local_mods::local_mods(app_mods_path).await?;
+            let mods = totalwar::local_mods::local_mods(handle, app_mods_path).await?;
             Ok(mods)
         }
         "bannerlord" => {
@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::game::{
+    find_installation_path::find_installation_path, supported_games::SUPPORTED_GAMES,
+};
+use crate::pack::migrate_local_mod::migrate_local_mod;
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn migrate_data_mod(
+    handle: tauri::AppHandle,
+    app_id: u32,
+    mod_file_path: String,
+    mod_installation_path: String,
+    remove_source: bool,
+) -> Result<String, String> {
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    if game.r#type != "totalwar" {
+        return Err(format!("Game type '{}' is not supported", game.r#type));
+    }
+
+    let game_installation_path =
+        find_installation_path(&handle, game.clone()).ok_or_else(|| {
+            format!(
+                "Could not find installation path for game with app_id {}",
+                app_id
+            )
+        })?;
+
+    let data_path = PathBuf::from(game_installation_path).join("data");
+    let source_path = Path::new(&mod_file_path);
+    if source_path.parent() != Some(data_path.as_path()) {
+        return Err(format!(
+            "Pack file is not inside the game's data folder: {}",
+            mod_file_path
+        ));
+    }
+
+    let mod_title = source_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .ok_or_else(|| "Failed to get file name or convert to string".to_string())?;
+
+    let identifier = migrate_local_mod(
+        app_id,
+        &mod_file_path,
+        &mod_title,
+        None,
+        &mod_installation_path,
+    )?;
+
+    // The game loads everything in data/, leaving the original behind would load it twice.
+    if remove_source {
+        fs::remove_file(source_path)
+            .map_err(|e| format!("Failed to remove pack file from data folder: {}", e))?;
+
+        for ext in ["png", "jpg"] {
+            let image_path = source_path.with_extension(ext);
+            if image_path.is_file() {
+                let _ = fs::remove_file(image_path);
+            }
+        }
+    }
+
+    Ok(identifier)
+}
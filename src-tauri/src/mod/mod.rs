@@ -1,8 +1,11 @@
 pub mod bannerlord;
 pub mod base_mods;
+pub mod conflict_acknowledgements;
+pub mod conflict_report;
 pub mod conflicts;
 pub mod delete;
 pub mod install;
 pub mod local_mods;
+pub mod migrate_data_mod;
 pub mod totalwar;
 pub mod validate_mod_path;
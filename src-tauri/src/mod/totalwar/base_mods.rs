@@ -0,0 +1,120 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::UNIX_EPOCH;
+use tauri::Manager;
+use tauri::path::BaseDirectory;
+
+use crate::game::{
+    find_installation_path::find_installation_path, supported_games::SUPPORTED_GAMES,
+};
+use crate::r#mod::base_mods::{ModItem, ModVersion};
+use crate::pack::pack_file_type::{PackFileType, pack_file_type};
+use crate::utils::thumbnail_cache::file_thumbnail;
+
+use super::vanilla_packs::vanilla_packs;
+
+pub async fn base_mods(handle: tauri::AppHandle, app_id: u32) -> Result<Vec<ModItem>, String> {
+    let app_cache_dir = handle
+        .path()
+        .resolve("cache".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    if !app_cache_dir.exists() {
+        fs::create_dir_all(&app_cache_dir)
+            .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+    }
+
+    let game = SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+    let game_installation_path = match find_installation_path(&handle, game.clone()) {
+        Some(path) => path,
+        None => return Ok(vec![]),
+    };
+
+    let data_path = PathBuf::from(game_installation_path).join("data");
+    if !data_path.exists() {
+        return Ok(vec![]);
+    }
+
+    // Without the manifest there is no reliable way to tell vanilla packs apart.
+    let vanilla = match vanilla_packs(&data_path) {
+        Some(vanilla) => vanilla,
+        None => return Ok(vec![]),
+    };
+
+    let mut mods: Vec<ModItem> = vec![];
+    for entry in fs::read_dir(&data_path).map_err(|e| e.to_string())? {
+        let entry = entry.map_err(|e| e.to_string())?;
+        let pack_path = entry.path();
+
+        if !pack_path.is_file() || pack_path.extension().map_or(true, |ext| ext != "pack") {
+            continue;
+        }
+
+        let mod_file = entry.file_name().to_string_lossy().to_string();
+        if vanilla.contains(&mod_file.to_lowercase()) {
+            continue;
+        }
+
+        let pack_type = match pack_file_type(&pack_path) {
+            Some(pack_type) => pack_type,
+            None => continue,
+        };
+        if !matches!(pack_type, PackFileType::Mod | PackFileType::Movie) {
+            continue;
+        }
+
+        let modified_at = entry
+            .metadata()
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis())
+            .unwrap_or_default();
+
+        let preview_local = ["png", "jpg"]
+            .iter()
+            .map(|ext| pack_path.with_extension(ext))
+            .find(|image_path| image_path.is_file())
+            .and_then(|image_path| file_thumbnail(&app_cache_dir, &image_path))
+            .unwrap_or_default();
+
+        let title = pack_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| mod_file.clone());
+
+        mods.push(ModItem {
+            game_specific_id: mod_file.clone(),
+            identifier: mod_file.clone(),
+            title,
+            description: Some(String::from("")),
+            created_at: modified_at,
+            updated_at: Some(modified_at),
+            categories: Some(String::from(if pack_type == PackFileType::Movie {
+                "Movie"
+            } else {
+                "Data"
+            })),
+            url: None,
+            download_url: None,
+            preview_url: None,
+            version: Some(ModVersion::Text(String::from(""))),
+            item_type: String::from("data_mod"),
+            mod_file,
+            mod_file_path: pack_path.to_string_lossy().to_string(),
+            preview_local,
+            creator_id: None,
+            creator_name: Some(String::from("")),
+            required_items: vec![],
+            child_mods: vec![],
+            submodule_info: None,
+        });
+    }
+
+    mods.sort_by(|a, b| a.mod_file.to_lowercase().cmp(&b.mod_file.to_lowercase()));
+
+    Ok(mods)
+}
@@ -0,0 +1,23 @@
+use crate::r#mod::conflict_report::{ConflictCategory, ConflictSeverity};
+
+pub fn conflict_severity(path: &str) -> (ConflictCategory, ConflictSeverity) {
+    let path = path.to_lowercase();
+
+    if path.starts_with("db/") {
+        return (ConflictCategory::DbTable, ConflictSeverity::High);
+    }
+
+    if path.starts_with("script/") || path.ends_with(".lua") {
+        return (ConflictCategory::Script, ConflictSeverity::High);
+    }
+
+    if path.starts_with("ui/") {
+        return (ConflictCategory::Ui, ConflictSeverity::Medium);
+    }
+
+    if path.starts_with("text/") || path.ends_with(".loc") {
+        return (ConflictCategory::Text, ConflictSeverity::Harmless);
+    }
+
+    (ConflictCategory::Asset, ConflictSeverity::Low)
+}
@@ -10,6 +10,8 @@ use tauri::Manager;
 use tauri::path::BaseDirectory;
 use tokio::task;
 
+use crate::pack::pack_file_type::is_pack_path;
+
 #[derive(Encode, Decode, PartialEq, Eq, Clone, Debug)]
 pub struct FileMetadata {
     pub size: u64,
@@ -58,17 +60,14 @@ pub async fn conflicts(
                                 .flatten()
                                 .flat_map(|sub_entry| {
                                     let sub_path = sub_entry.ok()?.path();
-                                    (sub_path.extension()?.to_str() == Some("pack"))
-                                        .then_some(sub_path)
+                                    is_pack_path(&sub_path).then_some(sub_path)
                                 })
                                 .collect::<Vec<_>>()
                         } else {
-                            match entry_path.extension().and_then(|ext| ext.to_str()) {
-                                Some("pack") => Some(entry_path),
-                                _ => None,
-                            }
-                            .into_iter()
-                            .collect()
+                            is_pack_path(&entry_path)
+                                .then_some(entry_path)
+                                .into_iter()
+                                .collect()
                         }
                     })
                     .collect()
@@ -1,6 +1,13 @@
 use std::{fs::read_dir, path::Path};
 
-pub fn find_mod_file_and_image(dir_path: &Path) -> (String, String, String) {
+use crate::pack::pack_file_type::is_pack_path;
+use crate::pack::pack_preview_image::pack_preview_image;
+use crate::utils::thumbnail_cache::{
+    cached_thumbnail, create_thumbnails, file_thumbnail, is_marked_missing, mark_missing,
+    thumbnail_key,
+};
+
+pub fn find_mod_file_and_image(dir_path: &Path, cache_dir: &Path) -> (String, String, String) {
     if !dir_path.exists() {
         return (String::new(), String::new(), String::new());
     }
@@ -13,7 +20,7 @@ pub fn find_mod_file_and_image(dir_path: &Path) -> (String, String, String) {
             let path = entry.path();
 
             if path.is_file() {
-                if path.extension().map_or(false, |ext| ext == "pack") {
+                if is_pack_path(&path) {
                     mod_file = (
                         path.file_name()
                             .and_then(|n| n.to_str())
@@ -37,5 +44,33 @@ pub fn find_mod_file_and_image(dir_path: &Path) -> (String, String, String) {
         }
     }
 
-    (mod_file.0, mod_file.1, image_file)
+    let preview_local = preview_thumbnail(cache_dir, &mod_file.1, &image_file);
+
+    (mod_file.0, mod_file.1, preview_local)
+}
+
+fn preview_thumbnail(cache_dir: &Path, mod_file_path: &str, image_file: &str) -> String {
+    if !mod_file_path.is_empty() {
+        let pack_path = Path::new(mod_file_path);
+        let key = thumbnail_key(mod_file_path);
+
+        if let Some(cached) = cached_thumbnail(cache_dir, &key, Some(pack_path)) {
+            return cached;
+        }
+
+        if !is_marked_missing(cache_dir, &key, pack_path) {
+            match pack_preview_image(pack_path)
+                .and_then(|image_data| create_thumbnails(cache_dir, &key, &image_data).ok())
+            {
+                Some(thumbnail) => return thumbnail,
+                None => mark_missing(cache_dir, &key),
+            }
+        }
+    }
+
+    if image_file.is_empty() {
+        return String::new();
+    }
+
+    file_thumbnail(cache_dir, Path::new(image_file)).unwrap_or_else(|| image_file.to_string())
 }
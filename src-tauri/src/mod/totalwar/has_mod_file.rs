@@ -1,11 +1,13 @@
 use std::{fs::read_dir, path::Path};
 
+use crate::pack::pack_file_type::is_pack_path;
+
 pub fn has_mod_file(path: &Path) -> bool {
     if let Ok(entries) = read_dir(path) {
         for entry in entries {
             if let Ok(entry) = entry {
                 let path = entry.path();
-                if path.is_file() && path.extension().and_then(|ext| ext.to_str()) == Some("pack") {
+                if path.is_file() && is_pack_path(&path) {
                     return true;
                 }
             }
@@ -1,11 +1,26 @@
 use std::fs;
 use std::path::PathBuf;
+use tauri::Manager;
+use tauri::path::BaseDirectory;
 
 use crate::r#mod::base_mods::{LocalModMeta, ModItem};
 
 use super::find_mod_file_and_image::find_mod_file_and_image;
 
-pub async fn local_mods(app_mods_path: PathBuf) -> Result<Vec<ModItem>, String> {
+pub async fn local_mods(
+    handle: tauri::AppHandle,
+    app_mods_path: PathBuf,
+) -> Result<Vec<ModItem>, String> {
+    let app_cache_dir = handle
+        .path()
+        .resolve("cache".to_string(), BaseDirectory::AppConfig)
+        .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
+
+    if !app_cache_dir.exists() {
+        fs::create_dir_all(&app_cache_dir)
+            .map_err(|e| format!("Failed to create cache directory: {}", e))?;
+    }
+
     let mut mods: Vec<ModItem> = vec![];
     for entry in fs::read_dir(app_mods_path).map_err(|e| e.to_string())? {
         let entry = entry.map_err(|e| e.to_string())?;
@@ -23,7 +38,7 @@ pub async fn local_mods(app_mods_path: PathBuf) -> Result<Vec<ModItem>, String>
         let meta_content = fs::read_to_string(&meta_path).map_err(|e| e.to_string())?;
         let meta: LocalModMeta = serde_json::from_str(&meta_content).map_err(|e| e.to_string())?;
 
-        let mod_file_and_images_paths = find_mod_file_and_image(&mod_path);
+        let mod_file_and_images_paths = find_mod_file_and_image(&mod_path, &app_cache_dir);
 
         mods.push(ModItem {
             game_specific_id: String::from(""),
@@ -45,6 +60,7 @@ pub async fn local_mods(app_mods_path: PathBuf) -> Result<Vec<ModItem>, String>
             creator_name: meta.creator_name,
             required_items: vec![],
             child_mods: vec![],
+            submodule_info: None,
         });
     }
 
@@ -1,4 +1,7 @@
+pub mod base_mods;
+pub mod conflict_severity;
 pub mod conflicts;
 pub mod find_mod_file_and_image;
 pub mod has_mod_file;
 pub mod local_mods;
+pub mod vanilla_packs;
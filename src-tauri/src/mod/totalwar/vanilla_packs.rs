@@ -0,0 +1,16 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+
+pub fn vanilla_packs(data_path: &Path) -> Option<HashSet<String>> {
+    let manifest = fs::read_to_string(data_path.join("manifest.txt")).ok()?;
+
+    Some(
+        manifest
+            .lines()
+            .filter_map(|line| line.split('\t').next())
+            .map(|file| file.trim().replace('\\', "/").to_lowercase())
+            .filter(|file| file.ends_with(".pack") && !file.contains('/'))
+            .collect(),
+    )
+}
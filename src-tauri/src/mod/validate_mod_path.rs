@@ -26,8 +26,8 @@ pub fn validate_mod_path(
         }
     }
 
-    let expected_suffix = format!("{}\\{}", app_id, item_id);
-    if !path_str.ends_with(&expected_suffix) {
+    let expected_suffix = Path::new(&app_id.to_string()).join(&item_id);
+    if !path.ends_with(&expected_suffix) {
         return Err(format!(
             "Path does not match expected folder structure: {}",
             path_str
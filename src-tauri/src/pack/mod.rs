@@ -1,8 +1,13 @@
 pub mod migrate_local_mod;
 pub mod open_pack_file;
+pub mod outdated_tables;
 pub mod pack_db_data;
 pub mod pack_db_data_raw;
 pub mod pack_fetch_data;
+pub mod pack_file_type;
 pub mod pack_files;
 pub mod pack_loc_data;
 pub mod pack_loc_data_raw;
+pub mod pack_preview_image;
+pub mod pack_texture_data;
+pub mod pack_version;
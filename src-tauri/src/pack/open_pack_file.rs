@@ -1,4 +1,5 @@
-use std::{os::windows::process::CommandExt, process::Command};
+#[cfg(windows)]
+use crate::utils::hidden_command::hidden_command;
 
 #[tauri::command(rename_all = "snake_case")]
 pub fn open_pack_file(pack_file_path: String) -> Result<(), String> {
@@ -6,10 +7,14 @@ pub fn open_pack_file(pack_file_path: String) -> Result<(), String> {
         return Err("The file must have a .pack extension".to_string());
     }
 
-    let result = Command::new("cmd")
-        .creation_flags(0x08000000)
+    #[cfg(windows)]
+    let result = hidden_command("cmd")
         .args(&["/C", "start", "", &pack_file_path])
         .output();
+    #[cfg(not(windows))]
+    let result = std::process::Command::new("xdg-open")
+        .arg(&pack_file_path)
+        .output();
 
     match result {
         Ok(output) => {
@@ -0,0 +1,60 @@
+use rpfm_lib::files::pack::Pack;
+use rpfm_lib::files::{Container, DecodeableExtraData, FileType, RFileDecoded};
+use rpfm_lib::schema::Schema;
+use std::env;
+use std::path::{Path, PathBuf};
+
+use crate::game::supported_games::Game;
+
+pub fn load_schema(game: &Game) -> Result<Schema, String> {
+    let exe_path = env::current_exe().map_err(|e| format!("Failed to get exe path: {}", e))?;
+    let exe_dir = exe_path
+        .parent()
+        .ok_or_else(|| "Failed to get exe directory".to_string())?;
+
+    Schema::load(&exe_dir.join(game.schema_file), None)
+        .map_err(|e| format!("Failed to load schema: {}", e))
+}
+
+// A table is outdated when the schema knows a newer version of it than the
+// one in the pack, or when no definition matches its version at all.
+pub fn outdated_tables(schema: &Schema, pack_file_path: &Path) -> Result<Vec<String>, String> {
+    let mut packfile = Pack::read_and_merge(&[PathBuf::from(pack_file_path)], true, false, false)
+        .map_err(|e| format!("Failed to read pack file: {:?}", e))?;
+
+    let mut decode_extra_data = DecodeableExtraData::default();
+    decode_extra_data.set_schema(Some(schema));
+    let extra_data = Some(decode_extra_data);
+
+    let mut outdated = Vec::new();
+    for file in packfile.files_by_type_mut(&[FileType::DB]) {
+        let path = file.path_in_container().path_raw().to_owned();
+        let Some(table_name) = path.split('/').nth(1).map(|name| name.to_string()) else {
+            continue;
+        };
+
+        let latest_version =
+            schema
+                .definitions_by_table_name(&table_name)
+                .and_then(|definitions| {
+                    definitions
+                        .iter()
+                        .map(|definition| *definition.version())
+                        .max()
+                });
+
+        let is_outdated = match file.decode(&extra_data, false, true) {
+            Ok(Some(RFileDecoded::DB(table_data))) => {
+                latest_version.is_some_and(|latest| *table_data.definition().version() < latest)
+            }
+            Ok(_) => false,
+            Err(_) => latest_version.is_some(),
+        };
+
+        if is_outdated {
+            outdated.push(path);
+        }
+    }
+
+    Ok(outdated)
+}
@@ -7,6 +7,7 @@ use tauri::Manager;
 use tauri::path::BaseDirectory;
 
 use super::pack_db_data_raw::{FileMetadata, get_pack_db_table_data};
+use super::pack_file_type::is_pack_path;
 
 #[derive(Clone, Encode, Decode)]
 struct CacheEntry {
@@ -26,7 +27,7 @@ pub async fn pack_db_data(
         return Err(format!("Pack file does not exist: {:?}", pack_file_path));
     }
 
-    if pack_file_path.extension().map_or(true, |ext| ext != "pack") {
+    if !is_pack_path(&pack_file_path) {
         return Err(format!("File is not a .pack file: {:?}", pack_file_path));
     }
 
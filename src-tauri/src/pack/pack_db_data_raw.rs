@@ -11,6 +11,8 @@ use tauri::Manager;
 use tauri::path::BaseDirectory;
 
 use crate::game::supported_games::SUPPORTED_GAMES;
+use crate::pack::pack_file_type::is_pack_path;
+use crate::pack::pack_version::ensure_pack_version;
 use crate::utils::json_wrapper::JsonWrapper;
 
 #[derive(Clone, Encode, Decode)]
@@ -39,7 +41,7 @@ pub async fn pack_db_data_raw(
         return Err(format!("Pack file does not exist: {:?}", pack_file_path));
     }
 
-    if pack_file_path.extension().map_or(true, |ext| ext != "pack") {
+    if !is_pack_path(&pack_file_path) {
         return Err(format!("File is not a .pack file: {:?}", pack_file_path));
     }
 
@@ -132,6 +134,8 @@ pub fn get_pack_db_table_data(
         .find(|game| game.steam_id == app_id)
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
 
+    ensure_pack_version(game, &pack_file_path)?;
+
     let exe_path = env::current_exe().unwrap();
     let exe_dir = exe_path.parent().unwrap().to_str().unwrap();
 
@@ -1,10 +1,14 @@
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
-use rpfm_lib::files::{Container, FileType, RFileDecoded, pack::Pack};
+use rpfm_lib::files::{Container, FileType, RFile, RFileDecoded, pack::Pack};
 use serde::Serialize;
-use serde_json::{Value, json};
+use serde_json::{Map, Value, json};
 use std::path::PathBuf;
 
+use crate::pack::pack_file_type::is_pack_path;
 use crate::utils::convert_dds::{DDSOutputFormat, convert_dds};
+use crate::utils::summarize_json::summarize_json;
+
+const HEX_PREVIEW_BYTES: usize = 512;
 
 #[derive(Debug, Serialize)]
 pub struct FileContent {
@@ -23,7 +27,7 @@ pub async fn pack_fetch_data(
         return Err(format!("Pack file does not exist: {:?}", pack_file_path));
     }
 
-    if pack_file_path.extension().map_or(true, |ext| ext != "pack") {
+    if !is_pack_path(&pack_file_path) {
         return Err(format!("File is not a .pack file: {:?}", pack_file_path));
     }
 
@@ -97,30 +101,140 @@ pub async fn pack_fetch_data(
                 content: serde_json::to_value(anims)
                     .map_err(|e| format!("Failed to serialize ANIMS data: {}", e))?,
             }),
-            RFileDecoded::ESF(esf) => Some(FileContent {
-                file_type: "esf".to_string(),
-                content: serde_json::to_value(esf)
-                    .map_err(|e| format!("Failed to serialize ESF data: {}", e))?,
-            }),
+            RFileDecoded::ESF(esf) => {
+                let tree = summarize_json(&esf)?;
+                Some(FileContent {
+                    file_type: if path_in_container.to_lowercase().ends_with(".ccd") {
+                        "ccd".to_string()
+                    } else {
+                        "esf".to_string()
+                    },
+                    content: json!({
+                        "metadata": startpos_metadata(&tree),
+                        "tree": tree,
+                    }),
+                })
+            }
             RFileDecoded::UnitVariant(unit) => Some(FileContent {
                 file_type: "unit_variant".to_string(),
                 content: serde_json::to_value(unit)
                     .map_err(|e| format!("Failed to serialize unit variant data: {}", e))?,
             }),
-            _ => Some(FileContent {
-                file_type: "binary".to_string(),
-                content: Value::String("Binary file".to_string()),
+            RFileDecoded::AnimFragmentBattle(fragment) => Some(FileContent {
+                file_type: "anim_fragment".to_string(),
+                content: serde_json::to_value(fragment)
+                    .map_err(|e| format!("Failed to serialize anim fragment data: {}", e))?,
+            }),
+            RFileDecoded::AnimPack(anim_pack) => {
+                let mut files: Vec<&String> = anim_pack.files().keys().collect();
+                files.sort();
+                Some(FileContent {
+                    file_type: "anim_pack".to_string(),
+                    content: json!({
+                        "file_count": files.len(),
+                        "files": files,
+                    }),
+                })
+            }
+            RFileDecoded::RigidModel(rigid_model) => Some(FileContent {
+                file_type: "rigid_model".to_string(),
+                content: summarize_json(&rigid_model)?,
             }),
+            RFileDecoded::MatchedCombat(matched_combat) => Some(FileContent {
+                file_type: "matched_combat".to_string(),
+                content: serde_json::to_value(matched_combat)
+                    .map_err(|e| format!("Failed to serialize matched combat data: {}", e))?,
+            }),
+            _ => Some(binary_content(file)),
         },
         Ok(None) => Some(FileContent {
             file_type: "empty".to_string(),
             content: Value::String("Empty file".to_string()),
         }),
-        Err(e) => Some(FileContent {
-            file_type: "error".to_string(),
-            content: Value::String(format!("Failed to decode file: {:?}", e)),
-        }),
+        Err(e) => {
+            let mut content = binary_content(file);
+            content.content["error"] = Value::String(format!("Failed to decode file: {:?}", e));
+            Some(content)
+        }
     };
 
     Ok(content)
 }
+
+fn binary_content(file: &mut RFile) -> FileContent {
+    match file.encode(&None, false, false, true) {
+        Ok(Some(data)) => FileContent {
+            file_type: "binary".to_string(),
+            content: json!({
+                "size": data.len(),
+                "hex": hex_dump(&data[..data.len().min(HEX_PREVIEW_BYTES)]),
+                "truncated": data.len() > HEX_PREVIEW_BYTES,
+            }),
+        },
+        _ => FileContent {
+            file_type: "binary".to_string(),
+            content: json!({ "size": 0, "hex": "", "truncated": false }),
+        },
+    }
+}
+
+fn hex_dump(data: &[u8]) -> String {
+    data.chunks(16)
+        .enumerate()
+        .map(|(line, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{:02X}", byte)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08X}  {:<47}  {}", line * 16, hex.join(" "), ascii)
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// The header fields of a startpos sit next to the root record, whose name
+// tells a campaign startpos from other ESF files.
+fn startpos_metadata(tree: &Value) -> Value {
+    let mut metadata = Map::new();
+    let Value::Object(fields) = tree else {
+        return Value::Object(metadata);
+    };
+
+    for (key, value) in fields {
+        if !value.is_object() && !value.is_array() {
+            metadata.insert(key.clone(), value.clone());
+        }
+    }
+    if let Some(name) = fields
+        .get("root_node")
+        .and_then(|root| record_name(root, 0))
+    {
+        metadata.insert("root_record".to_string(), name);
+    }
+
+    Value::Object(metadata)
+}
+
+// Nodes are wrapped in their variant name, the record itself is a level or
+// two below.
+fn record_name(node: &Value, depth: usize) -> Option<Value> {
+    let Value::Object(fields) = node else {
+        return None;
+    };
+    if let Some(name) = fields.get("name").filter(|name| name.is_string()) {
+        return Some(name.clone());
+    }
+    if depth >= 2 {
+        return None;
+    }
+    fields
+        .values()
+        .find_map(|value| record_name(value, depth + 1))
+}
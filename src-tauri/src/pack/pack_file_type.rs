@@ -0,0 +1,44 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackFileType {
+    Boot,
+    Release,
+    Patch,
+    Mod,
+    Movie,
+}
+
+// Rome II and Attila publish workshop packs with a .bin extension, the header
+// tells them apart from anything else that happens to use it.
+pub fn is_pack_path(path: &Path) -> bool {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("pack") => true,
+        Some("bin") => pack_file_type(path).is_some(),
+        _ => false,
+    }
+}
+
+pub fn pack_file_type(pack_file_path: &Path) -> Option<PackFileType> {
+    let mut header = [0u8; 8];
+    File::open(pack_file_path)
+        .ok()?
+        .read_exact(&mut header)
+        .ok()?;
+
+    if &header[0..3] != b"PFH" {
+        return None;
+    }
+
+    // The lower nibble holds the type, the rest of the field are header flags.
+    match u32::from_le_bytes([header[4], header[5], header[6], header[7]]) & 0xF {
+        0 => Some(PackFileType::Boot),
+        1 => Some(PackFileType::Release),
+        2 => Some(PackFileType::Patch),
+        3 => Some(PackFileType::Mod),
+        4 => Some(PackFileType::Movie),
+        _ => None,
+    }
+}
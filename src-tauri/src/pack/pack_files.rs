@@ -6,6 +6,8 @@ use std::time::SystemTime;
 use tauri::Manager;
 use tauri::path::BaseDirectory;
 
+use super::pack_file_type::is_pack_path;
+
 #[derive(Encode, Decode)]
 struct FileMetadata {
     size: u64,
@@ -34,7 +36,7 @@ pub async fn pack_files(
         return Err(format!("Pack file does not exist: {:?}", pack_file_path));
     }
 
-    if pack_file_path.extension().map_or(true, |ext| ext != "pack") {
+    if !is_pack_path(&pack_file_path) {
         return Err(format!("File is not a .pack file: {:?}", pack_file_path));
     }
     let pack_file_path_str = pack_file_path.to_string_lossy().to_string();
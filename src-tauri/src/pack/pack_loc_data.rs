@@ -6,6 +6,7 @@ use tauri::Manager;
 use tauri::path::BaseDirectory;
 
 use super::pack_db_data::parse_raw_pack_db;
+use super::pack_file_type::is_pack_path;
 use super::pack_loc_data_raw::FileMetadata;
 use super::pack_loc_data_raw::get_pack_loc_table_data;
 
@@ -27,7 +28,7 @@ pub async fn pack_loc_data(
         return Err(format!("Pack file does not exist: {:?}", pack_file_path));
     }
 
-    if pack_file_path.extension().map_or(true, |ext| ext != "pack") {
+    if !is_pack_path(&pack_file_path) {
         return Err(format!("File is not a .pack file: {:?}", pack_file_path));
     }
 
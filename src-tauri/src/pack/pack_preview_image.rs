@@ -0,0 +1,24 @@
+use rpfm_lib::files::{Container, RFileDecoded, pack::Pack};
+use std::path::Path;
+
+const PREVIEW_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
+pub fn pack_preview_image(pack_file_path: &Path) -> Option<Vec<u8>> {
+    let pack_stem = pack_file_path.file_stem()?.to_string_lossy().to_lowercase();
+
+    let mut packfile =
+        Pack::read_and_merge(&[pack_file_path.to_path_buf()], true, false, false).ok()?;
+
+    let path_in_container = PREVIEW_EXTENSIONS.iter().find_map(|ext| {
+        packfile
+            .paths()
+            .get(&format!("{}.{}", pack_stem, ext))
+            .and_then(|originals| originals.first().cloned())
+    })?;
+
+    let file = packfile.file_mut(&path_in_container, false)?;
+    match file.decode(&None, false, true) {
+        Ok(Some(RFileDecoded::Image(image))) => Some(image.data().to_vec()),
+        _ => None,
+    }
+}
@@ -0,0 +1,66 @@
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use rpfm_lib::files::{Container, RFileDecoded, pack::Pack};
+use serde::Serialize;
+use std::path::PathBuf;
+
+use crate::pack::pack_file_type::is_pack_path;
+use crate::utils::convert_dds::{
+    DDSConvertOptions, DDSMetadata, DDSOutputFormat, convert_dds_with_options, dds_metadata,
+};
+
+#[derive(Debug, Serialize)]
+pub struct TextureData {
+    metadata: DDSMetadata,
+    content: String,
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub async fn pack_texture_data(
+    pack_file_path: String,
+    path_in_container: String,
+    options: Option<DDSConvertOptions>,
+) -> Result<TextureData, String> {
+    let pack_file_path = PathBuf::from(pack_file_path);
+    if !pack_file_path.exists() {
+        return Err(format!("Pack file does not exist: {:?}", pack_file_path));
+    }
+
+    if !is_pack_path(&pack_file_path) {
+        return Err(format!("File is not a .pack file: {:?}", pack_file_path));
+    }
+
+    if !path_in_container.to_lowercase().ends_with(".dds") {
+        return Err(format!("File is not a .dds texture: {}", path_in_container));
+    }
+
+    let mut packfile = Pack::read_and_merge(&[pack_file_path.clone()], true, false, false)
+        .map_err(|e| format!("Failed to read pack file: {:?}", e))?;
+
+    let file = packfile
+        .file_mut(&path_in_container, false)
+        .ok_or_else(|| format!("File not found in pack: {}", path_in_container))?;
+
+    let dds_data = match file.decode(&None, false, true) {
+        Ok(Some(RFileDecoded::Image(image))) => image.data().to_vec(),
+        Ok(_) => return Err(format!("File is not an image: {}", path_in_container)),
+        Err(e) => return Err(format!("Failed to decode file: {:?}", e)),
+    };
+
+    let options = options.unwrap_or_default();
+    let metadata = dds_metadata(&dds_data)?;
+    let image_data = if metadata.is_hdr {
+        convert_dds_with_options(&dds_data, DDSOutputFormat::Png, &options)?
+    } else {
+        convert_dds_with_options(&dds_data, DDSOutputFormat::Jpeg(80), &options)?
+    };
+    let mime_type = if metadata.is_hdr {
+        "image/png"
+    } else {
+        "image/jpeg"
+    };
+
+    Ok(TextureData {
+        metadata,
+        content: format!("data:{};base64,{}", mime_type, BASE64.encode(&image_data)),
+    })
+}
@@ -0,0 +1,42 @@
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use crate::game::supported_games::Game;
+
+// The header starts with the format id in ascii, e.g. PFH5.
+pub fn pack_version(pack_file_path: &Path) -> Result<String, String> {
+    let mut file =
+        File::open(pack_file_path).map_err(|e| format!("Failed to open pack file: {}", e))?;
+    let mut magic = [0u8; 4];
+    file.read_exact(&mut magic)
+        .map_err(|e| format!("Failed to read pack header: {}", e))?;
+
+    let version = String::from_utf8_lossy(&magic).to_string();
+    if !version.starts_with("PFH") {
+        return Err(format!(
+            "{} is not a pack file",
+            pack_file_path.to_string_lossy()
+        ));
+    }
+    Ok(version)
+}
+
+// Older titles refuse packs saved for newer ones instead of skipping them.
+pub fn ensure_pack_version(game: &Game, pack_file_path: &Path) -> Result<(), String> {
+    if game.pack_versions.is_empty() {
+        return Ok(());
+    }
+
+    let version = pack_version(pack_file_path)?;
+    if !game.pack_versions.contains(&version.as_str()) {
+        return Err(format!(
+            "{} is a {} pack, {} loads {}",
+            pack_file_path.to_string_lossy(),
+            version,
+            game.name,
+            game.pack_versions.join(", ")
+        ));
+    }
+    Ok(())
+}
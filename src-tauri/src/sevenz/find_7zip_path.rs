@@ -1,5 +1,7 @@
+#[cfg(windows)]
 use winreg::{RegKey, enums::HKEY_LOCAL_MACHINE};
 
+#[cfg(windows)]
 pub fn find_7zip_path() -> Option<String> {
     let common_paths = [
         r"C:\Program Files\7-Zip\7z.exe",
@@ -26,3 +28,18 @@ pub fn find_7zip_path() -> Option<String> {
 
     None
 }
+
+#[cfg(not(windows))]
+pub fn find_7zip_path() -> Option<String> {
+    let search_paths = std::env::var_os("PATH")?;
+    for dir in std::env::split_paths(&search_paths) {
+        for binary in ["7z", "7zz", "7za"] {
+            let path = dir.join(binary);
+            if path.is_file() {
+                return Some(path.to_string_lossy().to_string());
+            }
+        }
+    }
+
+    None
+}
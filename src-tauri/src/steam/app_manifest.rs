@@ -0,0 +1,91 @@
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+use crate::game::supported_games::SUPPORTED_GAMES;
+
+use super::library_folders::{SteamLibrary, find_workshop_path, steam_libraries};
+use super::vdf::parse_vdf;
+
+const STATE_UPDATE_REQUIRED: u64 = 2;
+const STATE_FULLY_INSTALLED: u64 = 4;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppManifest {
+    pub app_id: u32,
+    pub name: String,
+    pub install_dir: String,
+    pub install_path: String,
+    pub library_path: String,
+    pub build_id: Option<u64>,
+    pub last_updated: Option<u64>,
+    pub state_flags: Option<u64>,
+    pub fully_installed: bool,
+    pub update_required: bool,
+    pub size_on_disk: Option<u64>,
+    pub workshop_path: Option<String>,
+}
+
+pub fn read_app_manifest(library_path: &Path, app_id: u32) -> Option<AppManifest> {
+    let manifest_path = library_path
+        .join("steamapps")
+        .join(format!("appmanifest_{}.acf", app_id));
+    let content = fs::read_to_string(&manifest_path).ok()?;
+    let root = parse_vdf(&content).ok()?;
+    let app_state = root.get("AppState")?;
+
+    let install_dir = app_state.get_str("installdir")?.to_string();
+    let state_flags = app_state.get_u64("StateFlags");
+
+    Some(AppManifest {
+        app_id,
+        name: app_state.get_str("name").unwrap_or_default().to_string(),
+        install_path: library_path
+            .join("steamapps")
+            .join("common")
+            .join(&install_dir)
+            .to_string_lossy()
+            .to_string(),
+        install_dir,
+        library_path: library_path.to_string_lossy().to_string(),
+        build_id: app_state.get_u64("buildid"),
+        last_updated: app_state.get_u64("LastUpdated"),
+        state_flags,
+        fully_installed: state_flags.is_some_and(|flags| flags & STATE_FULLY_INSTALLED != 0),
+        update_required: state_flags.is_some_and(|flags| flags & STATE_UPDATE_REQUIRED != 0),
+        size_on_disk: app_state.get_u64("SizeOnDisk"),
+        workshop_path: None,
+    })
+}
+
+pub fn find_app_manifest_in(libraries: &[SteamLibrary], app_id: u32) -> Option<AppManifest> {
+    // The library that lists the app wins over stale manifests left elsewhere.
+    let owning = libraries
+        .iter()
+        .filter(|library| library.apps.contains(&app_id));
+    let others = libraries
+        .iter()
+        .filter(|library| !library.apps.contains(&app_id));
+
+    let mut manifest = owning
+        .chain(others)
+        .find_map(|library| read_app_manifest(Path::new(&library.path), app_id))?;
+    manifest.workshop_path = find_workshop_path(libraries, app_id)
+        .map(|workshop_path| workshop_path.to_string_lossy().to_string());
+    Some(manifest)
+}
+
+pub fn find_app_manifest(app_id: u32) -> Option<AppManifest> {
+    find_app_manifest_in(&steam_libraries(), app_id)
+}
+
+#[tauri::command(rename_all = "snake_case")]
+pub fn steam_app_manifest(app_id: u32) -> Result<AppManifest, String> {
+    SUPPORTED_GAMES
+        .iter()
+        .find(|game| game.steam_id == app_id)
+        .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
+
+    find_app_manifest(app_id)
+        .ok_or_else(|| format!("Could not find a Steam app manifest for app_id {}", app_id))
+}
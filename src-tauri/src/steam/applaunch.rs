@@ -0,0 +1,45 @@
+use std::path::Path;
+use std::process::Command;
+
+use crate::utils::hidden_command::hidden_command;
+
+#[cfg(not(windows))]
+use super::steam_paths::FLATPAK_STEAM_ID;
+use super::steam_paths::steam_paths;
+
+#[cfg(windows)]
+fn steam_command() -> Result<Command, String> {
+    let steam_path = steam_paths()?
+        .into_iter()
+        .map(|steam_path| Path::new(&steam_path).join("steam.exe"))
+        .find(|steam_exe| steam_exe.is_file())
+        .ok_or_else(|| "Could not find steam.exe".to_string())?;
+
+    Ok(hidden_command(steam_path))
+}
+
+// Only go through Flatpak when that is the sole Steam installation, a native
+// client is always reachable as `steam`.
+#[cfg(not(windows))]
+fn steam_command() -> Result<Command, String> {
+    let steam_install_paths = steam_paths()?;
+    if !steam_install_paths.is_empty()
+        && steam_install_paths.iter().all(|steam_path| {
+            Path::new(steam_path)
+                .to_string_lossy()
+                .contains(FLATPAK_STEAM_ID)
+        })
+    {
+        let mut command = hidden_command("flatpak");
+        command.args(["run", FLATPAK_STEAM_ID]);
+        return Ok(command);
+    }
+
+    Ok(hidden_command("steam"))
+}
+
+pub fn applaunch_command(app_id: u32, args: &[String]) -> Result<Command, String> {
+    let mut command = steam_command()?;
+    command.arg("-applaunch").arg(app_id.to_string()).args(args);
+    Ok(command)
+}
@@ -18,11 +18,12 @@ pub struct DownloadInfo {
 
 #[tauri::command(rename_all = "snake_case")]
 pub async fn check_item_download(
+    handle: tauri::AppHandle,
     app_state: tauri::State<'_, AppState>,
     app_id: u32,
     item_id: u64,
 ) -> Result<DownloadInfo, String> {
-    let steam_client = initialize_client(&app_state, app_id).await?;
+    let steam_client = initialize_client(&handle, &app_state, app_id).await?;
 
     let (tx, mut rx) = mpsc::channel(32);
 
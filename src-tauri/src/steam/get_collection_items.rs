@@ -33,7 +33,7 @@ pub async fn get_collection_items(
     app_id: u32,
     item_id: u64,
 ) -> Result<CollectionDetails, String> {
-    let steam_client = initialize_client(&app_state, app_id).await?;
+    let steam_client = initialize_client(&handle, &app_state, app_id).await?;
 
     let (tx, mut rx) = mpsc::channel(32);
 
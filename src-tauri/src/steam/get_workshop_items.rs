@@ -49,7 +49,7 @@ pub async fn get_workshop_items(
         }
     }
 
-    let steam_client = initialize_client(&app_state, app_id).await?;
+    let steam_client = initialize_client(&handle, &app_state, app_id).await?;
 
     let (tx, mut rx) = tokio::sync::mpsc::channel(32);
     let item_ids_for_query = item_ids.clone();
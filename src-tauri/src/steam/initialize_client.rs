@@ -1,10 +1,30 @@
 use crate::AppState;
+use crate::game::find_installation_path::find_installation;
+use crate::game::supported_games::SUPPORTED_GAMES;
 use steamworks::Client;
 
+// Epic, GOG and manually added installs have no workshop to talk to.
+pub fn ensure_workshop_available(handle: &tauri::AppHandle, app_id: u32) -> Result<(), String> {
+    let Some(game) = SUPPORTED_GAMES.iter().find(|game| game.steam_id == app_id) else {
+        return Ok(());
+    };
+
+    match find_installation(handle, game) {
+        Some(installation) if !installation.workshop_supported => Err(format!(
+            "Steam Workshop is not available for {} installations",
+            installation.storefront.label()
+        )),
+        _ => Ok(()),
+    }
+}
+
 pub async fn initialize_client(
+    handle: &tauri::AppHandle,
     app_state: &AppState,
     app_id: u32,
 ) -> Result<steamworks::Client, String> {
+    ensure_workshop_available(handle, app_id)?;
+
     let steam_state = &app_state.steam_state;
 
     if !steam_state.has_client(app_id) {
@@ -0,0 +1,142 @@
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::steam_paths::steam_paths;
+use super::vdf::{VdfValue, parse_vdf};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SteamLibrary {
+    pub path: String,
+    pub apps: Vec<u32>,
+}
+
+fn normalize(path: &str) -> String {
+    path.replace('\\', "/").trim_end_matches('/').to_lowercase()
+}
+
+// Older libraries list no apps, the manifests on disk are the source of truth then.
+fn installed_apps(library_path: &Path) -> Vec<u32> {
+    let Ok(entries) = fs::read_dir(library_path.join("steamapps")) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("appmanifest_")?
+                .strip_suffix(".acf")?
+                .parse()
+                .ok()
+        })
+        .collect()
+}
+
+fn library_entries(steam_path: &Path) -> Vec<(String, Vec<u32>)> {
+    let Some(root) = fs::read_to_string(steam_path.join("steamapps").join("libraryfolders.vdf"))
+        .ok()
+        .and_then(|content| parse_vdf(&content).ok())
+    else {
+        return vec![];
+    };
+
+    let Some(folders) = root.get("libraryfolders") else {
+        return vec![];
+    };
+
+    folders
+        .entries()
+        .iter()
+        .filter_map(|(key, value)| match value {
+            // Pre-2021 files map an index straight to the library path.
+            VdfValue::String(path) if key.parse::<u32>().is_ok() => Some((path.clone(), vec![])),
+            VdfValue::Object(_) => {
+                let path = value.get_str("path")?.to_string();
+                let apps = value
+                    .get("apps")
+                    .map(|apps| {
+                        apps.entries()
+                            .iter()
+                            .filter_map(|(app_id, _)| app_id.parse().ok())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                Some((path, apps))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn steam_libraries() -> Vec<SteamLibrary> {
+    let mut libraries: Vec<SteamLibrary> = Vec::new();
+
+    for steam_path in steam_paths().unwrap_or_default() {
+        let steam_path = PathBuf::from(&steam_path);
+        let mut entries = library_entries(&steam_path);
+        entries.insert(0, (steam_path.to_string_lossy().to_string(), vec![]));
+
+        for (path, apps) in entries {
+            if !Path::new(&path).join("steamapps").is_dir() {
+                continue;
+            }
+
+            let apps = if apps.is_empty() {
+                installed_apps(Path::new(&path))
+            } else {
+                apps
+            };
+
+            match libraries
+                .iter_mut()
+                .find(|library| normalize(&library.path) == normalize(&path))
+            {
+                Some(library) => {
+                    for app_id in apps {
+                        if !library.apps.contains(&app_id) {
+                            library.apps.push(app_id);
+                        }
+                    }
+                }
+                None => libraries.push(SteamLibrary { path, apps }),
+            }
+        }
+    }
+
+    libraries
+}
+
+pub fn workshop_content_path(library_path: &Path, app_id: u32) -> PathBuf {
+    library_path
+        .join("steamapps")
+        .join("workshop")
+        .join("content")
+        .join(app_id.to_string())
+}
+
+// Workshop content follows the library that holds appworkshop_<appid>.acf,
+// which is normally but not always the one the game is installed in.
+pub fn find_workshop_path(libraries: &[SteamLibrary], app_id: u32) -> Option<PathBuf> {
+    let workshop_manifest = format!("appworkshop_{}.acf", app_id);
+    libraries
+        .iter()
+        .map(|library| Path::new(&library.path))
+        .find(|library_path| {
+            library_path
+                .join("steamapps")
+                .join("workshop")
+                .join(&workshop_manifest)
+                .is_file()
+                && workshop_content_path(library_path, app_id).is_dir()
+        })
+        .or_else(|| {
+            libraries
+                .iter()
+                .map(|library| Path::new(&library.path))
+                .find(|library_path| workshop_content_path(library_path, app_id).is_dir())
+        })
+        .map(|library_path| workshop_content_path(library_path, app_id))
+}
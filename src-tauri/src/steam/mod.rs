@@ -1,9 +1,12 @@
+pub mod app_manifest;
+pub mod applaunch;
 pub mod check_item_download;
 pub mod client;
 pub mod fetch_creator_names;
 pub mod get_collection_items;
 pub mod get_workshop_items;
 pub mod initialize_client;
+pub mod library_folders;
 pub mod localplayer;
 pub mod steam_library_paths;
 pub mod steam_paths;
@@ -11,6 +14,7 @@ pub mod subscribe;
 pub mod subscribed_mods;
 pub mod unsubscribe;
 pub mod update_workshop_item;
+pub mod vdf;
 pub mod workshop;
 pub mod workshop_item;
 pub mod workshop_path_for_app;
@@ -1,6 +1,12 @@
+#[cfg(windows)]
 use winreg::RegKey;
+#[cfg(windows)]
 use winreg::enums::*;
 
+#[cfg(not(windows))]
+pub const FLATPAK_STEAM_ID: &str = "com.valvesoftware.Steam";
+
+#[cfg(windows)]
 #[tauri::command(rename_all = "snake_case")]
 pub fn steam_paths() -> Result<Vec<String>, String> {
     let hkcu = RegKey::predef(HKEY_CURRENT_USER);
@@ -20,3 +26,40 @@ pub fn steam_paths() -> Result<Vec<String>, String> {
 
     Ok(paths)
 }
+
+// ~/.steam/steam and ~/.steam/root are usually symlinks to the real install,
+// canonicalizing keeps one entry per Steam installation.
+#[cfg(not(windows))]
+#[tauri::command(rename_all = "snake_case")]
+pub fn steam_paths() -> Result<Vec<String>, String> {
+    let home = dirs::home_dir().ok_or_else(|| "Home folder could not be resolved.".to_string())?;
+    let flatpak_home = home.join(".var").join("app").join(FLATPAK_STEAM_ID);
+
+    let mut candidates = vec![
+        home.join(".steam").join("steam"),
+        home.join(".steam").join("root"),
+        home.join(".local").join("share").join("Steam"),
+    ];
+    if let Some(data_dir) = dirs::data_dir() {
+        candidates.push(data_dir.join("Steam"));
+    }
+    candidates.push(flatpak_home.join(".local").join("share").join("Steam"));
+    candidates.push(flatpak_home.join("data").join("Steam"));
+
+    let mut paths = Vec::new();
+    for candidate in candidates {
+        if !candidate.join("steamapps").is_dir() {
+            continue;
+        }
+
+        let resolved = std::fs::canonicalize(&candidate)
+            .unwrap_or(candidate)
+            .to_string_lossy()
+            .to_string();
+        if !paths.contains(&resolved) {
+            paths.push(resolved);
+        }
+    }
+
+    Ok(paths)
+}
@@ -8,11 +8,12 @@ use super::initialize_client::initialize_client;
 
 #[tauri::command(rename_all = "snake_case")]
 pub async fn subscribe(
+    handle: tauri::AppHandle,
     app_state: tauri::State<'_, AppState>,
     app_id: u32,
     item_id: u64,
 ) -> Result<bool, String> {
-    let steam_client = initialize_client(&app_state, app_id).await?;
+    let steam_client = initialize_client(&handle, &app_state, app_id).await?;
 
     let (tx, mut rx) = mpsc::channel(32);
 
@@ -1,23 +1,26 @@
 use bincode::{Decode, Encode};
+use futures_util::{StreamExt, stream};
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap, FxHashSet};
+use serde::Serialize;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use steamworks::SteamId;
-use tauri::Manager;
 use tauri::path::BaseDirectory;
+use tauri::{Emitter, Manager};
 use tokio::task::spawn_blocking;
 
 use crate::AppState;
 use crate::game::supported_games::SUPPORTED_GAMES;
 use crate::r#mod::base_mods::{ModItem, ModVersion};
 use crate::r#mod::totalwar;
+use crate::utils::thumbnail_cache::{cached_thumbnail, remote_thumbnail, remote_thumbnail_key};
 use crate::xml::submodule_contents::{SubModuleContents, submodule_contents};
 
 use super::fetch_creator_names::fetch_creator_names;
 use super::get_workshop_items::get_workshop_items;
-use super::initialize_client::initialize_client;
+use super::initialize_client::{ensure_workshop_available, initialize_client};
 use super::workshop_item::workshop::WorkshopItem;
 use super::workshop_path_for_app::workshop_path_for_app;
 
@@ -39,7 +42,12 @@ pub async fn subscribed_mods(
         .find(|game| game.steam_id == app_id)
         .ok_or_else(|| format!("Given app_id {} is not supported", app_id))?;
 
-    let steam_client = initialize_client(&app_state, app_id).await?;
+    // Non-Steam installs simply have no subscriptions.
+    if ensure_workshop_available(&handle, app_id).is_err() {
+        return Ok(Vec::new());
+    }
+
+    let steam_client = initialize_client(&handle, &app_state, app_id).await?;
 
     let app_cache_dir = handle
         .path()
@@ -183,13 +191,85 @@ pub async fn subscribed_mods(
                         creator_name: Some(creator_name),
                         required_items,
                         child_mods,
+                        submodule_info: None,
                     }
                 },
             )
             .collect()
     };
 
-    Ok(final_mods)
+    Ok(with_remote_thumbnails(
+        &handle,
+        app_id,
+        final_mods,
+        &app_cache_dir,
+    ))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModThumbnail {
+    pub app_id: u32,
+    pub identifier: String,
+    pub preview_local: String,
+}
+
+// Cached previews are filled in right away, the rest are downloaded in the
+// background and sent as mod-thumbnail events so the list is not held up.
+fn with_remote_thumbnails(
+    handle: &tauri::AppHandle,
+    app_id: u32,
+    mut mods: Vec<ModItem>,
+    cache_dir: &Path,
+) -> Vec<ModItem> {
+    let mut pending = Vec::new();
+    for mod_item in mods.iter_mut() {
+        let Some(url) = mod_item
+            .preview_url
+            .clone()
+            .filter(|url| mod_item.preview_local.is_empty() && url.starts_with("http"))
+        else {
+            continue;
+        };
+
+        let key = remote_thumbnail_key(&url, mod_item.updated_at);
+        match cached_thumbnail(cache_dir, &key, None) {
+            Some(thumbnail) => mod_item.preview_local = thumbnail,
+            None => pending.push((mod_item.identifier.clone(), key, url)),
+        }
+    }
+
+    if pending.is_empty() {
+        return mods;
+    }
+
+    let handle = handle.clone();
+    let cache_dir = cache_dir.to_path_buf();
+    tauri::async_runtime::spawn(async move {
+        let thumbnails: Vec<(String, Option<String>)> = stream::iter(pending)
+            .map(|(identifier, key, url)| {
+                let cache_dir = cache_dir.clone();
+                async move { (identifier, remote_thumbnail(cache_dir, key, url).await) }
+            })
+            .buffer_unordered(8)
+            .collect()
+            .await;
+
+        for (identifier, thumbnail) in thumbnails {
+            let Some(preview_local) = thumbnail else {
+                continue;
+            };
+            let payload = ModThumbnail {
+                app_id,
+                identifier,
+                preview_local,
+            };
+            if let Err(e) = handle.emit("mod-thumbnail", payload) {
+                eprintln!("Failed to emit mod thumbnail: {}", e);
+            }
+        }
+    });
+
+    mods
 }
 
 fn process_item(
@@ -218,7 +298,10 @@ fn process_item(
     match game_type {
         "totalwar" => {
             let (mod_file, mod_file_path, preview_local) =
-                totalwar::find_mod_file_and_image::find_mod_file_and_image(item_path);
+                totalwar::find_mod_file_and_image::find_mod_file_and_image(
+                    item_path,
+                    app_cache_dir,
+                );
 
             if !mod_file.is_empty() {
                 let required_items = item
@@ -293,32 +376,31 @@ fn process_bannerlord_dependencies(
     ) in mod_items
     {
         let item_id = item.published_file_id.to_string();
+        let mut submodule_info = None;
 
         if let Some(module_id) = mod_contents_map
             .iter()
             .find(|(_, (_, pub_id))| *pub_id == item_id)
             .map(|(id, _)| id.clone())
         {
-            if let Some((submodule_info, _)) = mod_contents_map.get(&module_id) {
-                if let Some(ref depended_modules) = submodule_info.depended_modules {
-                    for dep in depended_modules {
-                        if let Some((_, pub_id)) = mod_contents_map.get(&dep.id) {
-                            required_items.push(pub_id.clone());
-                        } else {
-                            required_items.push(dep.id.clone());
-                        }
+            if let Some((contents, _)) = mod_contents_map.get(&module_id) {
+                for dep_id in contents.load_before_ids() {
+                    if let Some((_, pub_id)) = mod_contents_map.get(dep_id) {
+                        required_items.push(pub_id.clone());
+                    } else {
+                        required_items.push(dep_id.to_string());
                     }
                 }
 
-                if let Some(ref modules_to_load_after) = submodule_info.modules_to_load_after_this {
-                    for child in modules_to_load_after {
-                        if let Some((_, pub_id)) = mod_contents_map.get(&child.id) {
-                            child_mods.push(pub_id.clone());
-                        } else {
-                            child_mods.push(child.id.clone());
-                        }
+                for child_id in contents.load_after_ids() {
+                    if let Some((_, pub_id)) = mod_contents_map.get(child_id) {
+                        child_mods.push(pub_id.clone());
+                    } else {
+                        child_mods.push(child_id.to_string());
                     }
                 }
+
+                submodule_info = Some(contents.clone());
             }
         }
 
@@ -347,6 +429,7 @@ fn process_bannerlord_dependencies(
             creator_name: Some(creator_name),
             required_items,
             child_mods,
+            submodule_info,
         });
     }
 
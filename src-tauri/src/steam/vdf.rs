@@ -0,0 +1,376 @@
+use std::iter::Peekable;
+use std::str::Chars;
+
+#[derive(Debug, Clone)]
+pub enum VdfValue {
+    String(String),
+    Object(Vec<(String, VdfValue)>),
+}
+
+impl VdfValue {
+    // Steam is inconsistent about key casing between versions, e.g. LastUpdated
+    // and lastupdated, so lookups ignore case.
+    pub fn get(&self, key: &str) -> Option<&VdfValue> {
+        match self {
+            VdfValue::Object(entries) => entries
+                .iter()
+                .find(|(entry_key, _)| entry_key.eq_ignore_ascii_case(key))
+                .map(|(_, value)| value),
+            VdfValue::String(_) => None,
+        }
+    }
+
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(|value| value.as_str())
+    }
+
+    pub fn get_u64(&self, key: &str) -> Option<u64> {
+        self.get_str(key)
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            VdfValue::String(value) => Some(value),
+            VdfValue::Object(_) => None,
+        }
+    }
+
+    pub fn entries(&self) -> &[(String, VdfValue)] {
+        match self {
+            VdfValue::Object(entries) => entries,
+            VdfValue::String(_) => &[],
+        }
+    }
+}
+
+enum Token {
+    String(String),
+    Open,
+    Close,
+}
+
+struct Tokenizer<'a> {
+    chars: Peekable<Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn next_token(&mut self) -> Result<Option<Token>, String> {
+        while let Some(&c) = self.chars.peek() {
+            match c {
+                '\n' => {
+                    self.line += 1;
+                    self.chars.next();
+                }
+                c if c.is_whitespace() => {
+                    self.chars.next();
+                }
+                '/' => {
+                    self.chars.next();
+                    if self.chars.peek() != Some(&'/') {
+                        return Err(format!("Unexpected '/' on line {}", self.line));
+                    }
+                    while let Some(&c) = self.chars.peek() {
+                        if c == '\n' {
+                            break;
+                        }
+                        self.chars.next();
+                    }
+                }
+                // Platform conditionals such as [$WIN32] are ignored.
+                '[' => {
+                    while let Some(c) = self.chars.next() {
+                        if c == ']' {
+                            break;
+                        }
+                    }
+                }
+                '{' => {
+                    self.chars.next();
+                    return Ok(Some(Token::Open));
+                }
+                '}' => {
+                    self.chars.next();
+                    return Ok(Some(Token::Close));
+                }
+                '"' => {
+                    self.chars.next();
+                    return self.quoted().map(|value| Some(Token::String(value)));
+                }
+                _ => return Ok(Some(Token::String(self.unquoted()))),
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn quoted(&mut self) -> Result<String, String> {
+        let mut value = String::new();
+        while let Some(c) = self.chars.next() {
+            match c {
+                '"' => return Ok(value),
+                '\\' => match self.chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(escaped) => value.push(escaped),
+                    None => break,
+                },
+                '\n' => {
+                    self.line += 1;
+                    value.push(c);
+                }
+                _ => value.push(c),
+            }
+        }
+
+        Err(format!("Unterminated string on line {}", self.line))
+    }
+
+    fn unquoted(&mut self) -> String {
+        let mut value = String::new();
+        while let Some(&c) = self.chars.peek() {
+            if c.is_whitespace() || c == '{' || c == '}' || c == '"' {
+                break;
+            }
+            value.push(c);
+            self.chars.next();
+        }
+        value
+    }
+}
+
+fn parse_object(tokenizer: &mut Tokenizer, nested: bool) -> Result<VdfValue, String> {
+    let mut entries = Vec::new();
+
+    loop {
+        let key = match tokenizer.next_token()? {
+            Some(Token::String(key)) => key,
+            Some(Token::Close) if nested => return Ok(VdfValue::Object(entries)),
+            None if !nested => return Ok(VdfValue::Object(entries)),
+            Some(Token::Close) => {
+                return Err(format!("Unexpected '}}' on line {}", tokenizer.line));
+            }
+            Some(Token::Open) => {
+                return Err(format!("Expected a key on line {}", tokenizer.line));
+            }
+            None => return Err("Unexpected end of file".to_string()),
+        };
+
+        let value = match tokenizer.next_token()? {
+            Some(Token::String(value)) => VdfValue::String(value),
+            Some(Token::Open) => parse_object(tokenizer, true)?,
+            _ => {
+                return Err(format!(
+                    "Missing value for '{}' on line {}",
+                    key, tokenizer.line
+                ));
+            }
+        };
+
+        entries.push((key, value));
+    }
+}
+
+pub fn parse_vdf(content: &str) -> Result<VdfValue, String> {
+    let mut tokenizer = Tokenizer {
+        chars: content.trim_start_matches('\u{feff}').chars().peekable(),
+        line: 1,
+    };
+    parse_object(&mut tokenizer, false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIBRARY_FOLDERS: &str = r#""libraryfolders"
+{
+	"0"
+	{
+		"path"		"C:\\Program Files (x86)\\Steam"
+		"label"		""
+		"contentid"		"4313412306582196871"
+		"totalsize"		"0"
+		"update_clean_bytes_tally"		"51385312481"
+		"time_last_update_verified"		"1719316153"
+		"apps"
+		{
+			"228980"		"386529366"
+			"1142710"		"38419219021"
+		}
+	}
+	"1"
+	{
+		"path"		"D:\\SteamLibrary"
+		"label"		""
+		"contentid"		"6052128962307585912"
+		"totalsize"		"2000381014016"
+		"update_clean_bytes_tally"		"0"
+		"time_last_update_verified"		"0"
+		"apps"
+		{
+			"261550"		"62345827981"
+		}
+	}
+}
+"#;
+
+    const LIBRARY_FOLDERS_LEGACY: &str = r#""LibraryFolders"
+{
+	"TimeNextStatsReport"		"1612345678"
+	"ContentStatsID"		"-5498613474512345678"
+	"1"		"D:\\SteamLibrary"
+	"2"		"E:\\Games\\Steam"
+}
+"#;
+
+    const APP_MANIFEST: &str = "\u{feff}\"AppState\"
+{
+	\"appid\"		\"1142710\"
+	\"Universe\"		\"1\"
+	\"name\"		\"Total War: WARHAMMER III\"
+	\"StateFlags\"		\"4\"
+	\"installdir\"		\"Total War WARHAMMER III\"
+	\"LastUpdated\"		\"1718893614\"
+	\"SizeOnDisk\"		\"38419219021\"
+	\"buildid\"		\"14808474\"
+	\"InstalledDepots\"
+	{
+		\"1142711\"
+		{
+			\"manifest\"		\"5106487236432141372\"
+			\"size\"		\"38419219021\"
+		}
+	}
+	\"UserConfig\"
+	{
+		\"language\"		\"english\"
+	}
+}
+";
+
+    const APP_MANIFEST_LEGACY: &str = r#""AppState"
+{
+	"appid"		"261550"
+	"Universe"		"1"
+	"name"		"Mount & Blade II: Bannerlord"
+	"StateFlags"		"6"
+	"installdir"		"Mount & Blade II Bannerlord"
+	"lastupdated"		"1598012345"
+	"SizeOnDisk"		"62345827981"
+	"buildid"		"5690112"
+}
+"#;
+
+    #[test]
+    fn parses_library_folders() {
+        let root = parse_vdf(LIBRARY_FOLDERS).unwrap();
+        let folders = root.get("libraryfolders").unwrap();
+        assert_eq!(folders.entries().len(), 2);
+
+        let main = folders.get("0").unwrap();
+        assert_eq!(main.get_str("path"), Some(r"C:\Program Files (x86)\Steam"));
+        assert_eq!(main.get_str("label"), Some(""));
+        let apps: Vec<&str> = main
+            .get("apps")
+            .unwrap()
+            .entries()
+            .iter()
+            .map(|(app_id, _)| app_id.as_str())
+            .collect();
+        assert_eq!(apps, ["228980", "1142710"]);
+
+        assert_eq!(
+            folders.get("1").unwrap().get_str("path"),
+            Some(r"D:\SteamLibrary")
+        );
+    }
+
+    #[test]
+    fn parses_legacy_library_folders() {
+        let root = parse_vdf(LIBRARY_FOLDERS_LEGACY).unwrap();
+        let folders = root.get("libraryfolders").unwrap();
+        assert_eq!(folders.get_str("1"), Some(r"D:\SteamLibrary"));
+        assert_eq!(folders.get_str("2"), Some(r"E:\Games\Steam"));
+        assert_eq!(
+            folders.get_str("ContentStatsID"),
+            Some("-5498613474512345678")
+        );
+    }
+
+    #[test]
+    fn parses_app_manifest() {
+        let root = parse_vdf(APP_MANIFEST).unwrap();
+        let app_state = root.get("AppState").unwrap();
+        assert_eq!(app_state.get_str("name"), Some("Total War: WARHAMMER III"));
+        assert_eq!(app_state.get_u64("buildid"), Some(14808474));
+        assert_eq!(app_state.get_u64("LastUpdated"), Some(1718893614));
+        assert_eq!(
+            app_state
+                .get("InstalledDepots")
+                .and_then(|depots| depots.get("1142711"))
+                .and_then(|depot| depot.get_str("manifest")),
+            Some("5106487236432141372")
+        );
+    }
+
+    #[test]
+    fn looks_up_legacy_manifest_keys_without_case() {
+        let root = parse_vdf(APP_MANIFEST_LEGACY).unwrap();
+        let app_state = root.get("appstate").unwrap();
+        assert_eq!(app_state.get_u64("LastUpdated"), Some(1598012345));
+        assert_eq!(app_state.get_u64("StateFlags"), Some(6));
+    }
+
+    #[test]
+    fn skips_conditionals_and_comments() {
+        let content = r#"// Generated by Steam
+"Settings"
+{
+	"Launcher"		"launcher.exe"	[$WIN32]
+	"Launcher"		"launcher.sh"	[$LINUX||$OSX]
+	"Depth"		"2" // trailing comment
+	[$WIN64]
+	"Arch"		"x64"
+}
+"#;
+        let root = parse_vdf(content).unwrap();
+        let settings = root.get("Settings").unwrap();
+        assert_eq!(settings.entries().len(), 4);
+        assert_eq!(settings.get_str("Launcher"), Some("launcher.exe"));
+        assert_eq!(settings.get_u64("Depth"), Some(2));
+        assert_eq!(settings.get_str("Arch"), Some("x64"));
+    }
+
+    #[test]
+    fn parses_unquoted_tokens() {
+        let root =
+            parse_vdf("AppState\n{\n\tappid 1142710\n\tname \"Total War\"\n\tapps{228980 1}\n}")
+                .unwrap();
+        let app_state = root.get("AppState").unwrap();
+        assert_eq!(app_state.get_u64("appid"), Some(1142710));
+        assert_eq!(app_state.get_str("name"), Some("Total War"));
+        assert_eq!(
+            app_state
+                .get("apps")
+                .and_then(|apps| apps.get_str("228980")),
+            Some("1")
+        );
+    }
+
+    #[test]
+    fn unescapes_quoted_strings() {
+        let root = parse_vdf(r#""key" "say \"hi\"\tnow\\later""#).unwrap();
+        assert_eq!(root.get_str("key"), Some("say \"hi\"\tnow\\later"));
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        assert!(parse_vdf(r#""AppState" { "name" "unterminated }"#).is_err());
+        assert!(parse_vdf(r#""AppState" { "name" "x""#).is_err());
+        assert!(parse_vdf(r#""AppState" { "name" }"#).is_err());
+        assert!(parse_vdf(r#""AppState" { } }"#).is_err());
+        assert!(parse_vdf(r#""AppState" / "x""#).is_err());
+    }
+}
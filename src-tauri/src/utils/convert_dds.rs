@@ -1,22 +1,77 @@
-use ddsfile::{Dds, DxgiFormat, FourCC, PixelFormatFlags};
+use ddsfile::{Caps2, DataFormat, Dds, DxgiFormat, FourCC, MiscFlag, PixelFormatFlags};
+use image::imageops::FilterType;
 use image::{DynamicImage, ImageBuffer, Rgba};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::io::Cursor;
 
+use super::decode_bc6h::decode_bc6h;
+
 #[allow(dead_code)]
 pub enum DDSOutputFormat {
     Png,
     Jpeg(u8),
 }
 
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DDSConvertOptions {
+    pub mip_level: u32,
+    pub array_slice: u32,
+    pub face: u32,
+    pub max_size: Option<u32>,
+    pub exposure: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DDSMipLevel {
+    pub level: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DDSMetadata {
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+    pub mip_count: u32,
+    pub array_size: u32,
+    pub face_count: u32,
+    pub is_cube_map: bool,
+    pub is_hdr: bool,
+    pub mip_levels: Vec<DDSMipLevel>,
+}
+
 pub fn convert_dds(dds_data: &[u8], format: DDSOutputFormat) -> Result<Vec<u8>, String> {
+    convert_dds_with_options(dds_data, format, &DDSConvertOptions::default())
+}
+
+pub fn convert_dds_with_options(
+    dds_data: &[u8],
+    format: DDSOutputFormat,
+    options: &DDSConvertOptions,
+) -> Result<Vec<u8>, String> {
     let dds = Dds::read(dds_data).map_err(|e| format!("Failed to parse DDS: {:?}", e))?;
 
-    let width = dds.get_width() as usize;
-    let height = dds.get_height() as usize;
+    let (surface, width, height) = select_surface(&dds, options)?;
 
     let mut rgba_bytes = vec![0u8; width * height * 4];
-    decode_dds_to_rgba(&dds, width, height, &mut rgba_bytes)?;
+    decode_dds_to_rgba(
+        &dds,
+        surface,
+        width,
+        height,
+        options.exposure.unwrap_or(1.0),
+        &mut rgba_bytes,
+    )?;
+
+    let (rgba_bytes, width, height) = match options.max_size {
+        Some(max_size) if width > max_size as usize || height > max_size as usize => {
+            scale_rgba(rgba_bytes, width, height, max_size)?
+        }
+        _ => (rgba_bytes, width, height),
+    };
 
     match format {
         DDSOutputFormat::Png => encode_to_png(&rgba_bytes, width, height),
@@ -24,10 +79,207 @@ pub fn convert_dds(dds_data: &[u8], format: DDSOutputFormat) -> Result<Vec<u8>,
     }
 }
 
+pub fn dds_metadata(dds_data: &[u8]) -> Result<DDSMetadata, String> {
+    let dds = Dds::read(dds_data).map_err(|e| format!("Failed to parse DDS: {:?}", e))?;
+
+    let format = if let Some(dxgi_format) = dds.get_dxgi_format() {
+        format!("{:?}", dxgi_format)
+    } else if let Some(d3d_format) = dds.get_d3d_format() {
+        format!("{:?}", d3d_format)
+    } else if let Some(ref fourcc) = dds.header.spf.fourcc {
+        format!("FourCC 0x{:08X}", fourcc.0)
+    } else {
+        "Unknown".to_string()
+    };
+
+    let mip_count = dds.get_num_mipmap_levels().max(1);
+    let mip_levels = (0..mip_count)
+        .map(|level| DDSMipLevel {
+            level,
+            width: (dds.get_width() >> level).max(1),
+            height: (dds.get_height() >> level).max(1),
+        })
+        .collect();
+
+    Ok(DDSMetadata {
+        format,
+        width: dds.get_width(),
+        height: dds.get_height(),
+        depth: dds.get_depth(),
+        mip_count,
+        array_size: array_size(&dds),
+        face_count: face_count(&dds),
+        is_cube_map: is_cube_map(&dds),
+        is_hdr: is_hdr(&dds),
+        mip_levels,
+    })
+}
+
+fn is_cube_map(dds: &Dds) -> bool {
+    dds.header.caps2.contains(Caps2::CUBEMAP)
+        || dds
+            .header10
+            .as_ref()
+            .map_or(false, |h10| h10.misc_flag.contains(MiscFlag::TEXTURECUBE))
+}
+
+fn face_count(dds: &Dds) -> u32 {
+    if is_cube_map(dds) { 6 } else { 1 }
+}
+
+fn array_size(dds: &Dds) -> u32 {
+    dds.header10.as_ref().map_or(1, |h10| h10.array_size.max(1))
+}
+
+fn is_hdr(dds: &Dds) -> bool {
+    match dds.header.spf.fourcc.as_ref().map(|fourcc| fourcc.0) {
+        Some(FourCC::DX10) => matches!(
+            dds.get_dxgi_format(),
+            Some(
+                DxgiFormat::BC6H_UF16
+                    | DxgiFormat::BC6H_SF16
+                    | DxgiFormat::BC6H_Typeless
+                    | DxgiFormat::R16G16B16A16_Float
+                    | DxgiFormat::R32G32B32A32_Float
+            )
+        ),
+        Some(FourCC::A16B16G16R16F) | Some(FourCC::A32B32G32R32F) => true,
+        _ => false,
+    }
+}
+
+fn block_layout(dds: &Dds) -> Result<(usize, usize), String> {
+    if let Some(ref fourcc) = dds.header.spf.fourcc {
+        match fourcc.0 {
+            FourCC::DX10 => {
+                let dxgi_format = dds
+                    .get_dxgi_format()
+                    .ok_or_else(|| "DX10 format specified but no DXGI format found".to_string())?;
+                if let Some(block_size) = dxgi_format.get_block_size() {
+                    return Ok((4, block_size as usize));
+                }
+                if let Some(bpp) = dxgi_format.get_bits_per_pixel() {
+                    return Ok((1, (bpp as usize).div_ceil(8)));
+                }
+                Err(format!("Unsupported DXGI format: {:?}", dxgi_format))
+            }
+            FourCC::DXT1 | FourCC::ATI1 | FourCC::BC4_UNORM | FourCC::BC4_SNORM => Ok((4, 8)),
+            FourCC::DXT2 | FourCC::DXT3 | FourCC::DXT4 | FourCC::DXT5 => Ok((4, 16)),
+            FourCC::ATI2 | FourCC::BC5_SNORM => Ok((4, 16)),
+            FourCC::A16B16G16R16F => Ok((1, 8)),
+            FourCC::A32B32G32R32F => Ok((1, 16)),
+            fourcc_value => Err(format!("Unsupported FourCC format: 0x{:08X}", fourcc_value)),
+        }
+    } else {
+        match dds.header.spf.rgb_bit_count {
+            Some(bpp) => Ok((1, (bpp as usize).div_ceil(8))),
+            None => Err("Unknown or unsupported DDS format".to_string()),
+        }
+    }
+}
+
+fn mip_size(
+    width: usize,
+    height: usize,
+    depth: usize,
+    block_dim: usize,
+    block_bytes: usize,
+) -> usize {
+    let blocks_x = width.div_ceil(block_dim).max(1);
+    let blocks_y = height.div_ceil(block_dim).max(1);
+    blocks_x * blocks_y * depth * block_bytes
+}
+
+fn select_surface<'a>(
+    dds: &'a Dds,
+    options: &DDSConvertOptions,
+) -> Result<(&'a [u8], usize, usize), String> {
+    let mip_count = dds.get_num_mipmap_levels().max(1);
+    let array_size = array_size(dds);
+    let face_count = face_count(dds);
+
+    if options.mip_level >= mip_count {
+        return Err(format!(
+            "Mip level {} is out of range, texture has {} mip levels",
+            options.mip_level, mip_count
+        ));
+    }
+    if options.array_slice >= array_size {
+        return Err(format!(
+            "Array slice {} is out of range, texture has {} slices",
+            options.array_slice, array_size
+        ));
+    }
+    if options.face >= face_count {
+        return Err(format!(
+            "Face {} is out of range, texture has {} faces",
+            options.face, face_count
+        ));
+    }
+
+    let (block_dim, block_bytes) = block_layout(dds)?;
+    let base_width = dds.get_width() as usize;
+    let base_height = dds.get_height() as usize;
+    let base_depth = dds.get_depth() as usize;
+
+    let mut surface_stride = 0;
+    let mut mip_offset = 0;
+    let mut selected_size = 0;
+    for level in 0..mip_count as usize {
+        let size = mip_size(
+            (base_width >> level).max(1),
+            (base_height >> level).max(1),
+            (base_depth >> level).max(1),
+            block_dim,
+            block_bytes,
+        );
+        if level < options.mip_level as usize {
+            mip_offset += size;
+        } else if level == options.mip_level as usize {
+            selected_size = size;
+        }
+        surface_stride += size;
+    }
+
+    let surface_index = (options.array_slice * face_count + options.face) as usize;
+    let offset = surface_index * surface_stride + mip_offset;
+
+    let width = (base_width >> options.mip_level).max(1);
+    let height = (base_height >> options.mip_level).max(1);
+    let slice_size = mip_size(width, height, 1, block_dim, block_bytes);
+
+    let surface = dds
+        .data
+        .get(offset..offset + selected_size.min(slice_size))
+        .ok_or_else(|| "Texture data is smaller than its header describes".to_string())?;
+
+    Ok((surface, width, height))
+}
+
+fn scale_rgba(
+    rgba_bytes: Vec<u8>,
+    width: usize,
+    height: usize,
+    max_size: u32,
+) -> Result<(Vec<u8>, usize, usize), String> {
+    let rgba_image =
+        ImageBuffer::<Rgba<u8>, Vec<u8>>::from_raw(width as u32, height as u32, rgba_bytes)
+            .ok_or_else(|| "Failed to create image from decoded data".to_string())?;
+
+    let resized = DynamicImage::ImageRgba8(rgba_image)
+        .resize(max_size, max_size, FilterType::Triangle)
+        .to_rgba8();
+    let (width, height) = (resized.width() as usize, resized.height() as usize);
+
+    Ok((resized.into_raw(), width, height))
+}
+
 fn decode_dds_to_rgba(
     dds: &Dds,
+    data: &[u8],
     width: usize,
     height: usize,
+    exposure: f32,
     rgba_bytes: &mut [u8],
 ) -> Result<(), String> {
     let mut rgba_data: Vec<u32> = vec![0; width * height];
@@ -39,58 +291,37 @@ fn decode_dds_to_rgba(
                     DxgiFormat::BC1_UNorm
                     | DxgiFormat::BC1_UNorm_sRGB
                     | DxgiFormat::BC1_Typeless => {
-                        let dxt_data = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BC1 data: {:?}", e))?;
-                        texture2ddecoder::decode_bc1(dxt_data, width, height, &mut rgba_data)
+                        texture2ddecoder::decode_bc1(data, width, height, &mut rgba_data)
                             .map_err(|e| format!("Failed to decode BC1: {}", e))?;
                     }
                     DxgiFormat::BC2_UNorm
                     | DxgiFormat::BC2_UNorm_sRGB
                     | DxgiFormat::BC2_Typeless => {
-                        let dxt_data = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BC2 data: {:?}", e))?;
-                        texture2ddecoder::decode_bc2(dxt_data, width, height, &mut rgba_data)
+                        texture2ddecoder::decode_bc2(data, width, height, &mut rgba_data)
                             .map_err(|e| format!("Failed to decode BC2: {}", e))?;
                     }
                     DxgiFormat::BC3_UNorm
                     | DxgiFormat::BC3_UNorm_sRGB
                     | DxgiFormat::BC3_Typeless => {
-                        let dxt_data = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BC3 data: {:?}", e))?;
-                        texture2ddecoder::decode_bc3(dxt_data, width, height, &mut rgba_data)
+                        texture2ddecoder::decode_bc3(data, width, height, &mut rgba_data)
                             .map_err(|e| format!("Failed to decode BC3: {}", e))?;
                     }
                     DxgiFormat::BC4_UNorm | DxgiFormat::BC4_SNorm | DxgiFormat::BC4_Typeless => {
-                        let bc_data = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BC4 data: {:?}", e))?;
-                        texture2ddecoder::decode_bc4(bc_data, width, height, &mut rgba_data)
+                        texture2ddecoder::decode_bc4(data, width, height, &mut rgba_data)
                             .map_err(|e| format!("Failed to decode BC4: {}", e))?;
                     }
                     DxgiFormat::BC5_UNorm | DxgiFormat::BC5_SNorm | DxgiFormat::BC5_Typeless => {
-                        let bc_data = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BC5 data: {:?}", e))?;
-                        texture2ddecoder::decode_bc5(bc_data, width, height, &mut rgba_data)
+                        texture2ddecoder::decode_bc5(data, width, height, &mut rgba_data)
                             .map_err(|e| format!("Failed to decode BC5: {}", e))?;
                     }
                     DxgiFormat::BC7_UNorm
                     | DxgiFormat::BC7_UNorm_sRGB
                     | DxgiFormat::BC7_Typeless => {
-                        let bc_data = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BC7 data: {:?}", e))?;
-                        texture2ddecoder::decode_bc7(bc_data, width, height, &mut rgba_data)
+                        texture2ddecoder::decode_bc7(data, width, height, &mut rgba_data)
                             .map_err(|e| format!("Failed to decode BC7: {}", e))?;
                     }
                     DxgiFormat::R8G8B8A8_UNorm | DxgiFormat::R8G8B8A8_UNorm_sRGB => {
-                        let rgba_src = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get RGBA data: {:?}", e))?;
-                        if rgba_src.len() != width * height * 4 {
+                        if data.len() != width * height * 4 {
                             return Err(format!("Unexpected data size for R8G8B8A8 format"));
                         }
 
@@ -103,10 +334,10 @@ fn decode_dds_to_rgba(
                                     let idx = start_idx + i;
                                     if idx < width * height {
                                         let offset = idx * 4;
-                                        let r = rgba_src[offset] as u32;
-                                        let g = rgba_src[offset + 1] as u32;
-                                        let b = rgba_src[offset + 2] as u32;
-                                        let a = rgba_src[offset + 3] as u32;
+                                        let r = data[offset] as u32;
+                                        let g = data[offset + 1] as u32;
+                                        let b = data[offset + 2] as u32;
+                                        let a = data[offset + 3] as u32;
 
                                         *pixel = b | (g << 8) | (r << 16) | (a << 24);
                                     }
@@ -114,10 +345,7 @@ fn decode_dds_to_rgba(
                             });
                     }
                     DxgiFormat::B8G8R8A8_UNorm | DxgiFormat::B8G8R8A8_UNorm_sRGB => {
-                        let bgra_src = dds
-                            .get_data(0)
-                            .map_err(|e| format!("Failed to get BGRA data: {:?}", e))?;
-                        if bgra_src.len() != width * height * 4 {
+                        if data.len() != width * height * 4 {
                             return Err(format!("Unexpected data size for B8G8R8A8 format"));
                         }
 
@@ -131,15 +359,27 @@ fn decode_dds_to_rgba(
                                     if idx < width * height {
                                         let offset = idx * 4;
                                         *pixel = u32::from_le_bytes([
-                                            bgra_src[offset],
-                                            bgra_src[offset + 1],
-                                            bgra_src[offset + 2],
-                                            bgra_src[offset + 3],
+                                            data[offset],
+                                            data[offset + 1],
+                                            data[offset + 2],
+                                            data[offset + 3],
                                         ]);
                                     }
                                 }
                             });
                     }
+                    DxgiFormat::BC6H_UF16 | DxgiFormat::BC6H_Typeless => {
+                        decode_bc6h(data, width, height, false, exposure, &mut rgba_data)?;
+                    }
+                    DxgiFormat::BC6H_SF16 => {
+                        decode_bc6h(data, width, height, true, exposure, &mut rgba_data)?;
+                    }
+                    DxgiFormat::R16G16B16A16_Float => {
+                        decode_float_rgba(data, width, height, 2, exposure, &mut rgba_data)?;
+                    }
+                    DxgiFormat::R32G32B32A32_Float => {
+                        decode_float_rgba(data, width, height, 4, exposure, &mut rgba_data)?;
+                    }
                     unsupported_format => {
                         return Err(format!(
                             "Unsupported DXGI format in DX10 header: {:?}",
@@ -153,50 +393,37 @@ fn decode_dds_to_rgba(
         } else {
             match fourcc.0 {
                 FourCC::DXT1 => {
-                    let dxt_data = dds
-                        .get_data(0)
-                        .map_err(|e| format!("Failed to get DXT1 data: {:?}", e))?;
-                    texture2ddecoder::decode_bc1(dxt_data, width, height, &mut rgba_data)
+                    texture2ddecoder::decode_bc1(data, width, height, &mut rgba_data)
                         .map_err(|e| format!("Failed to decode DXT1: {}", e))?;
                 }
                 FourCC::DXT3 => {
-                    let dxt_data = dds
-                        .get_data(0)
-                        .map_err(|e| format!("Failed to get DXT3 data: {:?}", e))?;
-                    texture2ddecoder::decode_bc2(dxt_data, width, height, &mut rgba_data)
+                    texture2ddecoder::decode_bc2(data, width, height, &mut rgba_data)
                         .map_err(|e| format!("Failed to decode DXT3: {}", e))?;
                 }
                 FourCC::DXT5 => {
-                    let dxt_data = dds
-                        .get_data(0)
-                        .map_err(|e| format!("Failed to get DXT5 data: {:?}", e))?;
-                    texture2ddecoder::decode_bc3(dxt_data, width, height, &mut rgba_data)
+                    texture2ddecoder::decode_bc3(data, width, height, &mut rgba_data)
                         .map_err(|e| format!("Failed to decode DXT5: {}", e))?;
                 }
                 FourCC::BC4_UNORM | FourCC::ATI1 => {
-                    let bc_data = dds
-                        .get_data(0)
-                        .map_err(|e| format!("Failed to get BC4/ATI1 data: {:?}", e))?;
-                    texture2ddecoder::decode_bc4(bc_data, width, height, &mut rgba_data)
+                    texture2ddecoder::decode_bc4(data, width, height, &mut rgba_data)
                         .map_err(|e| format!("Failed to decode BC4/ATI1: {}", e))?;
                 }
                 FourCC::BC5_UNORM => {
-                    let bc_data = dds
-                        .get_data(0)
-                        .map_err(|e| format!("Failed to get BC5/ATI2 data: {:?}", e))?;
-                    texture2ddecoder::decode_bc5(bc_data, width, height, &mut rgba_data)
+                    texture2ddecoder::decode_bc5(data, width, height, &mut rgba_data)
                         .map_err(|e| format!("Failed to decode BC5/ATI2: {}", e))?;
                 }
+                FourCC::A16B16G16R16F => {
+                    decode_float_rgba(data, width, height, 2, exposure, &mut rgba_data)?;
+                }
+                FourCC::A32B32G32R32F => {
+                    decode_float_rgba(data, width, height, 4, exposure, &mut rgba_data)?;
+                }
                 fourcc_value => {
                     return Err(format!("Unsupported FourCC format: 0x{:08X}", fourcc_value));
                 }
             }
         }
     } else if dds.header.spf.flags.contains(PixelFormatFlags::RGB) {
-        let raw_data = dds
-            .get_data(0)
-            .map_err(|e| format!("Failed to get uncompressed RGB data: {:?}", e))?;
-
         let bpp = dds.header.spf.rgb_bit_count;
         let r_mask = dds.header.spf.r_bit_mask;
         let g_mask = dds.header.spf.g_bit_mask;
@@ -217,7 +444,7 @@ fn decode_dds_to_rgba(
 
         match bpp {
             Some(32) => {
-                if raw_data.len() != width * height * 4 {
+                if data.len() != width * height * 4 {
                     return Err(format!("Unexpected data size for 32-bit RGB format"));
                 }
 
@@ -231,10 +458,10 @@ fn decode_dds_to_rgba(
                             if idx < width * height {
                                 let offset = idx * 4;
                                 let pixel_value = u32::from_le_bytes([
-                                    raw_data[offset],
-                                    raw_data[offset + 1],
-                                    raw_data[offset + 2],
-                                    raw_data[offset + 3],
+                                    data[offset],
+                                    data[offset + 1],
+                                    data[offset + 2],
+                                    data[offset + 3],
                                 ]);
 
                                 let r = if let Some(r_mask) = r_mask {
@@ -270,7 +497,7 @@ fn decode_dds_to_rgba(
                     });
             }
             Some(24) => {
-                if raw_data.len() != width * height * 3 {
+                if data.len() != width * height * 3 {
                     return Err(format!("Unexpected data size for 24-bit RGB format"));
                 }
 
@@ -284,9 +511,9 @@ fn decode_dds_to_rgba(
                             if idx < width * height {
                                 let offset = idx * 3;
                                 let pixel_value = u32::from_le_bytes([
-                                    raw_data[offset],
-                                    raw_data[offset + 1],
-                                    raw_data[offset + 2],
+                                    data[offset],
+                                    data[offset + 1],
+                                    data[offset + 2],
                                     0,
                                 ]);
 
@@ -317,7 +544,7 @@ fn decode_dds_to_rgba(
                     });
             }
             Some(16) => {
-                if raw_data.len() != width * height * 2 {
+                if data.len() != width * height * 2 {
                     return Err(format!("Unexpected data size for 16-bit RGB format"));
                 }
 
@@ -340,8 +567,7 @@ fn decode_dds_to_rgba(
                             if idx < width * height {
                                 let offset = idx * 2;
                                 let pixel_value =
-                                    u16::from_le_bytes([raw_data[offset], raw_data[offset + 1]])
-                                        as u32;
+                                    u16::from_le_bytes([data[offset], data[offset + 1]]) as u32;
 
                                 let r = if let Some(r_mask) = r_mask {
                                     ((pixel_value & r_mask) >> r_shift) as u8
@@ -467,6 +693,85 @@ fn encode_to_jpeg(
     Ok(jpeg_data)
 }
 
+fn decode_float_rgba(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    channel_bytes: usize,
+    exposure: f32,
+    rgba_data: &mut [u32],
+) -> Result<(), String> {
+    let pixel_bytes = channel_bytes * 4;
+    if data.len() < width * height * pixel_bytes {
+        return Err("Unexpected data size for floating point RGBA format".to_string());
+    }
+
+    rgba_data
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(idx, pixel)| {
+            let offset = idx * pixel_bytes;
+            let channel = |i: usize| -> f32 {
+                let start = offset + i * channel_bytes;
+                if channel_bytes == 2 {
+                    half_to_f32(u16::from_le_bytes([data[start], data[start + 1]]))
+                } else {
+                    f32::from_le_bytes([
+                        data[start],
+                        data[start + 1],
+                        data[start + 2],
+                        data[start + 3],
+                    ])
+                }
+            };
+
+            let r = tonemap(channel(0), exposure) as u32;
+            let g = tonemap(channel(1), exposure) as u32;
+            let b = tonemap(channel(2), exposure) as u32;
+            let a = (channel(3).clamp(0.0, 1.0) * 255.0).round() as u32;
+
+            *pixel = b | (g << 8) | (r << 16) | (a << 24);
+        });
+
+    Ok(())
+}
+
+pub fn half_to_f32(half: u16) -> f32 {
+    let sign = ((half >> 15) & 0x1) as u32;
+    let exponent = ((half >> 10) & 0x1F) as u32;
+    let mantissa = (half & 0x3FF) as u32;
+
+    let bits = if exponent == 0 {
+        if mantissa == 0 {
+            sign << 31
+        } else {
+            let mut exponent = 127 - 15 + 1;
+            let mut mantissa = mantissa;
+            while mantissa & 0x400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            (sign << 31) | (exponent << 23) | ((mantissa & 0x3FF) << 13)
+        }
+    } else if exponent == 0x1F {
+        (sign << 31) | (0xFF << 23) | (mantissa << 13)
+    } else {
+        (sign << 31) | ((exponent + 127 - 15) << 23) | (mantissa << 13)
+    };
+
+    f32::from_bits(bits)
+}
+
+pub fn tonemap(value: f32, exposure: f32) -> u8 {
+    if !value.is_finite() || value <= 0.0 {
+        return 0;
+    }
+
+    let exposed = value * exposure;
+    let mapped = exposed / (1.0 + exposed);
+    (mapped.powf(1.0 / 2.2) * 255.0).round().clamp(0.0, 255.0) as u8
+}
+
 fn calculate_shift(mask: u32) -> u32 {
     if mask == 0 {
         return 0;
@@ -26,7 +26,7 @@ pub fn create_app_default_paths(handle: tauri::AppHandle) -> Result<(), String>
         .resolve("save_file_meta".to_string(), BaseDirectory::AppConfig)
         .map_err(|e| format!("Failed to resolve App Config directory: {}", e))?;
 
-    for game in SUPPORTED_GAMES {
+    for game in SUPPORTED_GAMES.iter() {
         let game_save_meta_path =
             default_save_file_meta_folder_path.join(game.steam_id.to_string());
         create_dir_all(&game_save_meta_path).map_err(|e| {
@@ -1,16 +1,17 @@
-use std::os::windows::process::CommandExt;
 use std::path::Path;
-use std::process::Command;
 
+#[cfg(windows)]
 use super::get_drive_letter::get_drive_letter;
+#[cfg(windows)]
+use super::hidden_command::hidden_command;
 
+#[cfg(windows)]
 pub fn create_junction(source: &Path, target: &Path) -> Result<bool, String> {
     if get_drive_letter(source) != get_drive_letter(target) {
         return Ok(false);
     }
 
-    let output = Command::new("cmd")
-        .creation_flags(0x08000000)
+    let output = hidden_command("cmd")
         .args([
             "/C",
             "mklink",
@@ -23,3 +24,10 @@ pub fn create_junction(source: &Path, target: &Path) -> Result<bool, String> {
 
     Ok(output.status.success())
 }
+
+#[cfg(not(windows))]
+pub fn create_junction(source: &Path, target: &Path) -> Result<bool, String> {
+    std::os::unix::fs::symlink(source, target)
+        .map_err(|e| format!("Failed to create symlink: {}", e))?;
+    Ok(true)
+}
@@ -0,0 +1,622 @@
+use super::convert_dds::{half_to_f32, tonemap};
+
+type Field = (usize, usize);
+
+const R0: Field = (0, 0);
+const G0: Field = (0, 1);
+const B0: Field = (0, 2);
+const R1: Field = (1, 0);
+const G1: Field = (1, 1);
+const B1: Field = (1, 2);
+const R2: Field = (2, 0);
+const G2: Field = (2, 1);
+const B2: Field = (2, 2);
+const R3: Field = (3, 0);
+const G3: Field = (3, 1);
+const B3: Field = (3, 2);
+const D: Field = (4, 0);
+
+struct Mode {
+    transformed: bool,
+    two_regions: bool,
+    endpoint_bits: u32,
+    delta_bits: [u32; 3],
+    layout: &'static [(Field, u32, u32)],
+}
+
+const MODES: [Mode; 14] = [
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 10,
+        delta_bits: [5, 5, 5],
+        layout: &[
+            (G2, 4, 4),
+            (B2, 4, 4),
+            (B3, 4, 4),
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 4),
+            (G3, 4, 4),
+            (G2, 0, 3),
+            (G1, 0, 4),
+            (B3, 0, 0),
+            (G3, 0, 3),
+            (B1, 0, 4),
+            (B3, 1, 1),
+            (B2, 0, 3),
+            (R2, 0, 4),
+            (B3, 2, 2),
+            (R3, 0, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 7,
+        delta_bits: [6, 6, 6],
+        layout: &[
+            (G2, 5, 5),
+            (G3, 4, 4),
+            (G3, 5, 5),
+            (R0, 0, 6),
+            (B3, 0, 0),
+            (B3, 1, 1),
+            (B2, 4, 4),
+            (G0, 0, 6),
+            (B2, 5, 5),
+            (B3, 2, 2),
+            (G2, 4, 4),
+            (B0, 0, 6),
+            (B3, 3, 3),
+            (B3, 5, 5),
+            (B3, 4, 4),
+            (R1, 0, 5),
+            (G2, 0, 3),
+            (G1, 0, 5),
+            (G3, 0, 3),
+            (B1, 0, 5),
+            (B2, 0, 3),
+            (R2, 0, 5),
+            (R3, 0, 5),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 11,
+        delta_bits: [5, 4, 4],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 4),
+            (R0, 10, 10),
+            (G2, 0, 3),
+            (G1, 0, 3),
+            (G0, 10, 10),
+            (B3, 0, 0),
+            (G3, 0, 3),
+            (B1, 0, 3),
+            (B0, 10, 10),
+            (B3, 1, 1),
+            (B2, 0, 3),
+            (R2, 0, 4),
+            (B3, 2, 2),
+            (R3, 0, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 11,
+        delta_bits: [4, 5, 4],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 3),
+            (R0, 10, 10),
+            (G3, 4, 4),
+            (G2, 0, 3),
+            (G1, 0, 4),
+            (G0, 10, 10),
+            (G3, 0, 3),
+            (B1, 0, 3),
+            (B0, 10, 10),
+            (B3, 1, 1),
+            (B2, 0, 3),
+            (R2, 0, 3),
+            (B3, 0, 0),
+            (B3, 2, 2),
+            (R3, 0, 3),
+            (G2, 4, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 11,
+        delta_bits: [4, 4, 5],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 3),
+            (R0, 10, 10),
+            (B2, 4, 4),
+            (G2, 0, 3),
+            (G1, 0, 3),
+            (G0, 10, 10),
+            (B3, 0, 0),
+            (G3, 0, 3),
+            (B1, 0, 4),
+            (B0, 10, 10),
+            (B2, 0, 3),
+            (R2, 0, 3),
+            (B3, 1, 1),
+            (B3, 2, 2),
+            (R3, 0, 3),
+            (B3, 4, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 9,
+        delta_bits: [5, 5, 5],
+        layout: &[
+            (R0, 0, 8),
+            (B2, 4, 4),
+            (G0, 0, 8),
+            (G2, 4, 4),
+            (B0, 0, 8),
+            (B3, 4, 4),
+            (R1, 0, 4),
+            (G3, 4, 4),
+            (G2, 0, 3),
+            (G1, 0, 4),
+            (B3, 0, 0),
+            (G3, 0, 3),
+            (B1, 0, 4),
+            (B3, 1, 1),
+            (B2, 0, 3),
+            (R2, 0, 4),
+            (B3, 2, 2),
+            (R3, 0, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 8,
+        delta_bits: [6, 5, 5],
+        layout: &[
+            (R0, 0, 7),
+            (G3, 4, 4),
+            (B2, 4, 4),
+            (G0, 0, 7),
+            (B3, 2, 2),
+            (G2, 4, 4),
+            (B0, 0, 7),
+            (B3, 3, 3),
+            (B3, 4, 4),
+            (R1, 0, 5),
+            (G2, 0, 3),
+            (G1, 0, 4),
+            (B3, 0, 0),
+            (G3, 0, 3),
+            (B1, 0, 4),
+            (B3, 1, 1),
+            (B2, 0, 3),
+            (R2, 0, 5),
+            (R3, 0, 5),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 8,
+        delta_bits: [5, 6, 5],
+        layout: &[
+            (R0, 0, 7),
+            (B3, 0, 0),
+            (B2, 4, 4),
+            (G0, 0, 7),
+            (G2, 5, 5),
+            (G2, 4, 4),
+            (B0, 0, 7),
+            (G3, 5, 5),
+            (B3, 4, 4),
+            (R1, 0, 4),
+            (G3, 4, 4),
+            (G2, 0, 3),
+            (G1, 0, 5),
+            (G3, 0, 3),
+            (B1, 0, 4),
+            (B3, 1, 1),
+            (B2, 0, 3),
+            (R2, 0, 4),
+            (B3, 2, 2),
+            (R3, 0, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: true,
+        endpoint_bits: 8,
+        delta_bits: [5, 5, 6],
+        layout: &[
+            (R0, 0, 7),
+            (B3, 1, 1),
+            (B2, 4, 4),
+            (G0, 0, 7),
+            (B2, 5, 5),
+            (G2, 4, 4),
+            (B0, 0, 7),
+            (B3, 5, 5),
+            (B3, 4, 4),
+            (R1, 0, 4),
+            (G3, 4, 4),
+            (G2, 0, 3),
+            (G1, 0, 4),
+            (B3, 0, 0),
+            (G3, 0, 3),
+            (B1, 0, 5),
+            (B2, 0, 3),
+            (R2, 0, 4),
+            (B3, 2, 2),
+            (R3, 0, 4),
+            (B3, 3, 3),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: false,
+        two_regions: true,
+        endpoint_bits: 6,
+        delta_bits: [6, 6, 6],
+        layout: &[
+            (R0, 0, 5),
+            (G3, 4, 4),
+            (B3, 0, 0),
+            (B3, 1, 1),
+            (B2, 4, 4),
+            (G0, 0, 5),
+            (G2, 5, 5),
+            (B2, 5, 5),
+            (B3, 2, 2),
+            (G2, 4, 4),
+            (B0, 0, 5),
+            (G3, 5, 5),
+            (B3, 3, 3),
+            (B3, 5, 5),
+            (B3, 4, 4),
+            (R1, 0, 5),
+            (G2, 0, 3),
+            (G1, 0, 5),
+            (G3, 0, 3),
+            (B1, 0, 5),
+            (B2, 0, 3),
+            (R2, 0, 5),
+            (R3, 0, 5),
+            (D, 0, 4),
+        ],
+    },
+    Mode {
+        transformed: false,
+        two_regions: false,
+        endpoint_bits: 10,
+        delta_bits: [10, 10, 10],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 9),
+            (G1, 0, 9),
+            (B1, 0, 9),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: false,
+        endpoint_bits: 11,
+        delta_bits: [9, 9, 9],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 8),
+            (R0, 10, 10),
+            (G1, 0, 8),
+            (G0, 10, 10),
+            (B1, 0, 8),
+            (B0, 10, 10),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: false,
+        endpoint_bits: 12,
+        delta_bits: [8, 8, 8],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 7),
+            (R0, 11, 10),
+            (G1, 0, 7),
+            (G0, 11, 10),
+            (B1, 0, 7),
+            (B0, 11, 10),
+        ],
+    },
+    Mode {
+        transformed: true,
+        two_regions: false,
+        endpoint_bits: 16,
+        delta_bits: [4, 4, 4],
+        layout: &[
+            (R0, 0, 9),
+            (G0, 0, 9),
+            (B0, 0, 9),
+            (R1, 0, 3),
+            (R0, 15, 10),
+            (G1, 0, 3),
+            (G0, 15, 10),
+            (B1, 0, 3),
+            (B0, 15, 10),
+        ],
+    },
+];
+
+const PARTITIONS: [u16; 32] = [
+    0xCCCC, 0x8888, 0xEEEE, 0xECC8, 0xC880, 0xFEEC, 0xFEC8, 0xEC80, 0xC800, 0xFFEC, 0xFE80, 0xE800,
+    0xFFE8, 0xFF00, 0xFFF0, 0xF000, 0xF710, 0x008E, 0x7100, 0x08CE, 0x008C, 0x7310, 0x3100, 0x8CCE,
+    0x088C, 0x3110, 0x6666, 0x366C, 0x17E8, 0x0FF0, 0x718E, 0x399C,
+];
+
+const ANCHORS: [usize; 32] = [
+    15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 2, 8, 2, 2, 8, 8, 15, 2, 8,
+    2, 2, 8, 8, 2, 2,
+];
+
+const WEIGHTS_3: [i32; 8] = [0, 9, 18, 27, 37, 46, 55, 64];
+const WEIGHTS_4: [i32; 16] = [0, 4, 9, 13, 17, 21, 26, 30, 34, 38, 43, 47, 51, 55, 60, 64];
+
+struct BitReader {
+    bits: u128,
+    position: u32,
+}
+
+impl BitReader {
+    fn new(block: &[u8]) -> Self {
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&block[..16]);
+        BitReader {
+            bits: u128::from_le_bytes(bytes),
+            position: 0,
+        }
+    }
+
+    fn read(&mut self, count: u32) -> i32 {
+        let value = ((self.bits >> self.position) & ((1u128 << count) - 1)) as i32;
+        self.position += count;
+        value
+    }
+}
+
+fn extend_sign(value: i32, bits: u32) -> i32 {
+    let shift = 32 - bits;
+    (value << shift) >> shift
+}
+
+fn unquantize(value: i32, bits: u32, signed: bool) -> i32 {
+    if !signed {
+        if bits >= 15 || value == 0 {
+            value
+        } else if value == (1 << bits) - 1 {
+            0xFFFF
+        } else {
+            ((value << 16) + 0x8000) >> bits
+        }
+    } else if bits >= 16 {
+        value
+    } else {
+        let negative = value < 0;
+        let magnitude = value.abs();
+        let unquantized = if magnitude == 0 {
+            0
+        } else if magnitude >= (1 << (bits - 1)) - 1 {
+            0x7FFF
+        } else {
+            ((magnitude << 15) + 0x4000) >> (bits - 1)
+        };
+        if negative { -unquantized } else { unquantized }
+    }
+}
+
+fn finish_unquantize(value: i32, signed: bool) -> f32 {
+    if !signed {
+        half_to_f32(((value * 31) >> 6) as u16)
+    } else {
+        let scaled = if value < 0 {
+            -(((-value) * 31) >> 5)
+        } else {
+            (value * 31) >> 5
+        };
+        let half = if scaled < 0 {
+            0x8000 | (-scaled) as u16
+        } else {
+            scaled as u16
+        };
+        half_to_f32(half)
+    }
+}
+
+fn decode_block(block: &[u8], signed: bool) -> [[f32; 3]; 16] {
+    let mut reader = BitReader::new(block);
+    let mut pixels = [[0.0f32; 3]; 16];
+
+    let mut mode_bits = reader.read(2);
+    if mode_bits > 1 {
+        mode_bits |= reader.read(3) << 2;
+    }
+
+    let mode_index = match mode_bits {
+        0b00 => 0,
+        0b01 => 1,
+        0b00010 => 2,
+        0b00110 => 3,
+        0b01010 => 4,
+        0b01110 => 5,
+        0b10010 => 6,
+        0b10110 => 7,
+        0b11010 => 8,
+        0b11110 => 9,
+        0b00011 => 10,
+        0b00111 => 11,
+        0b01011 => 12,
+        0b01111 => 13,
+        _ => return pixels,
+    };
+    let mode = &MODES[mode_index];
+
+    let mut endpoints = [[0i32; 3]; 4];
+    let mut partition = 0usize;
+    for &((endpoint, channel), first, last) in mode.layout {
+        let positions: Vec<u32> = if first <= last {
+            (first..=last).collect()
+        } else {
+            (last..=first).rev().collect()
+        };
+        for bit in positions {
+            let value = reader.read(1);
+            if endpoint == 4 {
+                partition |= (value as usize) << bit;
+            } else {
+                endpoints[endpoint][channel] |= value << bit;
+            }
+        }
+    }
+
+    let endpoint_count = if mode.two_regions { 4 } else { 2 };
+
+    if signed {
+        for channel in 0..3 {
+            endpoints[0][channel] = extend_sign(endpoints[0][channel], mode.endpoint_bits);
+        }
+    }
+
+    if mode.transformed || signed {
+        for endpoint in endpoints.iter_mut().take(endpoint_count).skip(1) {
+            for channel in 0..3 {
+                endpoint[channel] = extend_sign(endpoint[channel], mode.delta_bits[channel]);
+            }
+        }
+    }
+
+    if mode.transformed {
+        let mask = (1 << mode.endpoint_bits) - 1;
+        let base = endpoints[0];
+        for endpoint in endpoints.iter_mut().take(endpoint_count).skip(1) {
+            for channel in 0..3 {
+                let value = (endpoint[channel] + base[channel]) & mask;
+                endpoint[channel] = if signed {
+                    extend_sign(value, mode.endpoint_bits)
+                } else {
+                    value
+                };
+            }
+        }
+    }
+
+    for endpoint in endpoints.iter_mut().take(endpoint_count) {
+        for channel in 0..3 {
+            endpoint[channel] = unquantize(endpoint[channel], mode.endpoint_bits, signed);
+        }
+    }
+
+    let (weights, index_bits): (&[i32], u32) = if mode.two_regions {
+        (&WEIGHTS_3, 3)
+    } else {
+        (&WEIGHTS_4, 4)
+    };
+
+    for (pixel_index, pixel) in pixels.iter_mut().enumerate() {
+        let region = if mode.two_regions {
+            ((PARTITIONS[partition] >> pixel_index) & 1) as usize
+        } else {
+            0
+        };
+
+        let is_anchor = pixel_index == 0 || (mode.two_regions && pixel_index == ANCHORS[partition]);
+        let index = reader.read(if is_anchor {
+            index_bits - 1
+        } else {
+            index_bits
+        }) as usize;
+
+        let weight = weights[index];
+        let start = endpoints[region * 2];
+        let end = endpoints[region * 2 + 1];
+        for channel in 0..3 {
+            let interpolated = (start[channel] * (64 - weight) + end[channel] * weight + 32) >> 6;
+            pixel[channel] = finish_unquantize(interpolated, signed);
+        }
+    }
+
+    pixels
+}
+
+pub fn decode_bc6h(
+    data: &[u8],
+    width: usize,
+    height: usize,
+    signed: bool,
+    exposure: f32,
+    rgba_data: &mut [u32],
+) -> Result<(), String> {
+    let blocks_x = (width + 3) / 4;
+    let blocks_y = (height + 3) / 4;
+    if data.len() < blocks_x * blocks_y * 16 {
+        return Err("Unexpected data size for BC6H format".to_string());
+    }
+
+    for block_y in 0..blocks_y {
+        for block_x in 0..blocks_x {
+            let offset = (block_y * blocks_x + block_x) * 16;
+            let pixels = decode_block(&data[offset..offset + 16], signed);
+
+            for (pixel_index, pixel) in pixels.iter().enumerate() {
+                let x = block_x * 4 + pixel_index % 4;
+                let y = block_y * 4 + pixel_index / 4;
+                if x >= width || y >= height {
+                    continue;
+                }
+
+                let r = tonemap(pixel[0], exposure) as u32;
+                let g = tonemap(pixel[1], exposure) as u32;
+                let b = tonemap(pixel[2], exposure) as u32;
+                rgba_data[y * width + x] = b | (g << 8) | (r << 16) | (255 << 24);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,362 @@
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssemblyInfo {
+    pub name: String,
+    pub version: [u16; 4],
+}
+
+impl AssemblyInfo {
+    pub fn version_string(&self) -> String {
+        format!(
+            "{}.{}.{}.{}",
+            self.version[0], self.version[1], self.version[2], self.version[3]
+        )
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AssemblyMetadata {
+    pub definition: Option<AssemblyInfo>,
+    pub references: Vec<AssemblyInfo>,
+}
+
+const TABLE_ASSEMBLY: usize = 0x20;
+const TABLE_ASSEMBLY_REF: usize = 0x23;
+
+#[derive(Clone, Copy)]
+enum Column {
+    Fixed(usize),
+    String,
+    Guid,
+    Blob,
+    Table(usize),
+    Coded(&'static [usize]),
+}
+
+const TYPE_DEF_OR_REF: &[usize] = &[0x02, 0x01, 0x1B];
+const HAS_CONSTANT: &[usize] = &[0x04, 0x08, 0x17];
+const HAS_CUSTOM_ATTRIBUTE: &[usize] = &[
+    0x06, 0x04, 0x01, 0x02, 0x08, 0x09, 0x0A, 0x00, 0x0E, 0x17, 0x14, 0x11, 0x1A, 0x1B, 0x20, 0x23,
+    0x26, 0x27, 0x28, 0x2A, 0x2C, 0x2B,
+];
+const HAS_FIELD_MARSHAL: &[usize] = &[0x04, 0x08];
+const HAS_DECL_SECURITY: &[usize] = &[0x02, 0x06, 0x20];
+const MEMBER_REF_PARENT: &[usize] = &[0x02, 0x01, 0x1A, 0x06, 0x1B];
+const HAS_SEMANTICS: &[usize] = &[0x14, 0x17];
+const METHOD_DEF_OR_REF: &[usize] = &[0x06, 0x0A];
+const MEMBER_FORWARDED: &[usize] = &[0x04, 0x06];
+const CUSTOM_ATTRIBUTE_TYPE: &[usize] = &[usize::MAX, usize::MAX, 0x06, 0x0A, usize::MAX];
+const RESOLUTION_SCOPE: &[usize] = &[0x00, 0x1A, 0x23, 0x01];
+
+// ECMA-335 II.22 column layouts for every table up to AssemblyRef.
+fn table_columns(table: usize) -> &'static [Column] {
+    use Column::*;
+    match table {
+        0x00 => &[Fixed(2), String, Guid, Guid, Guid],
+        0x01 => &[Coded(RESOLUTION_SCOPE), String, String],
+        0x02 => &[
+            Fixed(4),
+            String,
+            String,
+            Coded(TYPE_DEF_OR_REF),
+            Table(0x04),
+            Table(0x06),
+        ],
+        0x03 => &[Table(0x04)],
+        0x04 => &[Fixed(2), String, Blob],
+        0x05 => &[Table(0x06)],
+        0x06 => &[Fixed(4), Fixed(2), Fixed(2), String, Blob, Table(0x08)],
+        0x07 => &[Table(0x08)],
+        0x08 => &[Fixed(2), Fixed(2), String],
+        0x09 => &[Table(0x02), Coded(TYPE_DEF_OR_REF)],
+        0x0A => &[Coded(MEMBER_REF_PARENT), String, Blob],
+        0x0B => &[Fixed(2), Coded(HAS_CONSTANT), Blob],
+        0x0C => &[
+            Coded(HAS_CUSTOM_ATTRIBUTE),
+            Coded(CUSTOM_ATTRIBUTE_TYPE),
+            Blob,
+        ],
+        0x0D => &[Coded(HAS_FIELD_MARSHAL), Blob],
+        0x0E => &[Fixed(2), Coded(HAS_DECL_SECURITY), Blob],
+        0x0F => &[Fixed(2), Fixed(4), Table(0x02)],
+        0x10 => &[Fixed(4), Table(0x04)],
+        0x11 => &[Blob],
+        0x12 => &[Table(0x02), Table(0x14)],
+        0x13 => &[Table(0x14)],
+        0x14 => &[Fixed(2), String, Coded(TYPE_DEF_OR_REF)],
+        0x15 => &[Table(0x02), Table(0x17)],
+        0x16 => &[Table(0x17)],
+        0x17 => &[Fixed(2), String, Blob],
+        0x18 => &[Fixed(2), Table(0x06), Coded(HAS_SEMANTICS)],
+        0x19 => &[
+            Table(0x02),
+            Coded(METHOD_DEF_OR_REF),
+            Coded(METHOD_DEF_OR_REF),
+        ],
+        0x1A => &[String],
+        0x1B => &[Blob],
+        0x1C => &[Fixed(2), Coded(MEMBER_FORWARDED), String, Table(0x1A)],
+        0x1D => &[Fixed(4), Table(0x04)],
+        0x1E => &[Fixed(4), Fixed(4)],
+        0x1F => &[Fixed(4)],
+        0x20 => &[
+            Fixed(4),
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(4),
+            Blob,
+            String,
+            String,
+        ],
+        0x21 => &[Fixed(4)],
+        0x22 => &[Fixed(4), Fixed(4), Fixed(4)],
+        0x23 => &[
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(2),
+            Fixed(4),
+            Blob,
+            String,
+            String,
+            Blob,
+        ],
+        _ => &[],
+    }
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+fn read_index(data: &[u8], offset: usize, size: usize) -> Option<u32> {
+    if size == 2 {
+        read_u16(data, offset).map(u32::from)
+    } else {
+        read_u32(data, offset)
+    }
+}
+
+fn rva_to_offset(
+    data: &[u8],
+    sections_offset: usize,
+    section_count: usize,
+    rva: u32,
+) -> Option<usize> {
+    (0..section_count).find_map(|index| {
+        let section = sections_offset + index * 40;
+        let virtual_size = read_u32(data, section + 8)?;
+        let virtual_address = read_u32(data, section + 12)?;
+        let raw_size = read_u32(data, section + 16)?;
+        let raw_pointer = read_u32(data, section + 20)?;
+        let size = virtual_size.max(raw_size);
+
+        if rva >= virtual_address && rva < virtual_address + size {
+            Some((rva - virtual_address + raw_pointer) as usize)
+        } else {
+            None
+        }
+    })
+}
+
+fn metadata_root(data: &[u8]) -> Option<usize> {
+    if data.get(0..2)? != b"MZ" {
+        return None;
+    }
+
+    let pe_offset = read_u32(data, 0x3C)? as usize;
+    if data.get(pe_offset..pe_offset + 4)? != b"PE\0\0" {
+        return None;
+    }
+
+    let coff = pe_offset + 4;
+    let section_count = read_u16(data, coff + 2)? as usize;
+    let optional_header_size = read_u16(data, coff + 16)? as usize;
+    let optional_header = coff + 20;
+
+    let data_directories = match read_u16(data, optional_header)? {
+        0x10B => optional_header + 96,
+        0x20B => optional_header + 112,
+        _ => return None,
+    };
+
+    let cli_header_rva = read_u32(data, data_directories + 14 * 8)?;
+    if cli_header_rva == 0 {
+        return None;
+    }
+
+    let sections_offset = optional_header + optional_header_size;
+    let cli_header = rva_to_offset(data, sections_offset, section_count, cli_header_rva)?;
+    let metadata_rva = read_u32(data, cli_header + 8)?;
+
+    rva_to_offset(data, sections_offset, section_count, metadata_rva)
+}
+
+fn read_string(heap: &[u8], index: u32) -> String {
+    let start = index as usize;
+    match heap.get(start..) {
+        Some(bytes) => {
+            let end = bytes
+                .iter()
+                .position(|byte| *byte == 0)
+                .unwrap_or(bytes.len());
+            String::from_utf8_lossy(&bytes[..end]).to_string()
+        }
+        None => String::new(),
+    }
+}
+
+pub fn assembly_metadata(data: &[u8]) -> Option<AssemblyMetadata> {
+    let root = metadata_root(data)?;
+    if read_u32(data, root)? != 0x424A5342 {
+        return None;
+    }
+
+    let version_length = read_u32(data, root + 12)? as usize;
+    let stream_count = read_u16(data, root + 16 + version_length + 2)? as usize;
+
+    let mut tables_stream = None;
+    let mut strings_heap: &[u8] = &[];
+    let mut offset = root + 16 + version_length + 4;
+    for _ in 0..stream_count {
+        let stream_offset = read_u32(data, offset)? as usize;
+        let stream_size = read_u32(data, offset + 4)? as usize;
+        let name_start = offset + 8;
+        let name_length = data.get(name_start..)?.iter().position(|byte| *byte == 0)?;
+        let name = &data[name_start..name_start + name_length];
+
+        let stream = data.get(root + stream_offset..root + stream_offset + stream_size)?;
+        match name {
+            b"#~" | b"#-" => tables_stream = Some(stream),
+            b"#Strings" => strings_heap = stream,
+            _ => {}
+        }
+
+        offset = name_start + ((name_length + 4) & !3);
+    }
+
+    let tables = tables_stream?;
+    let heap_sizes = *tables.get(6)?;
+    let valid = read_u64(tables, 8)?;
+
+    let mut row_counts = [0u32; 64];
+    let mut offset = 24;
+    for (table, row_count) in row_counts.iter_mut().enumerate() {
+        if valid & (1u64 << table) != 0 {
+            *row_count = read_u32(tables, offset)?;
+            offset += 4;
+        }
+    }
+    if heap_sizes & 0x40 != 0 {
+        offset += 4;
+    }
+
+    let string_size = if heap_sizes & 0x01 != 0 { 4 } else { 2 };
+    let guid_size = if heap_sizes & 0x02 != 0 { 4 } else { 2 };
+    let blob_size = if heap_sizes & 0x04 != 0 { 4 } else { 2 };
+
+    let column_size = |column: Column| -> usize {
+        match column {
+            Column::Fixed(size) => size,
+            Column::String => string_size,
+            Column::Guid => guid_size,
+            Column::Blob => blob_size,
+            Column::Table(table) => {
+                if row_counts[table] < 0x10000 {
+                    2
+                } else {
+                    4
+                }
+            }
+            Column::Coded(tables) => {
+                let tag_bits = usize::BITS - (tables.len() - 1).leading_zeros();
+                let max_rows = tables
+                    .iter()
+                    .filter(|table| **table < row_counts.len())
+                    .map(|table| row_counts[*table])
+                    .max()
+                    .unwrap_or(0);
+                if max_rows < (1u32 << (16 - tag_bits)) {
+                    2
+                } else {
+                    4
+                }
+            }
+        }
+    };
+
+    let mut table_offsets = [0usize; TABLE_ASSEMBLY_REF + 1];
+    for table in 0..=TABLE_ASSEMBLY_REF {
+        table_offsets[table] = offset;
+        let row_size: usize = table_columns(table).iter().map(|c| column_size(*c)).sum();
+        offset += row_size * row_counts[table] as usize;
+    }
+
+    let read_row = |table: usize, row: usize| -> Option<AssemblyInfo> {
+        let columns = table_columns(table);
+        let row_size: usize = columns.iter().map(|c| column_size(*c)).sum();
+        let row_offset = table_offsets[table] + row * row_size;
+
+        let mut values = Vec::with_capacity(columns.len());
+        let mut column_offset = row_offset;
+        for column in columns {
+            let size = column_size(*column);
+            values.push(read_index(tables, column_offset, size)?);
+            column_offset += size;
+        }
+
+        // Both tables keep the four version parts together, the name sits two
+        // columns after the flags.
+        let (version_start, name_column) = if table == TABLE_ASSEMBLY {
+            (1, 7)
+        } else {
+            (0, 6)
+        };
+        Some(AssemblyInfo {
+            name: read_string(strings_heap, values[name_column]),
+            version: [
+                values[version_start] as u16,
+                values[version_start + 1] as u16,
+                values[version_start + 2] as u16,
+                values[version_start + 3] as u16,
+            ],
+        })
+    };
+
+    let definition = if row_counts[TABLE_ASSEMBLY] > 0 {
+        read_row(TABLE_ASSEMBLY, 0)
+    } else {
+        None
+    };
+    let references = (0..row_counts[TABLE_ASSEMBLY_REF] as usize)
+        .filter_map(|row| read_row(TABLE_ASSEMBLY_REF, row))
+        .collect();
+
+    Some(AssemblyMetadata {
+        definition,
+        references,
+    })
+}
+
+pub fn read_assembly_metadata(path: &Path) -> Option<AssemblyMetadata> {
+    let data = fs::read(path).ok()?;
+    assembly_metadata(&data)
+}
@@ -11,14 +11,32 @@ pub fn highlight_path(handle: tauri::AppHandle, file_path: String) -> Result<(),
     let absolute_path = sanitize_and_resolve_path(&file_path)?;
     let path = Path::new(&absolute_path);
 
-    let mut command = Command::new("explorer");
-    if path.is_file() {
-        command.arg("/select,").arg(absolute_path);
-    } else if path.is_dir() {
-        command.arg(absolute_path);
-    } else {
-        return Err("The provided path is not valid.".to_string());
-    }
+    #[cfg(windows)]
+    let mut command = {
+        let mut command = Command::new("explorer");
+        if path.is_file() {
+            command.arg("/select,").arg(&absolute_path);
+        } else if path.is_dir() {
+            command.arg(&absolute_path);
+        } else {
+            return Err("The provided path is not valid.".to_string());
+        }
+        command
+    };
+
+    // File managers have no common way to select a file, open its folder instead.
+    #[cfg(not(windows))]
+    let mut command = {
+        let mut command = Command::new("xdg-open");
+        if path.is_file() {
+            command.arg(path.parent().unwrap_or(path));
+        } else if path.is_dir() {
+            command.arg(&absolute_path);
+        } else {
+            return Err("The provided path is not valid.".to_string());
+        }
+        command
+    };
 
     command
         .spawn()
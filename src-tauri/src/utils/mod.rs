@@ -1,9 +1,15 @@
 pub mod convert_dds;
 pub mod create_app_default_paths;
 pub mod create_junction;
+pub mod decode_bc6h;
+pub mod dotnet_assembly;
 pub mod get_drive_letter;
+pub mod hidden_command;
 pub mod highlight_path;
 pub mod json_wrapper;
 pub mod open_external_url;
 pub mod protected_paths;
+#[cfg(windows)]
 pub mod roaming_folder;
+pub mod summarize_json;
+pub mod thumbnail_cache;
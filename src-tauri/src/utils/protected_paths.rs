@@ -1,3 +1,4 @@
+#[cfg(windows)]
 pub const PROTECTED_PATHS: &[&str] = &[
     "C:\\Windows",
     "C:\\Program Files",
@@ -5,3 +6,8 @@ pub const PROTECTED_PATHS: &[&str] = &[
     "C:\\Users\\All Users",
     "C:\\ProgramData",
 ];
+
+#[cfg(not(windows))]
+pub const PROTECTED_PATHS: &[&str] = &[
+    "/bin", "/boot", "/dev", "/etc", "/lib", "/lib64", "/proc", "/sbin", "/sys", "/usr",
+];
@@ -0,0 +1,403 @@
+use serde::Serialize;
+use serde::ser::{
+    self, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+    SerializeTupleStruct, SerializeTupleVariant,
+};
+use serde_json::{Map, Value, json};
+
+const SUMMARY_MAX_DEPTH: usize = 6;
+const SUMMARY_MAX_ITEMS: usize = 50;
+
+// Decoded ESF and RigidModel files carry huge node trees and vertex buffers.
+// The summary is built while serializing, so anything past the depth and item
+// limits is counted but never turned into JSON.
+pub fn summarize_json<T: Serialize + ?Sized>(value: &T) -> Result<Value, String> {
+    value
+        .serialize(Summarizer { depth: 0 })
+        .map_err(|e| format!("Failed to summarize data: {}", e))
+}
+
+#[derive(Clone, Copy)]
+struct Summarizer {
+    depth: usize,
+}
+
+impl Summarizer {
+    fn child(self) -> Summarizer {
+        Summarizer {
+            depth: self.depth + 1,
+        }
+    }
+
+    fn is_too_deep(self) -> bool {
+        self.depth >= SUMMARY_MAX_DEPTH
+    }
+}
+
+fn object_key(key: Value) -> String {
+    match key {
+        Value::String(key) => key,
+        other => other.to_string(),
+    }
+}
+
+fn variant_object(variant: &str, value: Value) -> Value {
+    let mut map = Map::new();
+    map.insert(variant.to_string(), value);
+    Value::Object(map)
+}
+
+struct SeqSummary {
+    summarizer: Summarizer,
+    variant: Option<&'static str>,
+    items: Vec<Value>,
+    total: usize,
+}
+
+impl SeqSummary {
+    fn new(summarizer: Summarizer, variant: Option<&'static str>) -> Self {
+        SeqSummary {
+            summarizer,
+            variant,
+            items: Vec::new(),
+            total: 0,
+        }
+    }
+
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), serde_json::Error> {
+        self.total += 1;
+        if !self.summarizer.is_too_deep() && self.items.len() < SUMMARY_MAX_ITEMS {
+            self.items.push(value.serialize(self.summarizer.child())?);
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Value {
+        let value = if self.summarizer.is_too_deep() {
+            json!({ "items": self.total })
+        } else {
+            if self.total > SUMMARY_MAX_ITEMS {
+                self.items.push(Value::String(format!(
+                    "... {} more items",
+                    self.total - SUMMARY_MAX_ITEMS
+                )));
+            }
+            Value::Array(self.items)
+        };
+        match self.variant {
+            Some(variant) => variant_object(variant, value),
+            None => value,
+        }
+    }
+}
+
+struct MapSummary {
+    summarizer: Summarizer,
+    variant: Option<&'static str>,
+    map: Map<String, Value>,
+    next_key: Option<String>,
+    total: usize,
+}
+
+impl MapSummary {
+    fn new(summarizer: Summarizer, variant: Option<&'static str>) -> Self {
+        MapSummary {
+            summarizer,
+            variant,
+            map: Map::new(),
+            next_key: None,
+            total: 0,
+        }
+    }
+
+    fn insert<T: Serialize + ?Sized>(
+        &mut self,
+        key: String,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.total += 1;
+        if !self.summarizer.is_too_deep() {
+            self.map
+                .insert(key, value.serialize(self.summarizer.child())?);
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        let value = if self.summarizer.is_too_deep() {
+            json!({ "fields": self.total })
+        } else {
+            Value::Object(self.map)
+        };
+        match self.variant {
+            Some(variant) => variant_object(variant, value),
+            None => value,
+        }
+    }
+}
+
+impl ser::Serializer for Summarizer {
+    type Ok = Value;
+    type Error = serde_json::Error;
+    type SerializeSeq = SeqSummary;
+    type SerializeTuple = SeqSummary;
+    type SerializeTupleStruct = SeqSummary;
+    type SerializeTupleVariant = SeqSummary;
+    type SerializeMap = MapSummary;
+    type SerializeStruct = MapSummary;
+    type SerializeStructVariant = MapSummary;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Self::Error> {
+        Ok(Value::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Self::Error> {
+        Ok(json!(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Self::Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Self::Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Self::Error> {
+        let mut seq = SeqSummary::new(self, None);
+        for byte in v {
+            seq.push(byte)?;
+        }
+        Ok(seq.finish())
+    }
+
+    fn serialize_none(self) -> Result<Value, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Value, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Self::Error> {
+        Ok(Value::Null)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Self::Error> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Value, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Self::Error> {
+        let mut map = MapSummary::new(self, None);
+        map.insert(variant.to_string(), value)?;
+        Ok(map.finish())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<SeqSummary, Self::Error> {
+        Ok(SeqSummary::new(self, None))
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<SeqSummary, Self::Error> {
+        Ok(SeqSummary::new(self, None))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<SeqSummary, Self::Error> {
+        Ok(SeqSummary::new(self, None))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SeqSummary, Self::Error> {
+        Ok(SeqSummary::new(self, Some(variant)))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<MapSummary, Self::Error> {
+        Ok(MapSummary::new(self, None))
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<MapSummary, Self::Error> {
+        Ok(MapSummary::new(self, None))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<MapSummary, Self::Error> {
+        Ok(MapSummary::new(self, Some(variant)))
+    }
+}
+
+impl SerializeSeq for SeqSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeTuple for SeqSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeTupleStruct for SeqSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeTupleVariant for SeqSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeMap for MapSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.next_key = Some(object_key(serde_json::to_value(key)?));
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.next_key.take().unwrap_or_default();
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeStruct for MapSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
+
+impl SerializeStructVariant for MapSummary {
+    type Ok = Value;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.insert(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Value, Self::Error> {
+        Ok(self.finish())
+    }
+}
@@ -0,0 +1,165 @@
+use image::ImageFormat;
+use image::imageops::FilterType;
+use reqwest::Client;
+use rustc_hash::FxHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use std::time::{Duration, SystemTime};
+use tokio::task;
+
+pub const THUMBNAIL_SIZES: &[u32] = &[96, 192, 384];
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 384;
+
+// Previews that failed to download are retried once a day at most.
+const REMOTE_RETRY_SECS: u64 = 24 * 60 * 60;
+
+static CLIENT: LazyLock<Client> = LazyLock::new(|| {
+    Client::builder()
+        .timeout(Duration::from_secs(15))
+        .build()
+        .unwrap_or_default()
+});
+
+pub fn thumbnails_dir(cache_dir: &Path) -> PathBuf {
+    cache_dir.join("thumbnails")
+}
+
+pub fn thumbnail_key(source: &str) -> String {
+    let mut hasher = FxHasher::default();
+    source.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+pub fn thumbnail_path(cache_dir: &Path, key: &str, size: u32) -> PathBuf {
+    thumbnails_dir(cache_dir).join(format!("{}_{}.jpg", key, size))
+}
+
+fn missing_marker_path(cache_dir: &Path, key: &str) -> PathBuf {
+    thumbnails_dir(cache_dir).join(format!("{}.none", key))
+}
+
+fn modified_secs(path: &Path) -> Option<u64> {
+    fs::metadata(path)
+        .ok()?
+        .modified()
+        .ok()?
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .ok()
+        .map(|d| d.as_secs())
+}
+
+pub fn cached_thumbnail(cache_dir: &Path, key: &str, source: Option<&Path>) -> Option<String> {
+    let path = thumbnail_path(cache_dir, key, DEFAULT_THUMBNAIL_SIZE);
+    let thumbnail_modified = modified_secs(&path)?;
+
+    if let Some(source) = source {
+        if modified_secs(source)? > thumbnail_modified {
+            return None;
+        }
+    }
+
+    Some(path.to_string_lossy().to_string())
+}
+
+pub fn is_marked_missing(cache_dir: &Path, key: &str, source: &Path) -> bool {
+    match (
+        modified_secs(&missing_marker_path(cache_dir, key)),
+        modified_secs(source),
+    ) {
+        (Some(marker_modified), Some(source_modified)) => marker_modified >= source_modified,
+        _ => false,
+    }
+}
+
+pub fn mark_missing(cache_dir: &Path, key: &str) {
+    let dir = thumbnails_dir(cache_dir);
+    if fs::create_dir_all(&dir).is_ok() {
+        let _ = fs::write(missing_marker_path(cache_dir, key), b"");
+    }
+}
+
+pub fn create_thumbnails(cache_dir: &Path, key: &str, image_data: &[u8]) -> Result<String, String> {
+    let dir = thumbnails_dir(cache_dir);
+    fs::create_dir_all(&dir)
+        .map_err(|e| format!("Failed to create thumbnails directory: {}", e))?;
+
+    let image = image::load_from_memory(image_data)
+        .map_err(|e| format!("Failed to decode preview image: {}", e))?;
+
+    for size in THUMBNAIL_SIZES {
+        let resized = if image.width() > *size || image.height() > *size {
+            image.resize(*size, *size, FilterType::Triangle)
+        } else {
+            image.clone()
+        };
+
+        let mut buffer = Cursor::new(Vec::new());
+        resized
+            .to_rgb8()
+            .write_to(&mut buffer, ImageFormat::Jpeg)
+            .map_err(|e| format!("Failed to encode thumbnail: {}", e))?;
+
+        fs::write(thumbnail_path(cache_dir, key, *size), buffer.into_inner())
+            .map_err(|e| format!("Failed to write thumbnail: {}", e))?;
+    }
+
+    Ok(thumbnail_path(cache_dir, key, DEFAULT_THUMBNAIL_SIZE)
+        .to_string_lossy()
+        .to_string())
+}
+
+pub fn file_thumbnail(cache_dir: &Path, image_path: &Path) -> Option<String> {
+    let key = thumbnail_key(&image_path.to_string_lossy());
+    if let Some(cached) = cached_thumbnail(cache_dir, &key, Some(image_path)) {
+        return Some(cached);
+    }
+
+    let image_data = fs::read(image_path).ok()?;
+    create_thumbnails(cache_dir, &key, &image_data).ok()
+}
+
+// Workshop items keep their preview url when the image changes, so the update
+// time is part of the key and a new upload gets a new thumbnail.
+pub fn remote_thumbnail_key(url: &str, updated_at: Option<u128>) -> String {
+    thumbnail_key(&format!("{}#{}", url, updated_at.unwrap_or_default()))
+}
+
+fn is_recently_failed(cache_dir: &Path, key: &str) -> bool {
+    let now = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    modified_secs(&missing_marker_path(cache_dir, key))
+        .is_some_and(|marker_modified| now.saturating_sub(marker_modified) < REMOTE_RETRY_SECS)
+}
+
+async fn download_thumbnail(cache_dir: PathBuf, key: String, url: &str) -> Option<String> {
+    let res = CLIENT.get(url).send().await.ok()?;
+    if !res.status().is_success() {
+        return None;
+    }
+
+    let image_data = res.bytes().await.ok()?;
+    task::spawn_blocking(move || create_thumbnails(&cache_dir, &key, &image_data).ok())
+        .await
+        .ok()
+        .flatten()
+}
+
+pub async fn remote_thumbnail(cache_dir: PathBuf, key: String, url: String) -> Option<String> {
+    if let Some(cached) = cached_thumbnail(&cache_dir, &key, None) {
+        return Some(cached);
+    }
+    if is_recently_failed(&cache_dir, &key) {
+        return None;
+    }
+
+    let thumbnail = download_thumbnail(cache_dir.clone(), key.clone(), &url).await;
+    if thumbnail.is_none() {
+        mark_missing(&cache_dir, &key);
+    }
+    thumbnail
+}
@@ -1,18 +1,53 @@
 use bincode::{Decode, Encode};
+use serde::Serialize;
 use std::{fs::File, io::Read, path::Path};
 use xml::reader::{EventReader, XmlEvent};
 
 use crate::steam::subscribed_mods::CachedSubModuleContents;
 
+// Bump whenever SubModuleContents changes, the version is part of the cache
+// file name so stale bincode layouts are never decoded.
+pub const SUBMODULE_CACHE_VERSION: u32 = 3;
+
+fn cache_file_name(app_id: u32, identifier: &str) -> String {
+    format!(
+        "workshop_item_{}_{}_contents_v{}.bin",
+        app_id, identifier, SUBMODULE_CACHE_VERSION
+    )
+}
+
 #[allow(dead_code)]
-#[derive(Debug, Clone, Encode, Decode)]
+#[derive(Debug, Clone, Serialize, Encode, Decode)]
 pub struct DependentModule {
     pub id: String,
     pub dependent_version: Option<String>,
     pub optional: Option<bool>,
+    pub load_order: Option<String>,
+    pub incompatible: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Encode, Decode)]
+pub struct SubModuleTag {
+    pub key: String,
+    pub value: String,
 }
 
-#[derive(Debug, Clone, Encode, Decode)]
+#[derive(Debug, Clone, Default, Serialize, Encode, Decode)]
+pub struct SubModule {
+    pub name: String,
+    pub dll_name: Option<String>,
+    pub sub_module_class_type: Option<String>,
+    pub assemblies: Vec<String>,
+    pub tags: Vec<SubModuleTag>,
+}
+
+#[derive(Debug, Clone, Serialize, Encode, Decode)]
+pub struct XmlNode {
+    pub id: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize, Encode, Decode)]
 pub struct SubModuleContents {
     pub id: String,
     pub name: String,
@@ -20,6 +55,55 @@ pub struct SubModuleContents {
     pub module_type: Option<String>,
     pub depended_modules: Option<Vec<DependentModule>>,
     pub modules_to_load_after_this: Option<Vec<DependentModule>>,
+    pub depended_module_metadatas: Option<Vec<DependentModule>>,
+    pub incompatible_modules: Option<Vec<DependentModule>>,
+    pub sub_modules: Option<Vec<SubModule>>,
+    pub xml_nodes: Option<Vec<XmlNode>>,
+    pub is_singleplayer_module: Option<bool>,
+    pub is_multiplayer_module: Option<bool>,
+}
+
+impl SubModuleContents {
+    // Modules that have to be loaded before this one, from both dependency formats.
+    pub fn load_before_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = Vec::new();
+        let dependencies = self.depended_modules.iter().flatten().chain(
+            self.depended_module_metadatas
+                .iter()
+                .flatten()
+                .filter(|dep| dep.load_order.as_deref() == Some("LoadBeforeThis")),
+        );
+        for dep in dependencies {
+            if !ids.contains(&dep.id.as_str()) {
+                ids.push(dep.id.as_str());
+            }
+        }
+        ids
+    }
+
+    pub fn load_after_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = Vec::new();
+        let children = self.modules_to_load_after_this.iter().flatten().chain(
+            self.depended_module_metadatas
+                .iter()
+                .flatten()
+                .filter(|dep| dep.load_order.as_deref() == Some("LoadAfterThis")),
+        );
+        for child in children {
+            if !ids.contains(&child.id.as_str()) {
+                ids.push(child.id.as_str());
+            }
+        }
+        ids
+    }
+}
+
+fn parse_bool(value: &str) -> bool {
+    value.trim().eq_ignore_ascii_case("true")
+}
+
+fn none_if_empty<T>(items: Vec<T>) -> Option<Vec<T>> {
+    if items.is_empty() { None } else { Some(items) }
 }
 
 pub fn submodule_contents(
@@ -37,8 +121,7 @@ pub fn submodule_contents(
         return None;
     }
 
-    let cache_json_filename = format!("workshop_item_{}_{}_contents.bin", app_id, identifier);
-    let cache_file = cache_dir.join(cache_json_filename);
+    let cache_file = cache_dir.join(cache_file_name(app_id, &identifier));
     let bincode_config = bincode::config::standard();
     if cache_file.exists() {
         if let Ok(metadata) = std::fs::metadata(&submodule_path) {
@@ -79,6 +162,13 @@ pub fn submodule_contents(
                 let mut module_type = None;
                 let mut depended_modules = Vec::new();
                 let mut modules_to_load_after_this = Vec::new();
+                let mut depended_module_metadatas = Vec::new();
+                let mut incompatible_modules = Vec::new();
+                let mut sub_modules = Vec::new();
+                let mut current_sub_module: Option<SubModule> = None;
+                let mut xml_nodes = Vec::new();
+                let mut is_singleplayer_module = None;
+                let mut is_multiplayer_module = None;
 
                 let mut element_stack: Vec<String> = Vec::new();
                 let mut current_element = None;
@@ -102,12 +192,50 @@ pub fn submodule_contents(
                                     }
                                 }
                                 "Name" => {
+                                    let value = attributes
+                                        .iter()
+                                        .find(|a| a.name.local_name == "value")
+                                        .map(|a| a.value.clone());
                                     if element_stack.len() == 2 && element_stack[0] == "Module" {
-                                        if let Some(attribute) =
-                                            attributes.iter().find(|a| a.name.local_name == "value")
-                                        {
-                                            name = attribute.value.clone();
+                                        if let Some(value) = value {
+                                            name = value;
                                         }
+                                    } else if let Some(sub_module) = current_sub_module.as_mut() {
+                                        sub_module.name = value.unwrap_or_default();
+                                    }
+                                }
+                                "DLLName" | "SubModuleClassType" | "Assembly" => {
+                                    if let (Some(sub_module), Some(attribute)) = (
+                                        current_sub_module.as_mut(),
+                                        attributes.iter().find(|a| a.name.local_name == "value"),
+                                    ) {
+                                        match elem_name.local_name.as_str() {
+                                            "DLLName" => {
+                                                sub_module.dll_name = Some(attribute.value.clone())
+                                            }
+                                            "SubModuleClassType" => {
+                                                sub_module.sub_module_class_type =
+                                                    Some(attribute.value.clone())
+                                            }
+                                            _ => {
+                                                sub_module.assemblies.push(attribute.value.clone())
+                                            }
+                                        }
+                                    }
+                                }
+                                "Tag" => {
+                                    if let Some(sub_module) = current_sub_module.as_mut() {
+                                        let attribute = |key: &str| {
+                                            attributes
+                                                .iter()
+                                                .find(|a| a.name.local_name == key)
+                                                .map(|a| a.value.clone())
+                                                .unwrap_or_default()
+                                        };
+                                        sub_module.tags.push(SubModuleTag {
+                                            key: attribute("key"),
+                                            value: attribute("value"),
+                                        });
                                     }
                                 }
                                 "Version" => {
@@ -128,9 +256,94 @@ pub fn submodule_contents(
                                         }
                                     }
                                 }
+                                "SingleplayerModule" | "IsSingleplayerModule" => {
+                                    if element_stack.len() == 2 && element_stack[0] == "Module" {
+                                        if let Some(attribute) =
+                                            attributes.iter().find(|a| a.name.local_name == "value")
+                                        {
+                                            is_singleplayer_module =
+                                                Some(parse_bool(&attribute.value));
+                                        }
+                                    }
+                                }
+                                "MultiplayerModule" | "IsMultiplayerModule" => {
+                                    if element_stack.len() == 2 && element_stack[0] == "Module" {
+                                        if let Some(attribute) =
+                                            attributes.iter().find(|a| a.name.local_name == "value")
+                                        {
+                                            is_multiplayer_module =
+                                                Some(parse_bool(&attribute.value));
+                                        }
+                                    }
+                                }
                                 "DependedModules" => {
                                     current_element = Some("DependedModules");
                                 }
+                                "DependedModuleMetadatas" => {
+                                    current_element = Some("DependedModuleMetadatas");
+                                }
+                                "SubModules" => {
+                                    current_element = Some("SubModules");
+                                }
+                                "Xmls" => {
+                                    current_element = Some("Xmls");
+                                }
+                                "XmlName" => {
+                                    if current_element == Some("Xmls") {
+                                        let attribute = |key: &str| {
+                                            attributes
+                                                .iter()
+                                                .find(|a| a.name.local_name == key)
+                                                .map(|a| a.value.clone())
+                                                .unwrap_or_default()
+                                        };
+                                        xml_nodes.push(XmlNode {
+                                            id: attribute("id"),
+                                            path: attribute("path"),
+                                        });
+                                    }
+                                }
+                                "DependedModuleMetadata" => {
+                                    if current_element == Some("DependedModuleMetadatas") {
+                                        let mut dep = DependentModule {
+                                            id: String::new(),
+                                            dependent_version: None,
+                                            optional: None,
+                                            load_order: None,
+                                            incompatible: None,
+                                        };
+
+                                        for attr in &attributes {
+                                            match attr.name.local_name.as_str() {
+                                                "id" => dep.id = attr.value.clone(),
+                                                "version" => {
+                                                    dep.dependent_version = Some(attr.value.clone())
+                                                }
+                                                "optional" => {
+                                                    dep.optional = Some(parse_bool(&attr.value))
+                                                }
+                                                "order" => {
+                                                    dep.load_order = Some(attr.value.clone())
+                                                }
+                                                "incompatible" => {
+                                                    dep.incompatible = Some(parse_bool(&attr.value))
+                                                }
+                                                _ => {}
+                                            }
+                                        }
+
+                                        if dep.incompatible == Some(true) {
+                                            incompatible_modules.push(dep);
+                                        } else {
+                                            depended_module_metadatas.push(dep);
+                                        }
+                                    }
+                                }
+                                "SubModule" => {
+                                    if current_element == Some("SubModules") {
+                                        current_sub_module = Some(SubModule::default());
+                                    }
+                                }
                                 "ModulesToLoadAfterThis" => {
                                     current_element = Some("ModulesToLoadAfterThis");
                                 }
@@ -158,6 +371,8 @@ pub fn submodule_contents(
                                             id: dep_id,
                                             dependent_version: dep_version,
                                             optional,
+                                            load_order: None,
+                                            incompatible: None,
                                         });
                                     }
                                 }
@@ -170,6 +385,8 @@ pub fn submodule_contents(
                                                 id: attribute.value.clone(),
                                                 dependent_version: None,
                                                 optional: None,
+                                                load_order: None,
+                                                incompatible: None,
                                             });
                                         }
                                     }
@@ -177,14 +394,23 @@ pub fn submodule_contents(
                                 _ => {}
                             }
                         }
-                        Ok(XmlEvent::EndElement { name: _ }) => {
+                        Ok(XmlEvent::EndElement { name: elem_name }) => {
                             if !element_stack.is_empty() {
                                 element_stack.pop();
                             }
 
+                            if elem_name.local_name == "SubModule" {
+                                if let Some(sub_module) = current_sub_module.take() {
+                                    sub_modules.push(sub_module);
+                                }
+                            }
+
                             if element_stack.len() == 1 {
                                 if current_element == Some("DependedModules")
                                     || current_element == Some("ModulesToLoadAfterThis")
+                                    || current_element == Some("DependedModuleMetadatas")
+                                    || current_element == Some("SubModules")
+                                    || current_element == Some("Xmls")
                                 {
                                     current_element = None;
                                 }
@@ -210,6 +436,12 @@ pub fn submodule_contents(
                     } else {
                         Some(modules_to_load_after_this)
                     },
+                    depended_module_metadatas: none_if_empty(depended_module_metadatas),
+                    incompatible_modules: none_if_empty(incompatible_modules),
+                    sub_modules: none_if_empty(sub_modules),
+                    xml_nodes: none_if_empty(xml_nodes),
+                    is_singleplayer_module,
+                    is_multiplayer_module,
                 })
             } else {
                 None
@@ -237,10 +469,7 @@ pub fn submodule_contents(
 
         let bincode_config = bincode::config::standard();
         if let Ok(data) = bincode::encode_to_vec(&cache_data, bincode_config) {
-            let cache_json_filename =
-                format!("workshop_item_{}_{}_contents.bin", app_id, identifier);
-            let cache_file = cache_dir.join(cache_json_filename);
-            let _ = std::fs::write(&cache_file, data);
+            let _ = std::fs::write(cache_dir.join(cache_file_name(app_id, &identifier)), data);
         }
     }
 
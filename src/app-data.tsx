@@ -1,6 +1,9 @@
 import { useCallback, useEffect, useState } from 'react';
 import { useShallow } from 'zustand/react/shallow';
 
+import { convertFileSrc } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
+
 import { Loading } from '@/components/loading';
 
 import { settingStore } from '@/lib/store/setting';
@@ -204,6 +207,35 @@ export const AppData = ({ children }: { children: React.ReactNode }) => {
 		init();
 	}, [init]);
 
+	// Workshop previews that were not cached yet arrive after the list.
+	useEffect(() => {
+		const unlisten = listen<{
+			app_id: number;
+			identifier: string;
+			preview_local: string;
+		}>('mod-thumbnail', ({ payload }) => {
+			if (payload.app_id !== selectedGame!.steam_id) return;
+			const mods = modsStore.getState().mods;
+			setMods(
+				mods.map(mod =>
+					mod.identifier === payload.identifier &&
+					'preview_local' in mod
+						? {
+								...mod,
+								preview_local: convertFileSrc(
+									payload.preview_local,
+								),
+							}
+						: mod,
+				),
+			);
+		});
+
+		return () => {
+			unlisten.then(fn => fn());
+		};
+	}, [selectedGame!.steam_id]);
+
 	if (fetchModsLoading && stateMods.length === 0) return <Loading />;
 
 	return children;
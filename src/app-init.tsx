@@ -41,6 +41,7 @@ function AppInit() {
 		setIncludeHiddenDownloads,
 		setCompactArchiveNames,
 		setCompactSaveNames,
+		setStrictLaunch,
 		setSidebarAccordion,
 	} = settingStore(
 		useShallow(state => ({
@@ -59,6 +60,7 @@ function AppInit() {
 			setIncludeHiddenDownloads: state.setIncludeHiddenDownloads,
 			setCompactArchiveNames: state.setCompactArchiveNames,
 			setCompactSaveNames: state.setCompactSaveNames,
+			setStrictLaunch: state.setStrictLaunch,
 			setSidebarAccordion: state.setSidebarAccordion,
 		})),
 	);
@@ -85,6 +87,7 @@ function AppInit() {
 		setIncludeHiddenDownloads(setting.include_hidden_downloads);
 		setCompactArchiveNames(setting.compact_archive_names);
 		setCompactSaveNames(setting.compact_save_names);
+		setStrictLaunch(setting.strict_launch);
 		setSidebarAccordion(setting.sidebar_accordion);
 		setNexusAuthApi(setting.nexus_api_key ?? null);
 		setNexusAuthParams(
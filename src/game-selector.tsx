@@ -12,6 +12,8 @@ import { WindowActions } from '@/header/window-actions';
 
 import { settingStore } from '@/lib/store/setting';
 
+const GAME_IMAGE_FALLBACK = '/logo.png';
+
 const GameSelector = () => {
 	const [loading, setLoading] = useState(true);
 
@@ -37,6 +39,13 @@ const GameSelector = () => {
 				alt={gameDetails.name}
 				className="h-[300px] w-full rounded-lg object-cover transition-all hover:scale-105 hover:cursor-pointer"
 				src={`/${gameDetails.slug}.jpg`}
+				onError={event => {
+					// Not every supported game ships with cover art.
+					const image = event.currentTarget;
+					if (image.src.endsWith(GAME_IMAGE_FALLBACK)) return;
+					image.src = GAME_IMAGE_FALLBACK;
+					image.classList.replace('object-cover', 'object-contain');
+				}}
 				style={{
 					filter: gameDetails.game_path_exists
 						? ''
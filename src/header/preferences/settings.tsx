@@ -37,6 +37,8 @@ export function Settings() {
 		mod_download_path,
 		dependency_confirmation,
 		setDependencyConfirmation,
+		strict_launch,
+		setStrictLaunch,
 	} = settingStore(
 		useShallow(state => ({
 			setModInstallationPath: state.setModInstallationPath,
@@ -45,6 +47,8 @@ export function Settings() {
 			mod_download_path: state.mod_download_path,
 			dependency_confirmation: state.dependency_confirmation,
 			setDependencyConfirmation: state.setDependencyConfirmation,
+			strict_launch: state.strict_launch,
+			setStrictLaunch: state.setStrictLaunch,
 		})),
 	);
 
@@ -189,20 +193,38 @@ export function Settings() {
 				</div>
 				<Separator className="my-4" />
 				<div className="flex items-center justify-between">
-					<div className="flex items-center space-x-2">
-						<Checkbox
-							id="dependency_confirmation"
-							checked={dependency_confirmation === 1}
-							onCheckedChange={isChecked =>
-								setDependencyConfirmation(isChecked ? 1 : 0)
-							}
-						/>
-						<label
-							htmlFor="dependency_confirmation"
-							className="text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70"
-						>
-							Mod dependency confirmation
-						</label>
+					<div className="flex flex-col gap-3">
+						<div className="flex items-center space-x-2">
+							<Checkbox
+								id="dependency_confirmation"
+								checked={dependency_confirmation === 1}
+								onCheckedChange={isChecked =>
+									setDependencyConfirmation(isChecked ? 1 : 0)
+								}
+							/>
+							<label
+								htmlFor="dependency_confirmation"
+								className="text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70"
+							>
+								Mod dependency confirmation
+							</label>
+						</div>
+						<div className="flex items-center space-x-2">
+							<Checkbox
+								id="strict_launch"
+								checked={strict_launch === 1}
+								onCheckedChange={isChecked =>
+									setStrictLaunch(isChecked ? 1 : 0)
+								}
+							/>
+							<label
+								htmlFor="strict_launch"
+								className="text-sm font-medium leading-none peer-disabled:cursor-not-allowed peer-disabled:opacity-70"
+							>
+								Refuse to launch when the pre-launch check finds
+								blocking problems
+							</label>
+						</div>
 					</div>
 
 					<ClearCache />
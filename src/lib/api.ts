@@ -8,7 +8,12 @@ import {
 import type { ProfileExportData } from '@/lib/store/profile-model';
 import type { ModItem } from '@/lib/store/mods';
 import type { SaveFile, SaveFileLoadOrderData } from '@/lib/store/save_files';
-import type { PackDBRow, ParsedDB } from '@/lib/store/pack-manager';
+import type {
+	DDSConvertOptions,
+	DDSMetadata,
+	PackDBRow,
+	ParsedDB,
+} from '@/lib/store/pack-manager';
 import { normalizeTimestamp } from '@/lib/utils';
 
 export type ModConflicts = {
@@ -38,6 +43,109 @@ export type ZipItemInfo = {
 	filesize: number;
 };
 
+export type TotalwarMod = {
+	identifier: string;
+	mod_file_path: string;
+};
+
+export type UsedModsProblem =
+	| { kind: 'missing_file'; identifier: string; mod_file_path: string }
+	| {
+			kind: 'invalid_pack';
+			identifier: string;
+			mod_file_path: string;
+			message: string;
+	  }
+	| { kind: 'unquotable_path'; identifier: string; mod_file_path: string }
+	| {
+			kind: 'duplicate_pack_name';
+			identifier: string;
+			pack_name: string;
+			shadowed_by: string;
+	  };
+
+export type OutdatedMod = {
+	identifier: string;
+	mod_path: string;
+	modified_at: number | null;
+	older_than_patch: boolean;
+	outdated_tables: string[];
+};
+
+export type GameUpdate = {
+	app_id: number;
+	previous_build_id: number;
+	current_build_id: number;
+	patched_at: number | null;
+	outdated_mods: OutdatedMod[];
+};
+
+export type TotalwarLaunch = {
+	launched: boolean;
+	problems: UsedModsProblem[];
+};
+
+export type BannerlordLaunchMod = {
+	identifier: string;
+	bannerlord_id: string;
+	mod_path: string;
+};
+
+export type BannerlordLaunch = {
+	command: string;
+};
+
+export type PreflightIssue = {
+	kind: string;
+	severity: 'warning' | 'blocking';
+	subject: string;
+	message: string;
+};
+
+export type PreflightReport = {
+	app_id: number;
+	profile_id: number;
+	generated_at: number;
+	command: string[];
+	used_mods_txt: string | null;
+	deployments: { source: string; target: string }[];
+	issues: PreflightIssue[];
+	blocking: boolean;
+};
+
+export type LaunchOptions = {
+	launcher: string | null;
+	args: string[];
+	executable: string | null;
+	env: Record<string, string>;
+	launch_mode: 'direct' | 'steam';
+};
+
+export type BannerlordLauncher = {
+	id: string;
+	name: string;
+	exe_path: string;
+};
+
+export type ConfigEntry = {
+	name: string;
+	live_path: string;
+	live_exists: boolean;
+	captured: boolean;
+};
+
+export type ConfigSnapshot = {
+	profile_id: number;
+	captured_at: number | null;
+	entries: ConfigEntry[];
+};
+
+export type ConfigBackup = {
+	backup_id: string;
+	created_at: number;
+	entries: string[];
+};
+
 type ModMigrationResponse = {
 	mod_meta_information: Record<
 		string,
@@ -436,34 +544,157 @@ class API {
 		});
 	}
 
-	async start_game_totalwar(
+	async migrate_data_mod(
 		app_id: number,
-		add_directory_txt: string,
-		used_mods_txt: string,
-		save_game?: string,
+		mod_file_path: string,
+		remove_source: boolean,
 	): Promise<string> {
+		const setting = await SettingModel.retrieve();
+		return invoke('migrate_data_mod', {
+			app_id,
+			mod_file_path,
+			mod_installation_path: setting.mod_installation_path,
+			remove_source,
+		});
+	}
+
+	async start_game_totalwar(
+		app_id: number,
+		mods: TotalwarMod[],
+		save_game: string | undefined,
+		profile_id: number,
+		strict: boolean,
+		folder_paths: string[],
+	): Promise<TotalwarLaunch> {
 		return invoke('start_game_totalwar', {
 			app_id,
-			add_directory_txt,
-			used_mods_txt,
+			mods,
 			save_game,
+			profile_id,
+			strict,
+			folder_paths,
 		});
 	}
 
 	async start_game_bannerlord(
 		app_id: number,
-		mods: {
-			identifier: string;
-			bannerlord_id: string;
-			mod_path: string;
-		}[],
-	): Promise<string> {
+		mods: BannerlordLaunchMod[],
+		profile_id: number,
+		strict: boolean,
+		folder_paths: string[],
+	): Promise<BannerlordLaunch> {
 		return invoke('start_game_bannerlord', {
 			app_id,
 			mods,
+			profile_id,
+			strict,
+			folder_paths,
+		});
+	}
+
+	async preflight_totalwar(
+		app_id: number,
+		profile_id: number,
+		mods: TotalwarMod[],
+		save_game: string | undefined,
+		folder_paths: string[],
+	): Promise<PreflightReport> {
+		return invoke('preflight_totalwar', {
+			app_id,
+			profile_id,
+			mods,
+			save_game,
+			folder_paths,
+		});
+	}
+
+	async preflight_bannerlord(
+		app_id: number,
+		profile_id: number,
+		mods: BannerlordLaunchMod[],
+		folder_paths: string[],
+	): Promise<PreflightReport> {
+		return invoke('preflight_bannerlord', {
+			app_id,
+			profile_id,
+			mods,
+			folder_paths,
 		});
 	}
 
+	async check_game_update(
+		app_id: number,
+		mods: { identifier: string; mod_path: string }[],
+	): Promise<GameUpdate | null> {
+		return invoke('check_game_update', { app_id, mods });
+	}
+
+	async acknowledge_game_update(app_id: number): Promise<void> {
+		return invoke('acknowledge_game_update', { app_id });
+	}
+
+	async launch_options(
+		app_id: number,
+		profile_id: number,
+	): Promise<LaunchOptions> {
+		return invoke('launch_options', { app_id, profile_id });
+	}
+
+	async set_launch_options(
+		app_id: number,
+		profile_id: number,
+		options: LaunchOptions,
+	): Promise<void> {
+		return invoke('set_launch_options', { app_id, profile_id, options });
+	}
+
+	async game_executables(app_id: number): Promise<string[]> {
+		return invoke('game_executables', { app_id });
+	}
+
+	async bannerlord_launchers(app_id: number): Promise<BannerlordLauncher[]> {
+		return invoke('bannerlord_launchers', { app_id });
+	}
+
+	async config_snapshot(
+		app_id: number,
+		profile_id: number,
+	): Promise<ConfigSnapshot> {
+		return invoke('config_snapshot', { app_id, profile_id });
+	}
+
+	async capture_config_snapshot(
+		app_id: number,
+		profile_id: number,
+	): Promise<ConfigSnapshot> {
+		return invoke('capture_config_snapshot', { app_id, profile_id });
+	}
+
+	async apply_config_snapshot(
+		app_id: number,
+		profile_id: number,
+	): Promise<string | null> {
+		return invoke('apply_config_snapshot', { app_id, profile_id });
+	}
+
+	async delete_config_snapshot(
+		app_id: number,
+		profile_id: number,
+	): Promise<void> {
+		return invoke('delete_config_snapshot', { app_id, profile_id });
+	}
+
+	async config_backups(app_id: number): Promise<ConfigBackup[]> {
+		return invoke('config_backups', { app_id });
+	}
+
+	async restore_config_backup(
+		app_id: number,
+		backup_id: string,
+	): Promise<void> {
+		return invoke('restore_config_backup', { app_id, backup_id });
+	}
+
 	async export_profile(
 		app_id: number,
 		profile_id: number,
@@ -514,6 +745,21 @@ class API {
 		});
 	}
 
+	async pack_texture_data(
+		pack_file_path: string,
+		path_in_container: string,
+		options: DDSConvertOptions,
+	): Promise<{
+		metadata: DDSMetadata;
+		content: string;
+	}> {
+		return invoke('pack_texture_data', {
+			pack_file_path,
+			path_in_container,
+			options,
+		});
+	}
+
 	async pack_db_data(
 		app_id: number,
 		pack_file_path: string,
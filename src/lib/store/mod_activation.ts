@@ -5,7 +5,7 @@ import {
 	createStore,
 } from '@/lib/store/mod_generic';
 import { profileStore } from '@/lib/store/profile';
-import { modsStore, type ModItem } from '@/lib/store/mods';
+import { isAlwaysActiveMod, modsStore, type ModItem } from '@/lib/store/mods';
 import {
 	getChildMods,
 	isSeparator,
@@ -37,9 +37,7 @@ const customSyncData = async (dataToSync: ModActivationItem[]) => {
 	if (instance) {
 		instance.data = dataToSync.map(d => {
 			const mod = mods.find(m => m.identifier === d.mod_id);
-			const isBaseAndAlwaysActive =
-				(mod as ModItem).item_type === 'base_mod' &&
-				mod?.identifier !== 'BirthAndDeath';
+			const isBaseAndAlwaysActive = isAlwaysActiveMod(mod as ModItem);
 			if (isBaseAndAlwaysActive) {
 				return {
 					...d,
@@ -9,7 +9,12 @@ import { debounceCallback } from '@/lib/utils';
 
 export type ModVersionItem = {
 	mod_id: string;
-	mod_type: 'steam_mod' | 'nexus_mod' | 'base_mod' | 'local_mod';
+	mod_type:
+		| 'steam_mod'
+		| 'nexus_mod'
+		| 'base_mod'
+		| 'local_mod'
+		| 'data_mod';
 	title: string;
 	version: string | number;
 	last_time_checked: number;
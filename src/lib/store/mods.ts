@@ -15,7 +15,12 @@ export type ModItem = {
 	creator_name: string | null;
 	required_items: string[];
 	child_mods?: string[];
-	item_type: 'steam_mod' | 'nexus_mod' | 'base_mod' | 'local_mod';
+	item_type:
+		| 'steam_mod'
+		| 'nexus_mod'
+		| 'base_mod'
+		| 'local_mod'
+		| 'data_mod';
 	url?: string;
 	download_url?: string;
 	preview_url?: string | null;
@@ -27,6 +32,12 @@ export type ModItem = {
 	text_color?: string;
 };
 
+// Vanilla packs and movie packs dropped into data/ are loaded by the game no
+// matter what the profile says.
+export const isAlwaysActiveMod = (mod?: ModItem) =>
+	(mod?.item_type === 'base_mod' && mod.identifier !== 'BirthAndDeath') ||
+	(mod?.item_type === 'data_mod' && mod.categories === 'Movie');
+
 type DownloadedModMeta = {
 	mod_file_path?: string;
 	mod_url?: string | null;
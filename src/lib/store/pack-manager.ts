@@ -42,6 +42,27 @@ export type PackDBRow = {
 	}[];
 };
 
+export type DDSMetadata = {
+	format: string;
+	width: number;
+	height: number;
+	depth: number;
+	mip_count: number;
+	array_size: number;
+	face_count: number;
+	is_cube_map: boolean;
+	is_hdr: boolean;
+	mip_levels: { level: number; width: number; height: number }[];
+};
+
+export type DDSConvertOptions = {
+	mip_level: number;
+	array_slice: number;
+	face: number;
+	max_size?: number;
+	exposure?: number;
+};
+
 export type ParsedDB = {
 	[key: string]: ParsedDB | { [fieldName: string]: any }[];
 };
@@ -52,6 +52,7 @@ export type Setting = {
 	include_hidden_downloads: 1 | 0;
 	compact_archive_names: 1 | 0;
 	compact_save_names: 1 | 0;
+	strict_launch: 1 | 0;
 	sidebar_accordion: 'saves' | 'downloads';
 	mod_table_scroll: number;
 };
@@ -133,6 +134,7 @@ export class SettingModel {
 					include_hidden_downloads: 0,
 					compact_archive_names: 0,
 					compact_save_names: 0,
+					strict_launch: 0,
 					sidebar_accordion: 'saves',
 					mod_table_scroll: 0,
 				});
@@ -298,6 +300,15 @@ export class SettingModel {
 		this.props.compact_save_names = value;
 	}
 
+	// Strict Launch
+	get strict_launch(): 1 | 0 {
+		return this.props.strict_launch;
+	}
+
+	set strict_launch(value: 1 | 0) {
+		this.props.strict_launch = value;
+	}
+
 	// Sidebar Accordion
 	get sidebar_accordion(): 'saves' | 'downloads' {
 		return this.props.sidebar_accordion;
@@ -387,6 +398,9 @@ type SettingStore = {
 	compact_save_names: 1 | 0;
 	setCompactSaveNames: (compact_save_names: 1 | 0) => void;
 
+	strict_launch: 1 | 0;
+	setStrictLaunch: (strict_launch: 1 | 0) => void;
+
 	sidebar_accordion: 'saves' | 'downloads';
 	setSidebarAccordion: (sidebar_accordion: 'saves' | 'downloads') => void;
 
@@ -539,6 +553,12 @@ export const settingStore = create<SettingStore>()(
 				debounceCallback(syncSetting);
 			},
 
+			strict_launch: 0,
+			setStrictLaunch: value => {
+				set({ strict_launch: value });
+				debounceCallback(syncSetting);
+			},
+
 			sidebar_accordion: 'saves',
 			setSidebarAccordion: value => {
 				set({ sidebar_accordion: value });
@@ -665,6 +685,11 @@ const syncSetting = async () => {
 		changed = true;
 	}
 
+	if (setting.strict_launch !== state.strict_launch) {
+		setting.strict_launch = state.strict_launch ? 1 : 0;
+		changed = true;
+	}
+
 	if (setting.sidebar_accordion !== state.sidebar_accordion) {
 		setting.sidebar_accordion = state.sidebar_accordion;
 		changed = true;
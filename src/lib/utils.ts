@@ -4,10 +4,16 @@ import { toast } from 'sonner';
 import { twMerge } from 'tailwind-merge';
 
 import { convertFileSrc } from '@tauri-apps/api/core';
+import { ask } from '@tauri-apps/plugin-dialog';
 
 import type { FileMeta } from '@/components/native-file-input';
 
-import api from '@/lib/api';
+import api, {
+	type BannerlordLaunchMod,
+	type PreflightReport,
+	type TotalwarMod,
+	type UsedModsProblem,
+} from '@/lib/api';
 import type { ModItem } from '@/lib/store/mods';
 import type { ModOrderItem } from '@/lib/store/mod_order';
 import {
@@ -15,6 +21,8 @@ import {
 	type ModItemSeparatorUnion,
 } from '@/lib/store/mod_separator';
 import type { ModActivationItem } from '@/lib/store/mod_activation';
+import { profileStore } from '@/lib/store/profile';
+import { settingStore } from '@/lib/store/setting';
 import { type SaveFile } from '@/lib/store/save_files';
 
 export function cn(...inputs: ClassValue[]) {
@@ -118,31 +126,114 @@ export function determineErrorMessage(error: unknown) {
 	return errorMessage;
 }
 
-export async function startGameTotalwar(
+function describeUsedModsProblem(problem: UsedModsProblem) {
+	switch (problem.kind) {
+		case 'missing_file':
+			return `${problem.identifier}: ${problem.mod_file_path} does not exist`;
+		case 'invalid_pack':
+			return `${problem.identifier}: ${problem.message}`;
+		case 'unquotable_path':
+			return `${problem.identifier}: ${problem.mod_file_path} contains a quote or line break`;
+		case 'duplicate_pack_name':
+			return `${problem.identifier}: ${problem.pack_name} is already loaded from ${problem.shadowed_by}`;
+	}
+}
+
+// A game update is shown before launching, the new build is only recorded
+// once the user chose to play anyway.
+async function confirmGameUpdate(
 	app_id: number,
+	mods: ModItem[],
+): Promise<boolean> {
+	const update = await api.check_game_update(
+		app_id,
+		mods
+			.filter(m => m.mod_file_path !== '')
+			.map(m => ({
+				identifier: m.identifier,
+				mod_path: m.mod_file_path,
+			})),
+	);
+	if (!update) return true;
+
+	const outdated = update.outdated_mods.map(m => m.identifier);
+	const proceed = await ask(
+		outdated.length > 0
+			? `These mods may need an update: ${outdated.join(', ')}`
+			: 'None of the active mods look outdated.',
+		{
+			title: 'The game was updated since the last launch',
+			kind: 'warning',
+			okLabel: 'Play Anyway',
+			cancelLabel: 'Cancel',
+		},
+	);
+	if (!proceed) return false;
+
+	await api.acknowledge_game_update(app_id);
+	return true;
+}
+
+function activeTotalwarMods(
 	mods: ModItemSeparatorUnion[],
 	modActivationData: ModActivationItem[],
-	saveFile?: SaveFile,
 ) {
-	const reverseLoadOrder = [...mods].slice().reverse();
-	let addDirectoryTxt = '';
-	let usedModsTxt = '';
-	for (let ri = 0; ri < reverseLoadOrder.length; ri++) {
-		if (isSeparator(reverseLoadOrder[ri])) continue;
-
-		const mod = reverseLoadOrder[ri] as ModItem;
-		const isActive = modActivationData.some(
-			a => a.is_active === true && a.mod_id === mod.identifier,
-		);
-		if (!isActive) continue;
+	return mods.filter(
+		m =>
+			!isSeparator(m) &&
+			modActivationData.some(
+				a => a.is_active === true && a.mod_id === m.identifier,
+			),
+	) as ModItem[];
+}
 
-		const cleanedModPath = mod.mod_file_path.replace(/\\/g, '/');
-		const modFileName = cleanedModPath.split('/').pop();
-		const modFolder = cleanedModPath.replace('/' + modFileName, '');
-		addDirectoryTxt += `add_working_directory "${modFolder}";\n`;
-		usedModsTxt += `mod "${modFileName}";\n`;
-	}
+function activeBannerlordMods(
+	mods: ModItemSeparatorUnion[],
+	modActivationData: ModActivationItem[],
+) {
+	return mods.filter(
+		m =>
+			!isSeparator(m) &&
+			!modActivationData.some(
+				ma =>
+					ma.mod_id === m.identifier &&
+					!ma.is_active &&
+					(m as ModItem).item_type !== 'base_mod' &&
+					m.identifier !== 'BirthAndDeath',
+			),
+	) as ModItem[];
+}
+
+// The backend resolves working directories and the load order itself.
+function totalwarLaunchMods(activeMods: ModItem[]): TotalwarMod[] {
+	return activeMods.map(m => ({
+		identifier: m.identifier,
+		mod_file_path: m.mod_file_path,
+	}));
+}
+
+function bannerlordLaunchMods(activeMods: ModItem[]): BannerlordLaunchMod[] {
+	return activeMods.map(currentMod => {
+		if (
+			currentMod.item_type === 'steam_mod' ||
+			currentMod.item_type === 'base_mod'
+		) {
+			return {
+				identifier: currentMod.identifier,
+				bannerlord_id: currentMod.game_specific_id,
+				mod_path: currentMod.game_specific_id,
+			};
+		} else {
+			return {
+				identifier: currentMod.identifier,
+				bannerlord_id: currentMod.game_specific_id,
+				mod_path: currentMod.mod_file_path,
+			};
+		}
+	});
+}
 
+function saveGameName(saveFile?: SaveFile) {
 	let save_game: string | undefined = '';
 	if (
 		typeof saveFile?.path !== 'undefined' &&
@@ -152,13 +243,79 @@ export async function startGameTotalwar(
 	) {
 		save_game = saveFile.path.split('\\').pop();
 	}
+	return save_game;
+}
+
+// The same folders the conflict check scans, the pre-launch check reuses them.
+function modFolderPaths(app_id: number) {
+	const { games, mod_installation_path, steam_library_paths } =
+		settingStore.getState();
+	const game = games.find(g => g.steam_id === app_id);
+	return [
+		`${mod_installation_path}\\${app_id}`,
+		steam_library_paths.game_workshop_paths[game?.slug ?? ''],
+	].filter(path => !!path);
+}
 
-	await api.start_game_totalwar(
+export async function preflightGame(
+	app_id: number,
+	mods: ModItemSeparatorUnion[],
+	modActivationData: ModActivationItem[],
+	saveFile?: SaveFile,
+): Promise<PreflightReport> {
+	const profile_id = profileStore.getState().profile.id;
+	const game = settingStore.getState().games.find(g => g.steam_id === app_id);
+
+	switch (game?.type) {
+		case 'totalwar':
+			return api.preflight_totalwar(
+				app_id,
+				profile_id,
+				totalwarLaunchMods(
+					activeTotalwarMods(mods, modActivationData),
+				),
+				saveGameName(saveFile),
+				modFolderPaths(app_id),
+			);
+		case 'bannerlord':
+			return api.preflight_bannerlord(
+				app_id,
+				profile_id,
+				bannerlordLaunchMods(
+					activeBannerlordMods(mods, modActivationData),
+				),
+				modFolderPaths(app_id),
+			);
+		default:
+			throw new Error('Unsupported Game');
+	}
+}
+
+export async function startGameTotalwar(
+	app_id: number,
+	mods: ModItemSeparatorUnion[],
+	modActivationData: ModActivationItem[],
+	saveFile?: SaveFile,
+) {
+	const activeMods = activeTotalwarMods(mods, modActivationData);
+	if (!(await confirmGameUpdate(app_id, activeMods))) return;
+
+	const result = await api.start_game_totalwar(
 		app_id,
-		addDirectoryTxt,
-		usedModsTxt,
-		save_game,
+		totalwarLaunchMods(activeMods),
+		saveGameName(saveFile),
+		profileStore.getState().profile.id,
+		settingStore.getState().strict_launch === 1,
+		modFolderPaths(app_id),
 	);
+	if (!result.launched) {
+		throw new Error(
+			[
+				'The game was not started:',
+				...result.problems.map(describeUsedModsProblem),
+			].join('\n'),
+		);
+	}
 }
 
 export async function startGameBannerlord(
@@ -167,40 +324,17 @@ export async function startGameBannerlord(
 	modActivationData: ModActivationItem[],
 	_saveFile?: SaveFile,
 ): Promise<string> {
-	const modsToLoad = mods
-		.filter(
-			m =>
-				!isSeparator(m) &&
-				!modActivationData.some(
-					ma =>
-						ma.mod_id === m.identifier &&
-						!ma.is_active &&
-						(m as ModItem).item_type !== 'base_mod' &&
-						m.identifier !== 'BirthAndDeath',
-				),
-		)
-		.map(m => {
-			const currentMod = m as ModItem;
-			if (
-				currentMod.item_type === 'steam_mod' ||
-				currentMod.item_type === 'base_mod'
-			) {
-				return {
-					identifier: currentMod.identifier,
-					bannerlord_id: currentMod.game_specific_id,
-					mod_path: currentMod.game_specific_id,
-				};
-			} else {
-				return {
-					identifier: currentMod.identifier,
-					bannerlord_id: currentMod.game_specific_id,
-					mod_path: currentMod.mod_file_path,
-				};
-			}
-		});
+	const activeMods = activeBannerlordMods(mods, modActivationData);
+	if (!(await confirmGameUpdate(app_id, activeMods))) return '';
 
-	const command = await api.start_game_bannerlord(app_id, modsToLoad);
-	return command;
+	const result = await api.start_game_bannerlord(
+		app_id,
+		bannerlordLaunchMods(activeMods),
+		profileStore.getState().profile.id,
+		settingStore.getState().strict_launch === 1,
+		modFolderPaths(app_id),
+	);
+	return result.command;
 }
 
 export function normalizeTimestamp(timestamp: number): number {
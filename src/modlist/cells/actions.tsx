@@ -5,6 +5,7 @@ import {
 	ArrowRightIcon,
 	EllipsisVerticalIcon,
 	EyeIcon,
+	FolderInputIcon,
 	InfoIcon,
 	LinkIcon,
 	MinusIcon,
@@ -14,6 +15,7 @@ import {
 	UngroupIcon,
 	UserIcon,
 } from 'lucide-react';
+import { toast } from 'sonner';
 
 import {
 	DropdownMenu,
@@ -173,7 +175,13 @@ const ModActions = ({
 	mod: ModItem;
 	ModActionRenderer: any;
 }) => {
-	const selectedGame = settingStore(state => state.selectedGame);
+	const { selectedGame, init_reload, setInitReload } = settingStore(
+		useShallow(state => ({
+			selectedGame: state.selectedGame,
+			init_reload: state.init_reload,
+			setInitReload: state.setInitReload,
+		})),
+	);
 
 	const { toggleMetaInfo, setSelectedMetaMod } = modMetaStore(
 		useShallow(state => ({
@@ -240,6 +248,21 @@ const ModActions = ({
 		toggleModRemove();
 	}, [mod.identifier]);
 
+	const handleMigrate = useCallback(async () => {
+		if (!selectedGame) return;
+		try {
+			await api.migrate_data_mod(
+				selectedGame.steam_id,
+				mod.mod_file_path,
+				true,
+			);
+			toast.success(`${mod.title} moved to the mod folder`);
+			setInitReload(!init_reload);
+		} catch (error) {
+			toastError(error);
+		}
+	}, [mod.identifier, selectedGame, init_reload]);
+
 	const { showExternalLink, deleteText } = useMemo(
 		() => ({
 			showExternalLink:
@@ -259,6 +282,7 @@ const ModActions = ({
 			handleOpenModUrl={handleOpenModUrl}
 			handleMetaInfo={handleMetaInfo}
 			handleRemove={handleRemove}
+			handleMigrate={handleMigrate}
 			handleSetPriority={handleSetPriority}
 			handleSendToSeparator={handleSendToSeparator}
 			deleteText={deleteText}
@@ -277,6 +301,7 @@ export const ModActionDropdownRenderer = ({
 	handleSetPriority,
 	handleSendToSeparator,
 	handleRemove,
+	handleMigrate,
 	deleteText,
 
 	cellStyle,
@@ -292,6 +317,7 @@ export const ModActionDropdownRenderer = ({
 	handleSetPriority?: () => void;
 	handleSendToSeparator?: () => void;
 	handleRemove?: () => void;
+	handleMigrate?: () => void;
 	deleteText?: string;
 	selectedGame?: IGameMeta;
 	showExternalLink?: boolean;
@@ -506,6 +532,16 @@ export const ModActionDropdownRenderer = ({
 							</DropdownMenuPortal>
 						</DropdownMenuSub>
 
+						{currentMod.item_type === 'data_mod' && (
+							<DropdownMenuItem
+								className="my-0 py-2 text-xs"
+								onClick={handleMigrate}
+							>
+								<FolderInputIcon className="size-3" />
+								Move to Mod Folder
+							</DropdownMenuItem>
+						)}
+
 						<DropdownMenuItem
 							className="my-0 py-2 text-xs"
 							onClick={handleRemove}
@@ -530,6 +566,7 @@ export const ModActionContextMenuRenderer = ({
 	handleSetPriority,
 	handleSendToSeparator,
 	handleRemove,
+	handleMigrate,
 	deleteText,
 	handleEdit,
 	handleDelete,
@@ -543,6 +580,7 @@ export const ModActionContextMenuRenderer = ({
 	handleSetPriority?: () => void;
 	handleSendToSeparator?: () => void;
 	handleRemove?: () => void;
+	handleMigrate?: () => void;
 	deleteText?: string;
 	selectedGame?: IGameMeta;
 	showExternalLink?: boolean;
@@ -682,6 +720,13 @@ export const ModActionContextMenuRenderer = ({
 				</ContextMenuSubContent>
 			</ContextMenuSub>
 
+			{currentMod.item_type === 'data_mod' && (
+				<ContextMenuItem onSelect={handleMigrate}>
+					<FolderInputIcon className="mr-2 size-3" />
+					Move to Mod Folder
+				</ContextMenuItem>
+			)}
+
 			<ContextMenuItem onSelect={handleRemove}>
 				<TrashIcon className="mr-2 size-3 text-red-500" />
 				{deleteText}
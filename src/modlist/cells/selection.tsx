@@ -2,7 +2,7 @@ import { memo, useMemo, useCallback } from 'react';
 
 import { Checkbox } from '@/components/checkbox';
 
-import { ModItem, modsStore } from '@/lib/store/mods';
+import { isAlwaysActiveMod, ModItem, modsStore } from '@/lib/store/mods';
 import {
 	modActivationStore,
 	toggleModActivation,
@@ -69,14 +69,10 @@ export const Selection = memo(
 		} else {
 			const { isBaseAndAlwaysActive, isChecked } = useMemo(
 				() => ({
-					isBaseAndAlwaysActive:
-						(mod as ModItem).item_type === 'base_mod' &&
-						mod.identifier !== 'BirthAndDeath',
-					isChecked:
-						(mod as ModItem).item_type === 'base_mod' &&
-						mod.identifier !== 'BirthAndDeath'
-							? true
-							: currentSelection?.is_active,
+					isBaseAndAlwaysActive: isAlwaysActiveMod(mod as ModItem),
+					isChecked: isAlwaysActiveMod(mod as ModItem)
+						? true
+						: currentSelection?.is_active,
 				}),
 				[mod.identifier, currentSelection],
 			);
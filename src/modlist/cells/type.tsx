@@ -1,6 +1,6 @@
 import { memo, useMemo } from 'react';
 import { useShallow } from 'zustand/react/shallow';
-import { FileIcon } from 'lucide-react';
+import { DatabaseIcon, FileIcon } from 'lucide-react';
 
 import { settingStore } from '@/lib/store/setting';
 import {
@@ -65,6 +65,14 @@ export const Type = memo(
 						/>
 					);
 
+				case 'data_mod':
+					return (
+						<DatabaseIcon
+							className="select-none rounded-full object-cover"
+							style={{ width: ICON_SIZE, height: ICON_SIZE }}
+						/>
+					);
+
 				case 'local_mod':
 					return (
 						<FileIcon
@@ -0,0 +1,172 @@
+import { useEffect, useState } from 'react';
+import { useShallow } from 'zustand/react/shallow';
+import { CameraIcon, HistoryIcon, TrashIcon, UploadIcon } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { Button } from '@/components/button';
+import { Loading } from '@/components/loading';
+
+import { settingStore } from '@/lib/store/setting';
+import { profileStore } from '@/lib/store/profile';
+
+import api, { type ConfigBackup, type ConfigSnapshot } from '@/lib/api';
+import { toastError } from '@/lib/utils';
+
+export const ProfileConfigs = () => {
+	const [snapshot, setSnapshot] = useState<ConfigSnapshot>();
+	const [backups, setBackups] = useState<ConfigBackup[]>([]);
+	const [isLoading, setIsLoading] = useState(false);
+
+	const { selectedGame, isGameRunning } = settingStore(
+		useShallow(state => ({
+			selectedGame: state.selectedGame,
+			isGameRunning: state.isGameRunning,
+		})),
+	);
+	const profile = profileStore(state => state.profile);
+
+	const appId = selectedGame!.steam_id;
+
+	const loadConfigs = async () => {
+		setSnapshot(await api.config_snapshot(appId, profile.id));
+		setBackups(await api.config_backups(appId));
+	};
+
+	useEffect(() => {
+		loadConfigs().catch(toastError);
+	}, [appId, profile.id]);
+
+	const run = async (action: () => Promise<void>) => {
+		setIsLoading(true);
+		try {
+			await action();
+			await loadConfigs();
+		} catch (error) {
+			toastError(error);
+		} finally {
+			setIsLoading(false);
+		}
+	};
+
+	const handleCapture = () =>
+		run(async () => {
+			await api.capture_config_snapshot(appId, profile.id);
+			toast.success('Current config files saved to this profile.');
+		});
+
+	const handleApply = () =>
+		run(async () => {
+			await api.apply_config_snapshot(appId, profile.id);
+			toast.success(
+				'Profile config files applied, the previous ones were backed up.',
+			);
+		});
+
+	const handleDelete = () =>
+		run(async () => {
+			await api.delete_config_snapshot(appId, profile.id);
+			toast.success('Saved config files removed from this profile.');
+		});
+
+	const handleRestore = (backup_id: string) =>
+		run(async () => {
+			await api.restore_config_backup(appId, backup_id);
+			toast.success('Config files restored.');
+		});
+
+	const isDisabled = isLoading || isGameRunning;
+	const hasSnapshot = !!snapshot?.captured_at;
+
+	return (
+		<div className="flex flex-col gap-3">
+			<p className="text-sm text-muted-foreground">
+				{hasSnapshot
+					? `Saved ${new Date(snapshot!.captured_at!).toLocaleString()}. These files are swapped in whenever this profile is launched.`
+					: 'This profile has no saved config files yet, launches use whatever is currently in place.'}
+			</p>
+			{snapshot && snapshot.entries.length > 0 && (
+				<ul className="space-y-1 text-sm">
+					{snapshot.entries.map(entry => (
+						<li
+							key={entry.name}
+							className="flex justify-between gap-2"
+						>
+							<span className="truncate" title={entry.live_path}>
+								{entry.name}
+							</span>
+							<span className="shrink-0 text-muted-foreground">
+								{entry.captured
+									? 'Saved'
+									: entry.live_exists
+										? 'Not saved'
+										: 'Missing'}
+							</span>
+						</li>
+					))}
+				</ul>
+			)}
+			<div className="flex items-center gap-2">
+				<Button
+					className={`grow ${isDisabled ? 'disabled' : ''}`}
+					variant="success"
+					disabled={isDisabled}
+					onClick={handleCapture}
+				>
+					<CameraIcon />
+					Save Current
+					{isLoading && <Loading />}
+				</Button>
+				<Button
+					variant="secondary"
+					className={`${isDisabled || !hasSnapshot ? 'disabled' : ''}`}
+					disabled={isDisabled || !hasSnapshot}
+					onClick={handleApply}
+				>
+					<UploadIcon />
+					Apply
+				</Button>
+				<Button
+					variant="destructive"
+					className={`${isDisabled || !hasSnapshot ? 'disabled' : ''}`}
+					disabled={isDisabled || !hasSnapshot}
+					onClick={handleDelete}
+				>
+					<TrashIcon />
+				</Button>
+			</div>
+			<div className="mt-2 text-sm font-medium">Backups</div>
+			{backups.length === 0 ? (
+				<p className="text-sm text-muted-foreground">
+					Backups are made every time a profile's config files
+					replace the live ones.
+				</p>
+			) : (
+				<ul className="max-h-[200px] space-y-1 overflow-y-auto pr-3 text-sm">
+					{backups.map(backup => (
+						<li
+							key={backup.backup_id}
+							className="flex items-center justify-between gap-2"
+						>
+							<span>
+								{new Date(backup.created_at).toLocaleString()}
+								<span className="ml-2 text-muted-foreground">
+									{backup.entries.length} files
+								</span>
+							</span>
+							<Button
+								size="sm"
+								variant="secondary"
+								className={isDisabled ? 'disabled' : ''}
+								disabled={isDisabled}
+								onClick={() => handleRestore(backup.backup_id)}
+							>
+								<HistoryIcon />
+								Restore
+							</Button>
+						</li>
+					))}
+				</ul>
+			)}
+		</div>
+	);
+};
@@ -15,11 +15,15 @@ import {
 import { Button } from '@/components/button';
 
 import { profileStore } from '@/lib/store/profile';
+import { settingStore } from '@/lib/store/setting';
+
+import api from '@/lib/api';
 import { toastError } from '@/lib/utils';
 
 export const DeleteProfile = () => {
 	const [processLoading, setProcessLoading] = useState<boolean>(false);
 	const profile = profileStore(state => state.profile);
+	const selectedGame = settingStore(state => state.selectedGame);
 
 	const handleSubmit = async () => {
 		setProcessLoading(true);
@@ -29,6 +33,10 @@ export const DeleteProfile = () => {
 				return;
 			}
 
+			await api.delete_config_snapshot(
+				selectedGame!.steam_id,
+				profile.id,
+			);
 			await profile.delete();
 			toast.success('Profile deleted.');
 			setTimeout(() => {
@@ -83,14 +83,15 @@ export const ModItemComponent = ({
 						/>
 					)}
 				</div>
-				{item.item_type !== 'base_mod' && (
-					<XIcon
-						className="size-4 text-red-500 hover:cursor-pointer"
-						onClick={() => {
-							onRemoveMod(item);
-						}}
-					/>
-				)}
+				{item.item_type !== 'base_mod' &&
+					item.item_type !== 'data_mod' && (
+						<XIcon
+							className="size-4 text-red-500 hover:cursor-pointer"
+							onClick={() => {
+								onRemoveMod(item);
+							}}
+						/>
+					)}
 			</div>
 			<em className="block text-xs text-muted-foreground">
 				{item.mod_file}
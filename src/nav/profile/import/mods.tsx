@@ -54,7 +54,9 @@ export const Mods = ({
 	const [stateFilter, setStateFilter] = useState<StateFilter>('all');
 
 	const filteredMods = useMemo(() => {
-		let mods = profileExportMods.filter(m => m.item_type !== 'base_mod');
+		let mods = profileExportMods.filter(
+			m => m.item_type !== 'base_mod' && m.item_type !== 'data_mod',
+		);
 
 		switch (stateFilter) {
 			case 'steam-present':
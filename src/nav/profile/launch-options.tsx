@@ -0,0 +1,256 @@
+import { useEffect, useState } from 'react';
+import { PlusIcon, SaveIcon, XIcon } from 'lucide-react';
+import { toast } from 'sonner';
+
+import { Button } from '@/components/button';
+import { Input } from '@/components/input';
+import { Label } from '@/components/label';
+import { Loading } from '@/components/loading';
+import {
+	Select,
+	SelectContent,
+	SelectItem,
+	SelectTrigger,
+	SelectValue,
+} from '@/components/select';
+
+import { settingStore } from '@/lib/store/setting';
+import { profileStore } from '@/lib/store/profile';
+
+import api, { type BannerlordLauncher, type LaunchOptions } from '@/lib/api';
+import { toastError } from '@/lib/utils';
+
+type LaunchMode = LaunchOptions['launch_mode'];
+
+// Radix selects cannot hold an empty value, this stands in for "not set".
+const DEFAULT_VALUE = 'default';
+
+// Arguments are separated by spaces, double quotes keep one with spaces intact.
+const parseArgs = (value: string) =>
+	(value.match(/"[^"]*"|\S+/g) ?? []).map(arg =>
+		arg.startsWith('"') && arg.endsWith('"') && arg.length > 1
+			? arg.slice(1, -1)
+			: arg,
+	);
+
+const formatArgs = (args: string[]) =>
+	args.map(arg => (/\s/.test(arg) ? `"${arg}"` : arg)).join(' ');
+
+export const ProfileLaunchOptions = () => {
+	const [options, setOptions] = useState<LaunchOptions>();
+	const [args, setArgs] = useState('');
+	const [env, setEnv] = useState<{ key: string; value: string }[]>([]);
+	const [executables, setExecutables] = useState<string[]>([]);
+	const [launchers, setLaunchers] = useState<BannerlordLauncher[]>([]);
+	const [isLoading, setIsLoading] = useState(false);
+
+	const selectedGame = settingStore(state => state.selectedGame);
+	const profile = profileStore(state => state.profile);
+
+	const appId = selectedGame!.steam_id;
+	const isBannerlord = selectedGame!.type === 'bannerlord';
+
+	useEffect(() => {
+		(async () => {
+			try {
+				const launchOptions = await api.launch_options(
+					appId,
+					profile.id,
+				);
+				setOptions(launchOptions);
+				setArgs(formatArgs(launchOptions.args));
+				setEnv(
+					Object.entries(launchOptions.env).map(([key, value]) => ({
+						key,
+						value,
+					})),
+				);
+				setExecutables(await api.game_executables(appId));
+				if (isBannerlord) {
+					setLaunchers(await api.bannerlord_launchers(appId));
+				}
+			} catch (error) {
+				toastError(error);
+			}
+		})();
+	}, [appId, profile.id]);
+
+	if (!options) return <Loading />;
+
+	const handleSave = async () => {
+		setIsLoading(true);
+		try {
+			await api.set_launch_options(appId, profile.id, {
+				...options,
+				args: parseArgs(args),
+				env: Object.fromEntries(
+					env
+						.filter(e => e.key.trim() !== '')
+						.map(e => [e.key.trim(), e.value]),
+				),
+			});
+			toast.success('Launch options saved.');
+		} catch (error) {
+			toastError(error);
+		} finally {
+			setIsLoading(false);
+		}
+	};
+
+	const updateEnv = (
+		index: number,
+		update: { key?: string; value?: string },
+	) => setEnv(env.map((e, i) => (i === index ? { ...e, ...update } : e)));
+
+	return (
+		<div className="flex flex-col gap-3">
+			<div className="grid grid-cols-2 gap-3">
+				<div className="flex flex-col gap-2">
+					<Label>Start Through</Label>
+					<Select
+						value={options.launch_mode}
+						onValueChange={value =>
+							setOptions({
+								...options,
+								launch_mode: value as LaunchMode,
+							})
+						}
+					>
+						<SelectTrigger>
+							<SelectValue />
+						</SelectTrigger>
+						<SelectContent>
+							<SelectItem value="direct">Executable</SelectItem>
+							<SelectItem value="steam">Steam</SelectItem>
+						</SelectContent>
+					</Select>
+				</div>
+				<div className="flex flex-col gap-2">
+					<Label>Executable</Label>
+					<Select
+						value={options.executable ?? DEFAULT_VALUE}
+						onValueChange={value =>
+							setOptions({
+								...options,
+								executable:
+									value === DEFAULT_VALUE ? null : value,
+							})
+						}
+					>
+						<SelectTrigger>
+							<SelectValue />
+						</SelectTrigger>
+						<SelectContent>
+							<SelectItem value={DEFAULT_VALUE}>
+								Default
+							</SelectItem>
+							{executables.map(executable => (
+								<SelectItem key={executable} value={executable}>
+									{executable}
+								</SelectItem>
+							))}
+						</SelectContent>
+					</Select>
+				</div>
+				{isBannerlord && (
+					<div className="col-span-2 flex flex-col gap-2">
+						<Label>Launcher</Label>
+						<Select
+							value={options.launcher ?? DEFAULT_VALUE}
+							onValueChange={value =>
+								setOptions({
+									...options,
+									launcher:
+										value === DEFAULT_VALUE ? null : value,
+								})
+							}
+						>
+							<SelectTrigger>
+								<SelectValue />
+							</SelectTrigger>
+							<SelectContent>
+								<SelectItem value={DEFAULT_VALUE}>
+									Default
+								</SelectItem>
+								{launchers.map(launcher => (
+									<SelectItem
+										key={launcher.id}
+										value={launcher.id}
+									>
+										{launcher.name}
+									</SelectItem>
+								))}
+							</SelectContent>
+						</Select>
+					</div>
+				)}
+			</div>
+			<div className="flex flex-col gap-2">
+				<Label htmlFor="launch_args">Arguments</Label>
+				<Input
+					id="launch_args"
+					value={args}
+					onChange={e => setArgs(e.currentTarget.value)}
+					autoComplete="off"
+				/>
+			</div>
+			<div className="flex flex-col gap-2">
+				<div className="flex items-center justify-between">
+					<Label>Environment Variables</Label>
+					<Button
+						variant="ghost"
+						size="icon"
+						className="size-7"
+						onClick={() => setEnv([...env, { key: '', value: '' }])}
+					>
+						<PlusIcon />
+					</Button>
+				</div>
+				{env.map((e, index) => (
+					<div key={index} className="flex items-center gap-2">
+						<Input
+							placeholder="Name"
+							value={e.key}
+							onChange={event =>
+								updateEnv(index, {
+									key: event.currentTarget.value,
+								})
+							}
+							autoComplete="off"
+						/>
+						<Input
+							placeholder="Value"
+							value={e.value}
+							onChange={event =>
+								updateEnv(index, {
+									value: event.currentTarget.value,
+								})
+							}
+							autoComplete="off"
+						/>
+						<Button
+							variant="ghost"
+							size="icon"
+							className="size-7 shrink-0"
+							onClick={() =>
+								setEnv(env.filter((_, i) => i !== index))
+							}
+						>
+							<XIcon />
+						</Button>
+					</div>
+				))}
+			</div>
+			<Button
+				className={`mt-2 ${isLoading ? 'disabled' : ''}`}
+				variant="success"
+				disabled={isLoading}
+				onClick={handleSave}
+			>
+				<SaveIcon />
+				Save
+				{isLoading && <Loading />}
+			</Button>
+		</div>
+	);
+};
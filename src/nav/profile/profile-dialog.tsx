@@ -15,6 +15,8 @@ import { settingStore } from '@/lib/store/setting';
 import { AddProfile } from './add-profile';
 import { ImportProfile } from './import';
 import { ExportProfile } from './export';
+import { ProfileConfigs } from './configs';
+import { ProfileLaunchOptions } from './launch-options';
 
 export const ProfileDialog = () => {
 	const { isGameRunning, shouldLockScreen } = settingStore(
@@ -39,7 +41,7 @@ export const ProfileDialog = () => {
 				{/* suppress radix error */}
 				<DialogTitle className="hidden" />
 				<Tabs defaultValue="add_profile">
-					<TabsList className="mb-3 mr-10 grid grid-cols-3">
+					<TabsList className="mb-3 mr-10 grid grid-cols-5">
 						<TabsTrigger
 							value="add_profile"
 							disabled={isGameRunning || shouldLockScreen}
@@ -58,6 +60,18 @@ export const ProfileDialog = () => {
 						>
 							Export
 						</TabsTrigger>
+						<TabsTrigger
+							value="profile_configs"
+							disabled={isGameRunning || shouldLockScreen}
+						>
+							Configs
+						</TabsTrigger>
+						<TabsTrigger
+							value="launch_options"
+							disabled={isGameRunning || shouldLockScreen}
+						>
+							Launch
+						</TabsTrigger>
 					</TabsList>
 					<TabsContent value="add_profile">
 						<AddProfile />
@@ -68,6 +82,12 @@ export const ProfileDialog = () => {
 					<TabsContent value="export_profile">
 						<ExportProfile />
 					</TabsContent>
+					<TabsContent value="profile_configs">
+						<ProfileConfigs />
+					</TabsContent>
+					<TabsContent value="launch_options">
+						<ProfileLaunchOptions />
+					</TabsContent>
 				</Tabs>
 			</DialogContent>
 		</Dialog>
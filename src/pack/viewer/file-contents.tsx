@@ -12,6 +12,7 @@ import { PackTextRenderer } from './file-types/text';
 import { PackVMDRenderer } from './file-types/vmd';
 import { PackVideoRenderer } from './file-types/video';
 import { PackJSONRenderer } from './file-types/json';
+import { PackBinaryRenderer } from './file-types/binary';
 
 export const FileContents = () => {
 	const treeItemDataLoading = packManagerStore(s => s.treeItemDataLoading);
@@ -51,6 +52,22 @@ export const FileContents = () => {
 		case 'loc':
 			return <PackLocRenderer />;
 
+		case 'esf':
+		case 'ccd':
+		case 'anims_table':
+		case 'anim_fragment':
+		case 'anim_pack':
+		case 'rigid_model':
+		case 'unit_variant':
+		case 'matched_combat':
+			return <PackJSONRenderer />;
+
+		case 'binary':
+			return <PackBinaryRenderer />;
+
+		case 'empty':
+			return <PackTextRenderer />;
+
 		default:
 			return (
 				<div className="flex size-full items-center justify-center text-center">
@@ -0,0 +1,30 @@
+import { ScrollArea } from '@/components/scroll-area';
+import { packManagerStore } from '@/lib/store/pack-manager';
+
+type BinaryPreview = {
+	size: number;
+	hex: string;
+	truncated: boolean;
+	error?: string;
+};
+
+export const PackBinaryRenderer = () => {
+	const selectedTreeItemData = packManagerStore(s => s.selectedTreeItemData);
+	if (typeof selectedTreeItemData === 'undefined') return null;
+
+	const preview = selectedTreeItemData.content as BinaryPreview;
+	return (
+		<ScrollArea className="h-full">
+			<div className="space-y-3 p-5">
+				{preview.error && (
+					<div className="text-red-500">{preview.error}</div>
+				)}
+				<div className="text-muted-foreground">
+					{preview.size.toLocaleString()} bytes
+					{preview.truncated && ', showing the first 512'}
+				</div>
+				<pre className="font-mono text-sm">{preview.hex}</pre>
+			</div>
+		</ScrollArea>
+	);
+};
@@ -1,9 +1,189 @@
-import { packManagerStore } from '@/lib/store/pack-manager';
+import { useEffect, useState } from 'react';
+
+import {
+	Select,
+	SelectContent,
+	SelectItem,
+	SelectTrigger,
+	SelectValue,
+} from '@/components/select';
+import { Input } from '@/components/input';
+import { Label } from '@/components/label';
+
+import api from '@/lib/api';
+import {
+	packManagerStore,
+	type DDSConvertOptions,
+	type DDSMetadata,
+	type TreeItem,
+} from '@/lib/store/pack-manager';
+import { toastError } from '@/lib/utils';
+
+const DEFAULT_OPTIONS: DDSConvertOptions = {
+	mip_level: 0,
+	array_slice: 0,
+	face: 0,
+};
+
+const CUBE_FACES = ['+X', '-X', '+Y', '-Y', '+Z', '-Z'];
+
+const SurfaceSelect = ({
+	label,
+	value,
+	options,
+	onChange,
+}: {
+	label: string;
+	value: number;
+	options: string[];
+	onChange: (value: number) => void;
+}) => (
+	<div className="flex items-center gap-2">
+		<Label>{label}</Label>
+		<Select
+			value={value.toString()}
+			onValueChange={value => onChange(Number(value))}
+		>
+			<SelectTrigger className="h-8 w-[140px]">
+				<SelectValue />
+			</SelectTrigger>
+			<SelectContent>
+				{options.map((option, index) => (
+					<SelectItem key={index} value={index.toString()}>
+						{option}
+					</SelectItem>
+				))}
+			</SelectContent>
+		</Select>
+	</div>
+);
+
+const PackTextureRenderer = ({
+	item,
+	fallback,
+}: {
+	item: TreeItem;
+	fallback: string;
+}) => {
+	const [options, setOptions] = useState<DDSConvertOptions>(DEFAULT_OPTIONS);
+	const [texture, setTexture] = useState<{
+		metadata: DDSMetadata;
+		content: string;
+	}>();
+
+	useEffect(() => {
+		let cancelled = false;
+
+		(async () => {
+			try {
+				const data = await api.pack_texture_data(
+					item.pack_file_path,
+					item.id,
+					options,
+				);
+				if (!cancelled) setTexture(data);
+			} catch (error) {
+				toastError(error);
+			}
+		})();
+
+		return () => {
+			cancelled = true;
+		};
+	}, [options]);
+
+	const metadata = texture?.metadata;
+	const updateOptions = (update: Partial<DDSConvertOptions>) =>
+		setOptions(options => ({ ...options, ...update }));
+
+	return (
+		<div className="flex size-full flex-col">
+			{metadata && (
+				<div className="flex flex-wrap items-center gap-4 border-b p-3 text-sm">
+					<span className="text-muted-foreground">
+						{metadata.format}, {metadata.width}x{metadata.height}
+					</span>
+					{metadata.mip_count > 1 && (
+						<SurfaceSelect
+							label="Mip"
+							value={options.mip_level}
+							options={metadata.mip_levels.map(
+								mip => `${mip.level} (${mip.width}x${mip.height})`,
+							)}
+							onChange={mip_level => updateOptions({ mip_level })}
+						/>
+					)}
+					{metadata.face_count > 1 && (
+						<SurfaceSelect
+							label="Face"
+							value={options.face}
+							options={Array.from(
+								{ length: metadata.face_count },
+								(_, index) =>
+									metadata.is_cube_map
+										? CUBE_FACES[index]
+										: index.toString(),
+							)}
+							onChange={face => updateOptions({ face })}
+						/>
+					)}
+					{metadata.array_size > 1 && (
+						<SurfaceSelect
+							label="Slice"
+							value={options.array_slice}
+							options={Array.from(
+								{ length: metadata.array_size },
+								(_, index) => index.toString(),
+							)}
+							onChange={array_slice =>
+								updateOptions({ array_slice })
+							}
+						/>
+					)}
+					{metadata.is_hdr && (
+						<div className="flex items-center gap-2">
+							<Label>Exposure</Label>
+							<Input
+								type="number"
+								className="h-8 w-[90px]"
+								min={0.1}
+								step={0.1}
+								value={options.exposure ?? 1}
+								onChange={e => {
+									const exposure = Number(e.target.value);
+									if (exposure > 0)
+										updateOptions({ exposure });
+								}}
+							/>
+						</div>
+					)}
+				</div>
+			)}
+			<div className="flex min-h-0 flex-1 items-center justify-center overflow-auto">
+				<img
+					className="h-auto max-w-[1000px]"
+					src={texture?.content ?? fallback}
+				/>
+			</div>
+		</div>
+	);
+};
 
 export const PackImageRenderer = () => {
+	const selectedTreeItem = packManagerStore(s => s.selectedTreeItem);
 	const selectedTreeItemData = packManagerStore(s => s.selectedTreeItemData);
 	if (typeof selectedTreeItemData === 'undefined') return null;
 
+	if (selectedTreeItem?.id.toLowerCase().endsWith('.dds')) {
+		return (
+			<PackTextureRenderer
+				key={`${selectedTreeItem.pack_file_path}:${selectedTreeItem.id}`}
+				item={selectedTreeItem}
+				fallback={selectedTreeItemData.content}
+			/>
+		);
+	}
+
 	return (
 		<div className="flex size-full items-center justify-center overflow-auto">
 			<img
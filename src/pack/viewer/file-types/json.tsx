@@ -6,10 +6,11 @@ export const PackJSONRenderer = () => {
 	const selectedTreeItemData = packManagerStore(s => s.selectedTreeItemData);
 	if (typeof selectedTreeItemData === 'undefined') return null;
 
-	return (
-		<SyntaxHighlighter
-			syntax="json"
-			content={selectedTreeItemData.content}
-		/>
-	);
+	// Decoded binary formats arrive as objects rather than JSON text.
+	const content =
+		typeof selectedTreeItemData.content === 'string'
+			? selectedTreeItemData.content
+			: JSON.stringify(selectedTreeItemData.content, null, 2);
+
+	return <SyntaxHighlighter syntax="json" content={content} />;
 };
@@ -33,6 +33,8 @@ import {
 	toastError,
 } from '@/lib/utils';
 
+import { Preflight } from './preflight';
+
 export const Play = () => {
 	const {
 		setSaveFileDialogOpen,
@@ -74,6 +76,19 @@ export const Play = () => {
 		})),
 	);
 
+	const sortedMods = useMemo(() => {
+		const orderMap: Record<string, number> = modOrderData.reduce(
+			(acc: any, item: any) => {
+				acc[item.mod_id] = item.order;
+				return acc;
+			},
+			{} as Record<string, number>,
+		);
+		return [...mods].sort((a, b) => {
+			return orderMap[a.identifier] - orderMap[b.identifier];
+		});
+	}, [mods, modOrderData]);
+
 	const checkIfGameIsRunning = useCallback(async () => {
 		try {
 			const result = await api.is_game_running(selectedGame!.steam_id);
@@ -162,16 +177,6 @@ export const Play = () => {
 			if (!isCompatible) return;
 
 			setIsGameLoading(true);
-			const orderMap: Record<string, number> = modOrderData.reduce(
-				(acc: any, item: any) => {
-					acc[item.mod_id] = item.order;
-					return acc;
-				},
-				{} as Record<string, number>,
-			);
-			const sortedMods = [...mods].sort((a, b) => {
-				return orderMap[a.identifier] - orderMap[b.identifier];
-			});
 			setCurrentlyRunningMods(
 				sortedMods.map(m => {
 					if (isSeparator(m)) {
@@ -258,6 +263,12 @@ export const Play = () => {
 					{playButtonText}
 					{isGameLoading && <Loading />}
 				</RippleButton>
+				<Preflight
+					sortedMods={sortedMods}
+					disabled={
+						isGameRunning || shouldLockScreen || isGameLoading
+					}
+				/>
 				{isGameRunning && (
 					<AlertDialog>
 						<AlertDialogTrigger asChild>
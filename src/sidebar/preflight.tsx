@@ -0,0 +1,148 @@
+import { useState } from 'react';
+import { useShallow } from 'zustand/react/shallow';
+import { ClipboardCheckIcon } from 'lucide-react';
+
+import { Button } from '@/components/button';
+import {
+	Dialog,
+	DialogContent,
+	DialogDescription,
+	DialogHeader,
+	DialogTitle,
+} from '@/components/dialog';
+import { Loading } from '@/components/loading';
+import { ScrollArea } from '@/components/scroll-area';
+
+import { settingStore } from '@/lib/store/setting';
+import { modActivationStore } from '@/lib/store/mod_activation';
+import type { ModItemSeparatorUnion } from '@/lib/store/mod_separator';
+
+import type { PreflightReport } from '@/lib/api';
+import { preflightGame, toastError } from '@/lib/utils';
+
+export const Preflight = ({
+	sortedMods,
+	disabled,
+}: {
+	sortedMods: ModItemSeparatorUnion[];
+	disabled: boolean;
+}) => {
+	const [report, setReport] = useState<PreflightReport>();
+	const [isLoading, setIsLoading] = useState(false);
+
+	const selectedGame = settingStore(state => state.selectedGame);
+	const { saveFile, modActivationData } = modActivationStore(
+		useShallow(state => ({
+			saveFile: state.saveFile,
+			modActivationData: state.data,
+		})),
+	);
+
+	const handleCheck = async () => {
+		setIsLoading(true);
+		try {
+			setReport(
+				await preflightGame(
+					selectedGame!.steam_id,
+					sortedMods,
+					modActivationData,
+					saveFile,
+				),
+			);
+		} catch (error) {
+			toastError(error);
+		} finally {
+			setIsLoading(false);
+		}
+	};
+
+	return (
+		<>
+			<Button
+				size="icon"
+				variant="outline"
+				className={disabled || isLoading ? 'disabled' : ''}
+				disabled={disabled || isLoading}
+				title="Check what would be launched"
+				onClick={handleCheck}
+			>
+				{isLoading ? <Loading /> : <ClipboardCheckIcon />}
+			</Button>
+			<Dialog
+				open={!!report}
+				onOpenChange={open => !open && setReport(undefined)}
+			>
+				<DialogContent className="sm:max-w-[700px]">
+					<DialogHeader>
+						<DialogTitle>Pre-launch Check</DialogTitle>
+						<DialogDescription>
+							{report?.blocking
+								? 'The game would not start cleanly with the current profile.'
+								: 'Nothing is blocking the launch.'}
+						</DialogDescription>
+					</DialogHeader>
+					{report && (
+						<ScrollArea className="h-[60vh] pr-3">
+							<div className="flex flex-col gap-4 text-sm">
+								{report.issues.length > 0 && (
+									<ul className="space-y-2">
+										{report.issues.map((issue, index) => (
+											<li key={index}>
+												<span
+													className={
+														issue.severity ===
+														'blocking'
+															? 'text-red-500'
+															: 'text-orange-500'
+													}
+												>
+													{issue.subject}
+												</span>
+												: {issue.message}
+											</li>
+										))}
+									</ul>
+								)}
+								<div>
+									<div className="mb-1 font-medium">
+										Command
+									</div>
+									<pre className="whitespace-pre-wrap break-all font-mono text-xs">
+										{report.command.join(' ')}
+									</pre>
+								</div>
+								{report.used_mods_txt !== null && (
+									<div>
+										<div className="mb-1 font-medium">
+											Mod List
+										</div>
+										<pre className="whitespace-pre-wrap break-all font-mono text-xs">
+											{report.used_mods_txt}
+										</pre>
+									</div>
+								)}
+								{report.deployments.length > 0 && (
+									<div>
+										<div className="mb-1 font-medium">
+											Deployments
+										</div>
+										<ul className="space-y-1 break-all font-mono text-xs">
+											{report.deployments.map(
+												deployment => (
+													<li key={deployment.target}>
+														{deployment.source} →{' '}
+														{deployment.target}
+													</li>
+												),
+											)}
+										</ul>
+									</div>
+								)}
+							</div>
+						</ScrollArea>
+					)}
+				</DialogContent>
+			</Dialog>
+		</>
+	);
+};